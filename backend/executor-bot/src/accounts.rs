@@ -0,0 +1,248 @@
+/*!
+ * `execute_strategy` account set and instruction
+ *
+ * PDAs are derived with the same seeds as the programs:
+ *
+ * - execution-engine: `execution_vault`, `profit_config`, `executor_stats` + executor
+ * - strategy-registry: `metrics` + strategy, `config`
+ * - flash-loan: `flash_pool`, `flash_pool` + `authority`
+ *
+ * The vault borrows into its WSOL associated token account. The creator,
+ * treasury and flash loan pool token account are read from the strategy,
+ * `ProfitConfig` and `FlashLoanPool` accounts.
+ */
+
+use anyhow::Result;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{pubkey, system_program};
+use std::str::FromStr;
+
+use crate::config::Config;
+use crate::state::instruction_discriminator;
+
+pub const WSOL_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// Program IDs the executor talks to
+#[derive(Debug, Clone, Copy)]
+pub struct ProgramIds {
+    pub execution_engine: Pubkey,
+    pub flash_loan: Pubkey,
+    pub strategy_registry: Pubkey,
+    pub dao_governance: Pubkey,
+}
+
+impl ProgramIds {
+    pub fn from_config(config: &Config) -> Result<Self> {
+        Ok(Self {
+            execution_engine: Pubkey::from_str(&config.execution_engine_program_id)?,
+            flash_loan: Pubkey::from_str(&config.flash_loan_program_id)?,
+            strategy_registry: Pubkey::from_str(&config.strategy_registry_program_id)?,
+            dao_governance: Pubkey::from_str(&config.dao_governance_program_id)?,
+        })
+    }
+
+    pub fn vault(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"execution_vault"], &self.execution_engine).0
+    }
+
+    pub fn profit_config(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"profit_config"], &self.execution_engine).0
+    }
+
+    pub fn executor_stats(&self, executor: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"executor_stats", executor.as_ref()], &self.execution_engine).0
+    }
+
+    pub fn strategy_metrics(&self, strategy: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"metrics", strategy.as_ref()], &self.strategy_registry).0
+    }
+
+    pub fn registry_config(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"config"], &self.strategy_registry).0
+    }
+
+    pub fn flash_loan_pool(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"flash_pool"], &self.flash_loan).0
+    }
+
+    pub fn flash_loan_pool_authority(&self) -> Pubkey {
+        Pubkey::find_program_address(&[b"flash_pool", b"authority"], &self.flash_loan).0
+    }
+}
+
+/// Associated token account of `owner` for `mint` (owner may be a PDA)
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), TOKEN_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Accounts of the execution-engine `ExecuteStrategy` context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecuteStrategyAccounts {
+    pub vault: Pubkey,
+    pub profit_config: Pubkey,
+    pub strategy: Pubkey,
+    pub strategy_metrics: Pubkey,
+    pub creator: Pubkey,
+    pub executor: Pubkey,
+    pub executor_stats: Pubkey,
+    pub treasury: Pubkey,
+    pub dao_governance_program: Pubkey,
+    pub strategy_registry_program: Pubkey,
+    pub registry_config: Pubkey,
+    pub flash_loan_pool: Pubkey,
+    pub flash_loan_pool_authority: Pubkey,
+    pub flash_loan_pool_token_account: Pubkey,
+    pub vault_token_account: Pubkey,
+    pub flash_loan_program: Pubkey,
+    pub token_program: Pubkey,
+    pub system_program: Pubkey,
+}
+
+impl ExecuteStrategyAccounts {
+    pub fn new(
+        programs: &ProgramIds,
+        strategy: Pubkey,
+        creator: Pubkey,
+        executor: Pubkey,
+        treasury: Pubkey,
+        flash_loan_pool_token_account: Pubkey,
+    ) -> Self {
+        let vault = programs.vault();
+
+        Self {
+            vault,
+            profit_config: programs.profit_config(),
+            strategy,
+            strategy_metrics: programs.strategy_metrics(&strategy),
+            creator,
+            executor,
+            executor_stats: programs.executor_stats(&executor),
+            treasury,
+            dao_governance_program: programs.dao_governance,
+            strategy_registry_program: programs.strategy_registry,
+            registry_config: programs.registry_config(),
+            flash_loan_pool: programs.flash_loan_pool(),
+            flash_loan_pool_authority: programs.flash_loan_pool_authority(),
+            flash_loan_pool_token_account,
+            vault_token_account: associated_token_address(&vault, &WSOL_MINT),
+            flash_loan_program: programs.flash_loan,
+            token_program: TOKEN_PROGRAM_ID,
+            system_program: system_program::id(),
+        }
+    }
+
+    /// Account metas in the order of the program's `ExecuteStrategy` struct
+    pub fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.vault, false),
+            AccountMeta::new_readonly(self.profit_config, false),
+            AccountMeta::new(self.strategy, false),
+            AccountMeta::new(self.strategy_metrics, false),
+            AccountMeta::new(self.creator, false),
+            AccountMeta::new(self.executor, true),
+            AccountMeta::new(self.executor_stats, false),
+            AccountMeta::new(self.treasury, false),
+            AccountMeta::new_readonly(self.dao_governance_program, false),
+            AccountMeta::new_readonly(self.strategy_registry_program, false),
+            AccountMeta::new_readonly(self.registry_config, false),
+            AccountMeta::new(self.flash_loan_pool, false),
+            AccountMeta::new_readonly(self.flash_loan_pool_authority, false),
+            AccountMeta::new(self.flash_loan_pool_token_account, false),
+            AccountMeta::new(self.vault_token_account, false),
+            AccountMeta::new_readonly(self.flash_loan_program, false),
+            AccountMeta::new_readonly(self.token_program, false),
+            AccountMeta::new_readonly(self.system_program, false),
+        ]
+    }
+}
+
+/// `execute_strategy(borrow_amount, min_profit)` instruction
+pub fn execute_strategy_ix(
+    program_id: Pubkey,
+    accounts: &ExecuteStrategyAccounts,
+    borrow_amount: u64,
+    min_profit: u64,
+) -> Instruction {
+    let mut data = instruction_discriminator("execute_strategy").to_vec();
+    data.extend_from_slice(&borrow_amount.to_le_bytes());
+    data.extend_from_slice(&min_profit.to_le_bytes());
+
+    Instruction::new_with_bytes(program_id, &data, accounts.to_account_metas())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn programs() -> ProgramIds {
+        ProgramIds::from_config(&Config::for_tests()).unwrap()
+    }
+
+    #[test]
+    fn test_account_set_uses_program_seeds() {
+        let programs = programs();
+        let strategy = Pubkey::new_unique();
+        let executor = Pubkey::new_unique();
+        let accounts = ExecuteStrategyAccounts::new(
+            &programs,
+            strategy,
+            Pubkey::new_unique(),
+            executor,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        let (vault, _) = Pubkey::find_program_address(&[b"execution_vault"], &programs.execution_engine);
+        assert_eq!(accounts.vault, vault);
+        assert_eq!(
+            accounts.strategy_metrics,
+            Pubkey::find_program_address(&[b"metrics", strategy.as_ref()], &programs.strategy_registry).0
+        );
+        assert_eq!(
+            accounts.executor_stats,
+            Pubkey::find_program_address(&[b"executor_stats", executor.as_ref()], &programs.execution_engine).0
+        );
+        assert_eq!(
+            accounts.flash_loan_pool_authority,
+            Pubkey::find_program_address(&[b"flash_pool", b"authority"], &programs.flash_loan).0
+        );
+        assert_eq!(
+            accounts.vault_token_account,
+            associated_token_address(&vault, &WSOL_MINT)
+        );
+    }
+
+    #[test]
+    fn test_instruction_layout() {
+        let programs = programs();
+        let executor = Pubkey::new_unique();
+        let accounts = ExecuteStrategyAccounts::new(
+            &programs,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            executor,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let ix = execute_strategy_ix(programs.execution_engine, &accounts, 1_000, 7);
+
+        assert_eq!(&ix.data[..8], &instruction_discriminator("execute_strategy"));
+        assert_eq!(&ix.data[8..16], &1_000u64.to_le_bytes());
+        assert_eq!(&ix.data[16..], &7u64.to_le_bytes());
+
+        assert_eq!(ix.accounts.len(), 18);
+        let signers: Vec<_> = ix.accounts.iter().filter(|meta| meta.is_signer).collect();
+        assert_eq!(signers.len(), 1);
+        assert_eq!(signers[0].pubkey, executor);
+        assert!(!ix.accounts[1].is_writable); // profit_config
+        assert!(ix.accounts[14].is_writable); // vault_token_account
+    }
+}
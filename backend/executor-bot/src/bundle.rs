@@ -0,0 +1,277 @@
+/*!
+ * Jito bundle submission
+ *
+ * Transactions sent to the public mempool can be front-run, so executions
+ * can instead be submitted as single-transaction bundles to a block engine.
+ * The execution transaction ends with a SOL transfer to one of the block
+ * engine's tip accounts. A bundle lands atomically, so the tip is only paid
+ * when the execution succeeds.
+ *
+ * The block engine sits behind `BlockEngine`; `JitoBlockEngine` speaks the
+ * block engine's JSON-RPC (`getTipAccounts`, `sendBundle`).
+ */
+
+use anyhow::{anyhow, bail, Result};
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use reqwest::Client;
+use serde_json::{json, Value};
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::Transaction;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tracing::info;
+
+#[async_trait]
+pub trait BlockEngine: Send + Sync {
+    /// Accounts that bundle tips may be paid to
+    async fn tip_accounts(&self) -> Result<Vec<Pubkey>>;
+
+    /// Submit transactions as one bundle, returning the bundle ID
+    async fn send_bundle(&self, transactions: &[Transaction]) -> Result<String>;
+}
+
+/// Block engine JSON-RPC client (`<url>/api/v1/bundles`)
+pub struct JitoBlockEngine {
+    http_client: Client,
+    url: String,
+}
+
+impl JitoBlockEngine {
+    pub fn new(block_engine_url: &str) -> Self {
+        Self {
+            http_client: Client::new(),
+            url: format!("{}/api/v1/bundles", block_engine_url.trim_end_matches('/')),
+        }
+    }
+
+    async fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let response: Value = self
+            .http_client
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            bail!("{} failed: {}", method, error);
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow!("{} returned no result", method))
+    }
+}
+
+#[async_trait]
+impl BlockEngine for JitoBlockEngine {
+    async fn tip_accounts(&self) -> Result<Vec<Pubkey>> {
+        let result = self.call("getTipAccounts", json!([])).await?;
+        result
+            .as_array()
+            .ok_or_else(|| anyhow!("getTipAccounts returned {}", result))?
+            .iter()
+            .map(|account| {
+                let account = account.as_str().unwrap_or_default();
+                Pubkey::from_str(account).map_err(|e| anyhow!("invalid tip account {}: {}", account, e))
+            })
+            .collect()
+    }
+
+    async fn send_bundle(&self, transactions: &[Transaction]) -> Result<String> {
+        let encoded = transactions
+            .iter()
+            .map(|transaction| Ok(BASE64.encode(bincode::serialize(transaction)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let result = self
+            .call("sendBundle", json!([encoded, { "encoding": "base64" }]))
+            .await?;
+        result
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("sendBundle returned {}", result))
+    }
+}
+
+/// Submits execution transactions as tipped bundles
+pub struct Bundler {
+    engine: Box<dyn BlockEngine>,
+    tip_accounts: Vec<Pubkey>,
+    tip_lamports: u64,
+    next_tip_account: AtomicUsize,
+}
+
+impl Bundler {
+    /// Load the block engine's tip accounts
+    pub async fn new(engine: Box<dyn BlockEngine>, tip_lamports: u64) -> Result<Self> {
+        let tip_accounts = engine.tip_accounts().await?;
+        if tip_accounts.is_empty() {
+            bail!("block engine returned no tip accounts");
+        }
+        info!("Loaded {} bundle tip accounts", tip_accounts.len());
+
+        Ok(Self {
+            engine,
+            tip_accounts,
+            tip_lamports,
+            next_tip_account: AtomicUsize::new(0),
+        })
+    }
+
+    /// Tip transfer from `payer`, rotating through the tip accounts
+    pub fn tip_instruction(&self, payer: &Pubkey) -> Instruction {
+        let index = self.next_tip_account.fetch_add(1, Ordering::Relaxed) % self.tip_accounts.len();
+        system_instruction::transfer(payer, &self.tip_accounts[index], self.tip_lamports)
+    }
+
+    pub async fn submit(&self, transaction: &Transaction) -> Result<String> {
+        self.engine.send_bundle(std::slice::from_ref(transaction)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::hash::Hash;
+    use solana_sdk::signature::{Keypair, Signer};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::mpsc;
+
+    const TIP_ACCOUNT: &str = "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5";
+
+    /// Local block engine stand-in: answers JSON-RPC POSTs with `respond` and
+    /// forwards each request body to the test
+    async fn spawn_block_engine(
+        respond: fn(&Value) -> Value,
+    ) -> (String, mpsc::UnboundedReceiver<(String, Value)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (requests_tx, requests_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let (path, body) = read_request(&mut stream).await;
+                let request: Value = serde_json::from_slice(&body).unwrap();
+
+                let response = respond(&request).to_string();
+                let http = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                );
+                stream.write_all(http.as_bytes()).await.unwrap();
+                stream.shutdown().await.unwrap();
+
+                requests_tx.send((path, request)).unwrap();
+            }
+        });
+
+        (url, requests_rx)
+    }
+
+    /// Request path and body of one HTTP/1.1 request
+    async fn read_request(stream: &mut tokio::net::TcpStream) -> (String, Vec<u8>) {
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let read = stream.read(&mut chunk).await.unwrap();
+            assert!(read > 0, "connection closed mid-request");
+            buffer.extend_from_slice(&chunk[..read]);
+
+            let Some(header_end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") else {
+                continue;
+            };
+            let headers = String::from_utf8_lossy(&buffer[..header_end]).to_lowercase();
+            let content_length: usize = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map(|value| value.trim().parse().unwrap())
+                .unwrap_or(0);
+
+            let body_start = header_end + 4;
+            if buffer.len() >= body_start + content_length {
+                let path = headers.split_whitespace().nth(1).unwrap_or_default().to_string();
+                return (path, buffer[body_start..body_start + content_length].to_vec());
+            }
+        }
+    }
+
+    fn jito_responses(request: &Value) -> Value {
+        match request["method"].as_str().unwrap() {
+            "getTipAccounts" => json!({ "jsonrpc": "2.0", "id": 1, "result": [TIP_ACCOUNT] }),
+            "sendBundle" => json!({ "jsonrpc": "2.0", "id": 1, "result": "bundle-1" }),
+            _ => json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": -32601, "message": "not found" } }),
+        }
+    }
+
+    fn transfer(payer: &Keypair) -> Transaction {
+        Transaction::new_signed_with_payer(
+            &[system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1)],
+            Some(&payer.pubkey()),
+            &[payer],
+            Hash::new_unique(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_bundle_is_sent_to_block_engine() {
+        let (url, mut requests) = spawn_block_engine(jito_responses).await;
+        let bundler = Bundler::new(Box::new(JitoBlockEngine::new(&url)), 10_000)
+            .await
+            .unwrap();
+
+        let (path, request) = requests.recv().await.unwrap();
+        assert_eq!(path, "/api/v1/bundles");
+        assert_eq!(request["method"], "getTipAccounts");
+
+        let payer = Keypair::new();
+        let transaction = transfer(&payer);
+        assert_eq!(bundler.submit(&transaction).await.unwrap(), "bundle-1");
+
+        let (_, request) = requests.recv().await.unwrap();
+        assert_eq!(request["method"], "sendBundle");
+        assert_eq!(request["params"][1]["encoding"], "base64");
+        let encoded = request["params"][0][0].as_str().unwrap();
+        let sent: Transaction = bincode::deserialize(&BASE64.decode(encoded).unwrap()).unwrap();
+        assert_eq!(sent, transaction);
+    }
+
+    #[tokio::test]
+    async fn test_tip_goes_to_block_engine_tip_account() {
+        let (url, _requests) = spawn_block_engine(jito_responses).await;
+        let bundler = Bundler::new(Box::new(JitoBlockEngine::new(&url)), 10_000)
+            .await
+            .unwrap();
+
+        let payer = Pubkey::new_unique();
+        let tip = bundler.tip_instruction(&payer);
+        assert_eq!(tip, system_instruction::transfer(&payer, &Pubkey::from_str(TIP_ACCOUNT).unwrap(), 10_000));
+    }
+
+    #[tokio::test]
+    async fn test_block_engine_errors_are_reported() {
+        let (url, _requests) = spawn_block_engine(|_| {
+            json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": -32602, "message": "bundle rejected" } })
+        })
+        .await;
+        let engine = JitoBlockEngine::new(&url);
+
+        let err = engine.send_bundle(&[transfer(&Keypair::new())]).await.unwrap_err();
+        assert!(err.to_string().contains("bundle rejected"));
+        assert!(Bundler::new(Box::new(engine), 10_000).await.is_err());
+    }
+}
@@ -0,0 +1,127 @@
+/*!
+ * Configuration management
+ */
+
+use anyhow::Result;
+use std::env;
+
+#[derive(Clone)]
+pub struct Config {
+    pub helius_rpc: String,
+    pub redis_url: String,
+    pub executor_keypair_path: String,
+    pub dry_run: bool,
+    pub max_borrow_lamports: u64,
+    pub min_profit_lamports: u64,
+    pub opportunity_max_age_seconds: i64,
+    pub outcome_history_size: usize,
+    pub jito_block_engine_url: Option<String>,
+    pub jito_tip_lamports: u64,
+    pub bundle_confirm_timeout_seconds: u64,
+    pub priority_fee_percentile: u8,
+    pub min_compute_unit_price: u64,
+    pub max_compute_unit_price: u64,
+    pub compute_unit_margin_percent: u64,
+    pub execution_engine_program_id: String,
+    pub flash_loan_program_id: String,
+    pub strategy_registry_program_id: String,
+    pub dao_governance_program_id: String,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self> {
+        dotenvy::dotenv().ok();
+
+        Ok(Config {
+            helius_rpc: env::var("HELIUS_RPC")
+                .expect("HELIUS_RPC must be set"),
+            redis_url: env::var("REDIS_URL")
+                .unwrap_or_else(|_| "redis://localhost:6379".to_string()),
+            executor_keypair_path: env::var("EXECUTOR_KEYPAIR_PATH")
+                .expect("EXECUTOR_KEYPAIR_PATH must be set"),
+            dry_run: env::var("DRY_RUN")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .expect("DRY_RUN must be true or false"),
+            max_borrow_lamports: env::var("MAX_BORROW_LAMPORTS")
+                .unwrap_or_else(|_| "10000000000".to_string())
+                .parse()
+                .expect("MAX_BORROW_LAMPORTS must be a valid u64"),
+            min_profit_lamports: env::var("MIN_PROFIT_LAMPORTS")
+                .unwrap_or_else(|_| "1000000".to_string())
+                .parse()
+                .expect("MIN_PROFIT_LAMPORTS must be a valid u64"),
+            opportunity_max_age_seconds: env::var("OPPORTUNITY_MAX_AGE_SECONDS")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .expect("OPPORTUNITY_MAX_AGE_SECONDS must be a valid i64"),
+            outcome_history_size: env::var("OUTCOME_HISTORY_SIZE")
+                .unwrap_or_else(|_| "1000".to_string())
+                .parse()
+                .expect("OUTCOME_HISTORY_SIZE must be a valid usize"),
+            jito_block_engine_url: env::var("JITO_BLOCK_ENGINE_URL")
+                .ok()
+                .filter(|url| !url.is_empty()),
+            jito_tip_lamports: env::var("JITO_TIP_LAMPORTS")
+                .unwrap_or_else(|_| "10000".to_string())
+                .parse()
+                .expect("JITO_TIP_LAMPORTS must be a valid u64"),
+            bundle_confirm_timeout_seconds: env::var("BUNDLE_CONFIRM_TIMEOUT_SECONDS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .expect("BUNDLE_CONFIRM_TIMEOUT_SECONDS must be a valid u64"),
+            priority_fee_percentile: env::var("PRIORITY_FEE_PERCENTILE")
+                .unwrap_or_else(|_| "75".to_string())
+                .parse()
+                .expect("PRIORITY_FEE_PERCENTILE must be a valid u8"),
+            min_compute_unit_price: env::var("MIN_COMPUTE_UNIT_PRICE")
+                .unwrap_or_else(|_| "1000".to_string())
+                .parse()
+                .expect("MIN_COMPUTE_UNIT_PRICE must be a valid u64"),
+            max_compute_unit_price: env::var("MAX_COMPUTE_UNIT_PRICE")
+                .unwrap_or_else(|_| "1000000".to_string())
+                .parse()
+                .expect("MAX_COMPUTE_UNIT_PRICE must be a valid u64"),
+            compute_unit_margin_percent: env::var("COMPUTE_UNIT_MARGIN_PERCENT")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .expect("COMPUTE_UNIT_MARGIN_PERCENT must be a valid u64"),
+            execution_engine_program_id: env::var("EXECUTION_ENGINE_PROGRAM_ID")
+                .unwrap_or_else(|_| "REC2Aq9iAu4hu7efgJhtyFyWS1bSBDYgeoVXXQFtfpk".to_string()),
+            flash_loan_program_id: env::var("FLASH_LOAN_PROGRAM_ID")
+                .unwrap_or_else(|_| "REChcXRRzBxhLvjs4v1HZ1Zx3R6hUnTEVjdKDwwbT9w".to_string()),
+            strategy_registry_program_id: env::var("STRATEGY_REGISTRY_PROGRAM_ID")
+                .unwrap_or_else(|_| "RECjnbr96LG2mDTXzhB5ZVY4JRfnSKmtx1pCgrGbMws".to_string()),
+            dao_governance_program_id: env::var("DAO_GOVERNANCE_PROGRAM_ID")
+                .unwrap_or_else(|_| "RECwcpcHwBeDAV7tBvUuhJzsih16BaveZRC74kbBkSS".to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+impl Config {
+    /// Defaults for unit tests (no network endpoints)
+    pub fn for_tests() -> Self {
+        Config {
+            helius_rpc: String::new(),
+            redis_url: String::new(),
+            executor_keypair_path: String::new(),
+            dry_run: true,
+            max_borrow_lamports: 10_000_000_000,
+            min_profit_lamports: 1_000_000,
+            opportunity_max_age_seconds: 10,
+            outcome_history_size: 100,
+            jito_block_engine_url: None,
+            jito_tip_lamports: 10_000,
+            bundle_confirm_timeout_seconds: 30,
+            priority_fee_percentile: 75,
+            min_compute_unit_price: 1_000,
+            max_compute_unit_price: 1_000_000,
+            compute_unit_margin_percent: 10,
+            execution_engine_program_id: "REC2Aq9iAu4hu7efgJhtyFyWS1bSBDYgeoVXXQFtfpk".to_string(),
+            flash_loan_program_id: "REChcXRRzBxhLvjs4v1HZ1Zx3R6hUnTEVjdKDwwbT9w".to_string(),
+            strategy_registry_program_id: "RECjnbr96LG2mDTXzhB5ZVY4JRfnSKmtx1pCgrGbMws".to_string(),
+            dao_governance_program_id: "RECwcpcHwBeDAV7tBvUuhJzsih16BaveZRC74kbBkSS".to_string(),
+        }
+    }
+}
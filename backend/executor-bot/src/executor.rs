@@ -0,0 +1,646 @@
+/*!
+ * Opportunity execution
+ *
+ * For each opportunity:
+ *
+ * 1. Skip stale routes, routes that do not borrow and repay SOL, and routes
+ *    without an approved strategy
+ * 2. Load `ProfitConfig`, the flash loan pool and the candidate strategies
+ * 3. Size the borrow (suggested amount capped by `MAX_BORROW_LAMPORTS` and
+ *    the strategy's `max_borrow`) and the on-chain `min_profit`
+ *    (`MIN_PROFIT_LAMPORTS` or the strategy's threshold, whichever is higher)
+ * 4. Price compute from recent prioritization fees and simulate
+ *    `execute_strategy` at the maximum compute-unit limit; in dry-run mode
+ *    stop here
+ * 5. Rebuild with the limit sized from simulation, sign, and send it either
+ *    as a tipped Jito bundle (when a block engine is configured) or through
+ *    the RPC, then confirm
+ *
+ * Every opportunity produces an `ExecutionOutcome`, which is recorded.
+ */
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, error, info, warn};
+
+use crate::accounts::{execute_strategy_ix, ExecuteStrategyAccounts, ProgramIds, WSOL_MINT};
+use crate::bundle::Bundler;
+use crate::config::Config;
+use crate::fees::{compute_budget_instructions, compute_unit_limit, compute_unit_price, MAX_COMPUTE_UNIT_LIMIT};
+use crate::recorder::OutcomeRecorder;
+use crate::rpc::ExecutorRpc;
+use crate::state::{FlashLoanPool, ProfitConfig, Strategy};
+use crate::types::{ArbitrageOpportunity, ExecutionOutcome, ExecutionStatus};
+
+/// Smallest borrow the flash loan program accepts (0.01 SOL)
+const MIN_BORROW_AMOUNT: u64 = 10_000_000;
+
+/// Borrow and minimum profit for one strategy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionPlan {
+    pub borrow_amount: u64,
+    pub min_profit: u64,
+}
+
+/// Size an opportunity for a strategy, or explain why it cannot execute it
+pub fn plan_execution(
+    opportunity: &ArbitrageOpportunity,
+    strategy: &Strategy,
+    config: &Config,
+) -> Result<ExecutionPlan, String> {
+    let borrow_amount = opportunity
+        .suggested_amount
+        .min(config.max_borrow_lamports)
+        .min(strategy.max_borrow);
+    if borrow_amount < MIN_BORROW_AMOUNT {
+        return Err(format!(
+            "borrow {} below flash loan minimum {}",
+            borrow_amount, MIN_BORROW_AMOUNT
+        ));
+    }
+
+    // Profit is concave in trade size, so scaling it down linearly is conservative
+    let expected_profit = (opportunity.expected_profit as u128 * borrow_amount as u128
+        / opportunity.suggested_amount as u128) as u64;
+    let min_profit = config
+        .min_profit_lamports
+        .max(strategy.min_profit_for(borrow_amount));
+    if expected_profit < min_profit {
+        return Err(format!(
+            "expected profit {} below minimum {}",
+            expected_profit, min_profit
+        ));
+    }
+
+    Ok(ExecutionPlan {
+        borrow_amount,
+        min_profit,
+    })
+}
+
+/// Why an opportunity cannot be executed at all, regardless of strategy
+fn route_skip_reason(opportunity: &ArbitrageOpportunity, config: &Config, now: i64) -> Option<String> {
+    let age = now - opportunity.timestamp;
+    if age > config.opportunity_max_age_seconds {
+        return Some(format!("stale ({}s old)", age));
+    }
+
+    // Flash loans are WSOL only
+    let wsol = WSOL_MINT.to_string();
+    let borrows_sol = matches!(
+        (opportunity.hops.first(), opportunity.hops.last()),
+        (Some(first), Some(last)) if first.token_in == wsol && last.token_out == wsol
+    );
+    if !borrows_sol {
+        return Some("route does not borrow and repay SOL".to_string());
+    }
+
+    if opportunity.suggested_amount == 0 {
+        return Some("no suggested amount".to_string());
+    }
+
+    if opportunity.strategies.is_empty() {
+        return Some("no approved strategy".to_string());
+    }
+
+    None
+}
+
+pub struct Executor<R: ExecutorRpc, O: OutcomeRecorder> {
+    config: Arc<Config>,
+    programs: ProgramIds,
+    rpc: R,
+    recorder: O,
+    keypair: Keypair,
+    bundler: Option<Bundler>,
+}
+
+impl<R: ExecutorRpc, O: OutcomeRecorder> Executor<R, O> {
+    pub fn new(config: Arc<Config>, rpc: R, recorder: O, keypair: Keypair) -> Result<Self> {
+        Ok(Self {
+            programs: ProgramIds::from_config(&config)?,
+            config,
+            rpc,
+            recorder,
+            keypair,
+            bundler: None,
+        })
+    }
+
+    /// Submit executions as Jito bundles instead of through the RPC
+    pub fn with_bundler(mut self, bundler: Bundler) -> Self {
+        self.bundler = Some(bundler);
+        self
+    }
+
+    /// Execute (or dry-run) an opportunity and record the outcome
+    pub async fn handle(&self, opportunity: &ArbitrageOpportunity) -> ExecutionOutcome {
+        let mut outcome = ExecutionOutcome {
+            opportunity_id: opportunity.id.clone(),
+            status: ExecutionStatus::Skipped,
+            strategy: None,
+            borrow_amount: 0,
+            min_profit: 0,
+            signature: None,
+            bundle_id: None,
+            units_consumed: None,
+            compute_unit_limit: None,
+            compute_unit_price: None,
+            detail: None,
+            timestamp: Utc::now().timestamp(),
+        };
+
+        if let Err(e) = self.execute(opportunity, &mut outcome).await {
+            outcome.status = ExecutionStatus::Failed;
+            outcome.detail = Some(e.to_string());
+        }
+
+        match outcome.status {
+            ExecutionStatus::Skipped => debug!(
+                "Skipped {}: {}",
+                outcome.opportunity_id,
+                outcome.detail.as_deref().unwrap_or_default()
+            ),
+            ExecutionStatus::Simulated | ExecutionStatus::Confirmed => info!(
+                "{:?} {} (borrow {}, min profit {})",
+                outcome.status, outcome.opportunity_id, outcome.borrow_amount, outcome.min_profit
+            ),
+            ExecutionStatus::SimulationFailed | ExecutionStatus::Failed => warn!(
+                "{:?} {}: {}",
+                outcome.status,
+                outcome.opportunity_id,
+                outcome.detail.as_deref().unwrap_or_default()
+            ),
+        }
+
+        if let Err(e) = self.recorder.record(&outcome).await {
+            error!("Failed to record outcome for {}: {}", outcome.opportunity_id, e);
+        }
+
+        outcome
+    }
+
+    async fn execute(
+        &self,
+        opportunity: &ArbitrageOpportunity,
+        outcome: &mut ExecutionOutcome,
+    ) -> Result<()> {
+        if let Some(reason) = route_skip_reason(opportunity, &self.config, outcome.timestamp) {
+            outcome.detail = Some(reason);
+            return Ok(());
+        }
+
+        // Global accounts first, then each candidate strategy
+        let candidates: Vec<Pubkey> = opportunity
+            .strategies
+            .iter()
+            .filter_map(|key| Pubkey::from_str(key).ok())
+            .collect();
+        let mut keys = vec![self.programs.profit_config(), self.programs.flash_loan_pool()];
+        keys.extend(&candidates);
+        let accounts = self.rpc.get_accounts(&keys).await?;
+
+        let profit_config = ProfitConfig::decode(
+            accounts[0]
+                .as_deref()
+                .ok_or_else(|| anyhow!("ProfitConfig account not found"))?,
+        )?;
+        let flash_loan_pool = FlashLoanPool::decode(
+            accounts[1]
+                .as_deref()
+                .ok_or_else(|| anyhow!("FlashLoanPool account not found"))?,
+        )?;
+
+        // First strategy that can execute the route
+        let mut selected = None;
+        let mut skip_reason = "no executable strategy".to_string();
+        for (key, data) in candidates.iter().zip(&accounts[2..]) {
+            let Some(strategy) = data.as_deref().and_then(|data| Strategy::decode(data).ok()) else {
+                continue;
+            };
+            if !strategy.is_executable() {
+                continue;
+            }
+            match plan_execution(opportunity, &strategy, &self.config) {
+                Ok(plan) => {
+                    selected = Some((*key, strategy, plan));
+                    break;
+                }
+                Err(reason) => skip_reason = reason,
+            }
+        }
+        let Some((strategy_key, strategy, plan)) = selected else {
+            outcome.detail = Some(skip_reason);
+            return Ok(());
+        };
+
+        outcome.strategy = Some(strategy_key.to_string());
+        outcome.borrow_amount = plan.borrow_amount;
+        outcome.min_profit = plan.min_profit;
+
+        let executor = self.keypair.pubkey();
+        let accounts = ExecuteStrategyAccounts::new(
+            &self.programs,
+            strategy_key,
+            strategy.creator,
+            executor,
+            profit_config.treasury,
+            flash_loan_pool.pool_token_account,
+        );
+        let instruction = execute_strategy_ix(
+            self.programs.execution_engine,
+            &accounts,
+            plan.borrow_amount,
+            plan.min_profit,
+        );
+
+        let price = self.compute_unit_price(&instruction).await;
+        outcome.compute_unit_price = Some(price);
+        let tip = self.bundler.as_ref().map(|bundler| bundler.tip_instruction(&executor));
+
+        // Simulate at the maximum limit, then size the limit from what was used
+        let blockhash = self.rpc.latest_blockhash().await?;
+        let transaction = self.sign(&instruction, tip.as_ref(), MAX_COMPUTE_UNIT_LIMIT, price, blockhash);
+
+        let simulation = self.rpc.simulate(&transaction).await?;
+        outcome.units_consumed = simulation.units_consumed;
+        if let Some(err) = simulation.err {
+            outcome.status = ExecutionStatus::SimulationFailed;
+            // The last program log usually names the failing check
+            outcome.detail = Some(match simulation.logs.last() {
+                Some(log) => format!("{} ({})", err, log),
+                None => err,
+            });
+            return Ok(());
+        }
+
+        let limit = compute_unit_limit(simulation.units_consumed, self.config.compute_unit_margin_percent);
+        outcome.compute_unit_limit = Some(limit);
+
+        if self.config.dry_run {
+            outcome.status = ExecutionStatus::Simulated;
+            return Ok(());
+        }
+
+        let transaction = self.sign(&instruction, tip.as_ref(), limit, price, blockhash);
+        let signature = transaction.signatures[0];
+        outcome.signature = Some(signature.to_string());
+
+        let result = match &self.bundler {
+            Some(bundler) => match bundler.submit(&transaction).await {
+                Ok(bundle_id) => {
+                    outcome.bundle_id = Some(bundle_id);
+                    let timeout = Duration::from_secs(self.config.bundle_confirm_timeout_seconds);
+                    self.rpc.wait_for_signature(&signature, timeout).await
+                }
+                Err(e) => Err(e),
+            },
+            None => self.rpc.send_and_confirm(&transaction).await.map(|_| ()),
+        };
+
+        match result {
+            Ok(()) => outcome.status = ExecutionStatus::Confirmed,
+            Err(e) => {
+                outcome.status = ExecutionStatus::Failed;
+                outcome.detail = Some(e.to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Percentile of recent fees paid for the instruction's writable accounts
+    async fn compute_unit_price(&self, instruction: &Instruction) -> u64 {
+        let writable: Vec<Pubkey> = instruction
+            .accounts
+            .iter()
+            .filter(|meta| meta.is_writable)
+            .map(|meta| meta.pubkey)
+            .collect();
+
+        let recent_fees = match self.rpc.recent_prioritization_fees(&writable).await {
+            Ok(fees) => fees,
+            Err(e) => {
+                warn!("Failed to fetch prioritization fees, using minimum price: {}", e);
+                Vec::new()
+            }
+        };
+
+        compute_unit_price(
+            &recent_fees,
+            self.config.priority_fee_percentile,
+            self.config.min_compute_unit_price,
+            self.config.max_compute_unit_price,
+        )
+    }
+
+    /// Compute budget, `execute_strategy`, then the bundle tip (if any)
+    fn sign(
+        &self,
+        instruction: &Instruction,
+        tip: Option<&Instruction>,
+        limit: u32,
+        price: u64,
+        blockhash: Hash,
+    ) -> Transaction {
+        let mut instructions = compute_budget_instructions(limit, price).to_vec();
+        instructions.push(instruction.clone());
+        instructions.extend(tip.cloned());
+
+        Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&self.keypair.pubkey()),
+            &[&self.keypair],
+            blockhash,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bundle::BlockEngine;
+    use crate::rpc::Simulation;
+    use crate::state::tests::{encode_flash_loan_pool, encode_profit_config, encode_strategy};
+    use crate::types::{DexType, Hop};
+    use async_trait::async_trait;
+    use solana_sdk::compute_budget::ComputeBudgetInstruction;
+    use solana_sdk::signature::Signature;
+    use solana_sdk::system_instruction;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockRpc {
+        accounts: HashMap<Pubkey, Vec<u8>>,
+        simulation_err: Option<String>,
+        recent_fees: Vec<u64>,
+        sent: Mutex<Vec<Transaction>>,
+        awaited: Mutex<Vec<Signature>>,
+    }
+
+    #[async_trait]
+    impl ExecutorRpc for MockRpc {
+        async fn get_accounts(&self, keys: &[Pubkey]) -> Result<Vec<Option<Vec<u8>>>> {
+            Ok(keys.iter().map(|key| self.accounts.get(key).cloned()).collect())
+        }
+
+        async fn latest_blockhash(&self) -> Result<Hash> {
+            Ok(Hash::new_unique())
+        }
+
+        async fn simulate(&self, _transaction: &Transaction) -> Result<Simulation> {
+            Ok(Simulation {
+                err: self.simulation_err.clone(),
+                logs: vec!["Program log: AnchorError: SlippageExceeded".to_string()],
+                units_consumed: Some(120_000),
+            })
+        }
+
+        async fn recent_prioritization_fees(&self, _accounts: &[Pubkey]) -> Result<Vec<u64>> {
+            Ok(self.recent_fees.clone())
+        }
+
+        async fn send_and_confirm(&self, transaction: &Transaction) -> Result<Signature> {
+            self.sent.lock().unwrap().push(transaction.clone());
+            Ok(transaction.signatures[0])
+        }
+
+        async fn wait_for_signature(&self, signature: &Signature, _timeout: Duration) -> Result<()> {
+            self.awaited.lock().unwrap().push(*signature);
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MockBlockEngine {
+        bundles: Arc<Mutex<Vec<Vec<Transaction>>>>,
+    }
+
+    #[async_trait]
+    impl BlockEngine for MockBlockEngine {
+        async fn tip_accounts(&self) -> Result<Vec<Pubkey>> {
+            Ok(vec![TIP_ACCOUNT])
+        }
+
+        async fn send_bundle(&self, transactions: &[Transaction]) -> Result<String> {
+            self.bundles.lock().unwrap().push(transactions.to_vec());
+            Ok("bundle".to_string())
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryRecorder {
+        outcomes: Mutex<Vec<ExecutionOutcome>>,
+    }
+
+    #[async_trait]
+    impl OutcomeRecorder for MemoryRecorder {
+        async fn record(&self, outcome: &ExecutionOutcome) -> Result<()> {
+            self.outcomes.lock().unwrap().push(outcome.clone());
+            Ok(())
+        }
+    }
+
+    const STRATEGY: Pubkey = solana_sdk::pubkey!("7Yk2hWUs9mcDvuZ7DWDFUXqDuVoxCpYm8yDpvmxVfDvG");
+    const TIP_ACCOUNT: Pubkey = solana_sdk::pubkey!("96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5");
+
+    fn rpc(config: &Config, max_borrow: u64) -> MockRpc {
+        let programs = ProgramIds::from_config(config).unwrap();
+        let mut accounts = HashMap::new();
+        accounts.insert(programs.profit_config(), encode_profit_config(Pubkey::new_unique()));
+        accounts.insert(programs.flash_loan_pool(), encode_flash_loan_pool(Pubkey::new_unique()));
+        accounts.insert(STRATEGY, encode_strategy(Pubkey::new_unique(), 50, 1, max_borrow));
+        MockRpc {
+            accounts,
+            ..Default::default()
+        }
+    }
+
+    fn hop(token_in: &str, token_out: &str) -> Hop {
+        Hop {
+            dex: DexType::Raydium,
+            pool: "pool".to_string(),
+            token_in: token_in.to_string(),
+            token_out: token_out.to_string(),
+        }
+    }
+
+    /// Borrow 5 SOL for 0.05 SOL profit
+    fn opportunity() -> ArbitrageOpportunity {
+        let sol = WSOL_MINT.to_string();
+        let usdc = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+        ArbitrageOpportunity {
+            id: "opp".to_string(),
+            buy_dex: DexType::Raydium,
+            buy_pool: "pool".to_string(),
+            sell_dex: DexType::Raydium,
+            sell_pool: "pool".to_string(),
+            token_a: sol.clone(),
+            token_b: usdc.to_string(),
+            buy_price: 1.0,
+            sell_price: 1.01,
+            profit_bps: 100,
+            expected_profit: 50_000_000,
+            suggested_amount: 5_000_000_000,
+            timestamp: Utc::now().timestamp(),
+            hops: vec![hop(&sol, usdc), hop(usdc, &sol)],
+            strategies: vec![STRATEGY.to_string()],
+        }
+    }
+
+    fn executor(config: Config, rpc: MockRpc) -> Executor<MockRpc, MemoryRecorder> {
+        Executor::new(Arc::new(config), rpc, MemoryRecorder::default(), Keypair::new()).unwrap()
+    }
+
+    #[test]
+    fn test_plan_caps_borrow_and_applies_profit_floor() {
+        let mut config = Config::for_tests();
+        config.max_borrow_lamports = 2_000_000_000;
+        let strategy = Strategy::decode(&encode_strategy(Pubkey::new_unique(), 50, 1, 4_000_000_000)).unwrap();
+
+        // Capped at 2 SOL: profit scales to 0.02 SOL, threshold is 0.01 SOL
+        let plan = plan_execution(&opportunity(), &strategy, &config).unwrap();
+        assert_eq!(
+            plan,
+            ExecutionPlan {
+                borrow_amount: 2_000_000_000,
+                min_profit: 10_000_000,
+            }
+        );
+
+        config.min_profit_lamports = 30_000_000;
+        assert!(plan_execution(&opportunity(), &strategy, &config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_simulates_without_sending() {
+        let config = Config::for_tests();
+        let executor = executor(config.clone(), rpc(&config, 10_000_000_000));
+
+        let outcome = executor.handle(&opportunity()).await;
+
+        assert_eq!(outcome.status, ExecutionStatus::Simulated);
+        assert_eq!(outcome.strategy, Some(STRATEGY.to_string()));
+        assert_eq!(outcome.borrow_amount, 5_000_000_000);
+        assert_eq!(outcome.min_profit, 25_000_000);
+        assert_eq!(outcome.units_consumed, Some(120_000));
+        assert!(executor.rpc.sent.lock().unwrap().is_empty());
+        assert_eq!(executor.recorder.outcomes.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_live_mode_sends_signed_execute_strategy() {
+        let mut config = Config::for_tests();
+        config.dry_run = false;
+        let executor = executor(config.clone(), rpc(&config, 10_000_000_000));
+
+        let outcome = executor.handle(&opportunity()).await;
+
+        assert_eq!(outcome.status, ExecutionStatus::Confirmed);
+        let sent = executor.rpc.sent.lock().unwrap();
+        assert_eq!(sent.len(), 1);
+        assert!(sent[0].verify().is_ok());
+        assert_eq!(outcome.signature, Some(sent[0].signatures[0].to_string()));
+
+        // Compute budget sized from the 120k simulated units, then execute_strategy
+        let message = &sent[0].message;
+        let instructions: Vec<_> = message
+            .instructions
+            .iter()
+            .map(|ix| (message.account_keys[ix.program_id_index as usize], ix.data.clone()))
+            .collect();
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(instructions[0].1, ComputeBudgetInstruction::set_compute_unit_limit(132_000).data);
+        assert_eq!(instructions[1].1, ComputeBudgetInstruction::set_compute_unit_price(1_000).data);
+        assert_eq!(instructions[2].0, executor.programs.execution_engine);
+        assert_eq!(&instructions[2].1[8..16], &5_000_000_000u64.to_le_bytes());
+        assert_eq!(outcome.compute_unit_limit, Some(132_000));
+    }
+
+    #[tokio::test]
+    async fn test_bundle_mode_tips_and_bypasses_rpc() {
+        let mut config = Config::for_tests();
+        config.dry_run = false;
+        config.jito_block_engine_url = Some("http://block-engine".to_string());
+        let mut rpc = rpc(&config, 10_000_000_000);
+        rpc.recent_fees = vec![500, 2_000, 8_000, 40_000, 5_000_000];
+
+        let engine = MockBlockEngine::default();
+        let bundles = engine.bundles.clone();
+        let bundler = Bundler::new(Box::new(engine), config.jito_tip_lamports).await.unwrap();
+        let executor = executor(config.clone(), rpc).with_bundler(bundler);
+
+        let outcome = executor.handle(&opportunity()).await;
+
+        assert_eq!(outcome.status, ExecutionStatus::Confirmed, "{:?}", outcome.detail);
+        assert_eq!(outcome.bundle_id, Some("bundle".to_string()));
+        assert_eq!(outcome.compute_unit_price, Some(40_000));
+        assert!(executor.rpc.sent.lock().unwrap().is_empty());
+
+        let bundles = bundles.lock().unwrap();
+        assert_eq!(bundles.len(), 1);
+        let transaction = &bundles[0][0];
+        assert!(transaction.verify().is_ok());
+        assert_eq!(*executor.rpc.awaited.lock().unwrap(), vec![transaction.signatures[0]]);
+
+        // The tip is the last instruction, so it is only paid if execution succeeds
+        let tip = transaction.message.instructions.last().unwrap();
+        let expected = system_instruction::transfer(&executor.keypair.pubkey(), &TIP_ACCOUNT, 10_000);
+        assert_eq!(tip.data, expected.data);
+        assert_eq!(transaction.message.account_keys[tip.accounts[1] as usize], TIP_ACCOUNT);
+    }
+
+    #[tokio::test]
+    async fn test_failed_simulation_is_not_sent() {
+        let mut config = Config::for_tests();
+        config.dry_run = false;
+        let mut rpc = rpc(&config, 10_000_000_000);
+        rpc.simulation_err = Some("custom program error: 0x1771".to_string());
+        let executor = executor(config, rpc);
+
+        let outcome = executor.handle(&opportunity()).await;
+
+        assert_eq!(outcome.status, ExecutionStatus::SimulationFailed);
+        assert!(outcome.detail.unwrap().contains("SlippageExceeded"));
+        assert!(executor.rpc.sent.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unexecutable_routes_are_skipped() {
+        let config = Config::for_tests();
+
+        // Borrows USDC
+        let mut usdc_route = opportunity();
+        usdc_route.hops = vec![
+            hop(&usdc_route.token_b, &usdc_route.token_a),
+            hop(&usdc_route.token_a, &usdc_route.token_b),
+        ];
+
+        let mut stale = opportunity();
+        stale.timestamp -= 60;
+
+        let mut unapproved = opportunity();
+        unapproved.strategies.clear();
+
+        let executor = executor(config.clone(), rpc(&config, 10_000_000_000));
+        for opportunity in [usdc_route, stale, unapproved] {
+            let outcome = executor.handle(&opportunity).await;
+            assert_eq!(outcome.status, ExecutionStatus::Skipped, "{:?}", outcome.detail);
+        }
+
+        // Strategy borrow cap below the flash loan minimum
+        let executor_capped = self::executor(config.clone(), rpc(&config, 1_000));
+        let outcome = executor_capped.handle(&opportunity()).await;
+        assert_eq!(outcome.status, ExecutionStatus::Skipped);
+        assert!(outcome.detail.unwrap().contains("below flash loan minimum"));
+    }
+}
@@ -0,0 +1,72 @@
+/*!
+ * Compute budget sizing
+ *
+ * The compute-unit price is a percentile of the recent prioritization fees
+ * paid for the transaction's writable accounts, clamped to the configured
+ * range. The compute-unit limit is the simulated consumption plus a margin,
+ * so the transaction neither runs out of compute nor pays priority fees for
+ * units it does not use.
+ */
+
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+
+/// Largest compute-unit limit a transaction can request
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Compute-unit price (micro-lamports) at `percentile` of recent fees
+pub fn compute_unit_price(recent_fees: &[u64], percentile: u8, min_price: u64, max_price: u64) -> u64 {
+    if recent_fees.is_empty() {
+        return min_price;
+    }
+
+    let mut fees = recent_fees.to_vec();
+    fees.sort_unstable();
+    let index = (fees.len() - 1) * percentile.min(100) as usize / 100;
+
+    fees[index].clamp(min_price, max_price)
+}
+
+/// Compute-unit limit for a simulated consumption plus `margin_percent`
+pub fn compute_unit_limit(units_consumed: Option<u64>, margin_percent: u64) -> u32 {
+    match units_consumed {
+        Some(units) => {
+            let limit = units.saturating_mul(100 + margin_percent) / 100;
+            limit.min(MAX_COMPUTE_UNIT_LIMIT as u64) as u32
+        }
+        None => MAX_COMPUTE_UNIT_LIMIT,
+    }
+}
+
+/// Compute budget instructions to prepend to a transaction
+pub fn compute_budget_instructions(limit: u32, price: u64) -> [Instruction; 2] {
+    [
+        ComputeBudgetInstruction::set_compute_unit_limit(limit),
+        ComputeBudgetInstruction::set_compute_unit_price(price),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_is_clamped_percentile_of_recent_fees() {
+        let fees = [0, 0, 5_000, 1_000, 20_000, 2_000, 0, 10_000, 3_000, 4_000, 50_000];
+
+        assert_eq!(compute_unit_price(&fees, 50, 0, u64::MAX), 3_000);
+        assert_eq!(compute_unit_price(&fees, 75, 0, u64::MAX), 5_000);
+        assert_eq!(compute_unit_price(&fees, 100, 0, u64::MAX), 50_000);
+
+        assert_eq!(compute_unit_price(&fees, 10, 1_000, 100_000), 1_000);
+        assert_eq!(compute_unit_price(&fees, 100, 1_000, 25_000), 25_000);
+        assert_eq!(compute_unit_price(&[], 75, 1_000, 25_000), 1_000);
+    }
+
+    #[test]
+    fn test_limit_adds_margin_to_simulated_units() {
+        assert_eq!(compute_unit_limit(Some(120_000), 10), 132_000);
+        assert_eq!(compute_unit_limit(Some(1_350_000), 10), MAX_COMPUTE_UNIT_LIMIT);
+        assert_eq!(compute_unit_limit(None, 10), MAX_COMPUTE_UNIT_LIMIT);
+    }
+}
@@ -0,0 +1,70 @@
+/*!
+ * MEVrebels Executor Bot
+ *
+ * Consumes arbitrage opportunities from Redis and executes them on-chain
+ * through the execution engine's `execute_strategy`
+ */
+
+mod accounts;
+mod bundle;
+mod config;
+mod executor;
+mod fees;
+mod recorder;
+mod rpc;
+mod state;
+mod subscriber;
+mod types;
+
+use anyhow::{anyhow, Result};
+use solana_sdk::signature::{read_keypair_file, Signer};
+use std::sync::Arc;
+use tokio::signal;
+use tracing::info;
+
+use crate::bundle::{Bundler, JitoBlockEngine};
+use crate::config::Config;
+use crate::executor::Executor;
+use crate::recorder::RedisRecorder;
+use crate::rpc::SolanaRpc;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Initialize tracing
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
+        )
+        .init();
+
+    info!("Starting MEVrebels Executor Bot...");
+
+    // Load configuration
+    let config = Arc::new(Config::from_env()?);
+    let keypair = read_keypair_file(&config.executor_keypair_path)
+        .map_err(|e| anyhow!("Failed to read {}: {}", config.executor_keypair_path, e))?;
+    info!("Configuration loaded (executor {})", keypair.pubkey());
+
+    if config.dry_run {
+        info!("DRY_RUN enabled: transactions are simulated, never sent");
+    }
+
+    let recorder = RedisRecorder::new(&config.redis_url, config.outcome_history_size).await?;
+    let rpc = SolanaRpc::new(&config.helius_rpc);
+    let mut executor = Executor::new(config.clone(), rpc, recorder, keypair)?;
+
+    if let Some(url) = &config.jito_block_engine_url {
+        let bundler = Bundler::new(Box::new(JitoBlockEngine::new(url)), config.jito_tip_lamports).await?;
+        executor = executor.with_bundler(bundler);
+        info!("Submitting executions as bundles to {}", url);
+    }
+
+    tokio::select! {
+        result = subscriber::run(&config.redis_url, &executor) => result?,
+        _ = signal::ctrl_c() => info!("Shutdown signal received"),
+    }
+
+    info!("Executor Bot stopped");
+    Ok(())
+}
@@ -0,0 +1,62 @@
+/*!
+ * Execution outcome recording
+ *
+ * Every opportunity handled produces an `ExecutionOutcome`. The Redis
+ * recorder keeps the latest `OUTCOME_HISTORY_SIZE` outcomes in the
+ * `executions` list and publishes each one on the `executions` channel.
+ */
+
+use anyhow::Result;
+use async_trait::async_trait;
+use redis::{aio::ConnectionManager, Client};
+use tracing::info;
+
+use crate::types::ExecutionOutcome;
+
+/// Redis list and pub/sub channel outcomes are written to
+pub const EXECUTIONS_KEY: &str = "executions";
+
+/// Destination for execution outcomes
+#[async_trait]
+pub trait OutcomeRecorder: Send + Sync {
+    async fn record(&self, outcome: &ExecutionOutcome) -> Result<()>;
+}
+
+pub struct RedisRecorder {
+    conn: ConnectionManager,
+    history_size: usize,
+}
+
+impl RedisRecorder {
+    pub async fn new(redis_url: &str, history_size: usize) -> Result<Self> {
+        let redis_client = Client::open(redis_url)?;
+        let mut conn = ConnectionManager::new(redis_client).await?;
+
+        // Test connection
+        let _: String = redis::cmd("PING").query_async(&mut conn).await?;
+
+        info!("Redis recorder connected");
+
+        Ok(Self { conn, history_size })
+    }
+}
+
+#[async_trait]
+impl OutcomeRecorder for RedisRecorder {
+    async fn record(&self, outcome: &ExecutionOutcome) -> Result<()> {
+        let payload = serde_json::to_string(outcome)?;
+        let mut conn = self.conn.clone();
+
+        redis::pipe()
+            .lpush(EXECUTIONS_KEY, &payload)
+            .ignore()
+            .ltrim(EXECUTIONS_KEY, 0, self.history_size as isize - 1)
+            .ignore()
+            .publish(EXECUTIONS_KEY, &payload)
+            .ignore()
+            .query_async::<_, ()>(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+}
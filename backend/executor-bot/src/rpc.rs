@@ -0,0 +1,118 @@
+/*!
+ * Solana RPC access used by the executor
+ *
+ * Behind a trait so the execution pipeline can be tested without a cluster.
+ */
+
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::Hash;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::Transaction;
+use std::time::{Duration, Instant};
+use tokio::time;
+
+const SIGNATURE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Result of simulating a transaction
+#[derive(Debug, Clone, Default)]
+pub struct Simulation {
+    /// Transaction error, if the simulation failed
+    pub err: Option<String>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+#[async_trait]
+pub trait ExecutorRpc: Send + Sync {
+    /// Account data for each key, `None` for accounts that do not exist
+    async fn get_accounts(&self, keys: &[Pubkey]) -> Result<Vec<Option<Vec<u8>>>>;
+
+    async fn latest_blockhash(&self) -> Result<Hash>;
+
+    async fn simulate(&self, transaction: &Transaction) -> Result<Simulation>;
+
+    /// Prioritization fees (micro-lamports per compute unit) paid in recent
+    /// slots by transactions writing to `accounts`
+    async fn recent_prioritization_fees(&self, accounts: &[Pubkey]) -> Result<Vec<u64>>;
+
+    /// Send a signed transaction and wait for confirmation
+    async fn send_and_confirm(&self, transaction: &Transaction) -> Result<Signature>;
+
+    /// Wait for a transaction sent elsewhere (e.g. in a bundle) to confirm
+    async fn wait_for_signature(&self, signature: &Signature, timeout: Duration) -> Result<()>;
+}
+
+/// JSON-RPC client at `confirmed` commitment
+pub struct SolanaRpc {
+    client: RpcClient,
+}
+
+impl SolanaRpc {
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            client: RpcClient::new_with_commitment(rpc_url.to_string(), CommitmentConfig::confirmed()),
+        }
+    }
+}
+
+#[async_trait]
+impl ExecutorRpc for SolanaRpc {
+    async fn get_accounts(&self, keys: &[Pubkey]) -> Result<Vec<Option<Vec<u8>>>> {
+        let accounts = self.client.get_multiple_accounts(keys).await?;
+        Ok(accounts.into_iter().map(|account| account.map(|a| a.data)).collect())
+    }
+
+    async fn latest_blockhash(&self) -> Result<Hash> {
+        Ok(self.client.get_latest_blockhash().await?)
+    }
+
+    async fn simulate(&self, transaction: &Transaction) -> Result<Simulation> {
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..Default::default()
+        };
+        let result = self
+            .client
+            .simulate_transaction_with_config(transaction, config)
+            .await?
+            .value;
+
+        Ok(Simulation {
+            err: result.err.map(|err| err.to_string()),
+            logs: result.logs.unwrap_or_default(),
+            units_consumed: result.units_consumed,
+        })
+    }
+
+    async fn recent_prioritization_fees(&self, accounts: &[Pubkey]) -> Result<Vec<u64>> {
+        let fees = self.client.get_recent_prioritization_fees(accounts).await?;
+        Ok(fees.into_iter().map(|fee| fee.prioritization_fee).collect())
+    }
+
+    async fn send_and_confirm(&self, transaction: &Transaction) -> Result<Signature> {
+        Ok(self.client.send_and_confirm_transaction(transaction).await?)
+    }
+
+    async fn wait_for_signature(&self, signature: &Signature, timeout: Duration) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        while Instant::now() < deadline {
+            let status = self
+                .client
+                .get_signature_status_with_commitment(signature, CommitmentConfig::confirmed())
+                .await?;
+            match status {
+                Some(Ok(())) => return Ok(()),
+                Some(Err(err)) => bail!("transaction failed: {}", err),
+                None => time::sleep(SIGNATURE_POLL_INTERVAL).await,
+            }
+        }
+        bail!("not confirmed within {:?}", timeout)
+    }
+}
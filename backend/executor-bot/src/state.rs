@@ -0,0 +1,270 @@
+/*!
+ * On-chain account decoders
+ *
+ * Reads the fields the executor needs from the strategy-registry,
+ * execution-engine and flash-loan Anchor accounts (Borsh layout, little
+ * endian, 8-byte discriminator first).
+ */
+
+use anyhow::{anyhow, bail, Result};
+use solana_sdk::hash::hashv;
+use solana_sdk::pubkey::Pubkey;
+
+/// Anchor account discriminator: sha256("account:<Name>")[..8]
+pub fn account_discriminator(name: &str) -> [u8; 8] {
+    discriminator("account", name)
+}
+
+/// Anchor instruction discriminator: sha256("global:<name>")[..8]
+pub fn instruction_discriminator(name: &str) -> [u8; 8] {
+    discriminator("global", name)
+}
+
+fn discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = hashv(&[format!("{}:{}", namespace, name).as_bytes()]);
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash.to_bytes()[..8]);
+    discriminator
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrategyStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Paused,
+    Retired,
+}
+
+/// strategy-registry `StrategyAccount` fields used for execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Strategy {
+    pub creator: Pubkey,
+    pub profit_threshold: u16,
+    pub status: StrategyStatus,
+    pub emergency_paused: bool,
+    pub max_borrow: u64,
+}
+
+impl Strategy {
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Reader::anchor(data, "StrategyAccount")?;
+
+        let creator = r.pubkey()?;
+        r.skip(8)?; // strategy_id
+        let num_dexs = r.u32()? as usize;
+        r.skip(num_dexs)?;
+        let num_token_pairs = r.u32()? as usize;
+        r.skip(num_token_pairs * 64)?;
+
+        let profit_threshold = r.u16()?;
+        r.skip(2)?; // max_slippage
+        let status = match r.u8()? {
+            0 => StrategyStatus::Pending,
+            1 => StrategyStatus::Approved,
+            2 => StrategyStatus::Rejected,
+            3 => StrategyStatus::Paused,
+            4 => StrategyStatus::Retired,
+            value => bail!("StrategyAccount: invalid status {}", value),
+        };
+        r.skip(8 * 4)?; // metrics
+        r.skip(1 + 4)?; // bump, version
+        let emergency_paused = r.u8()? != 0;
+        r.skip(8 + 4 + 8 + 8 + 4)?; // rate limits
+        let max_borrow = r.u64()?;
+
+        Ok(Self {
+            creator,
+            profit_threshold,
+            status,
+            emergency_paused,
+            max_borrow,
+        })
+    }
+
+    pub fn is_executable(&self) -> bool {
+        self.status == StrategyStatus::Approved && !self.emergency_paused
+    }
+
+    /// Minimum net profit the program accepts for a borrow size
+    pub fn min_profit_for(&self, borrow_amount: u64) -> u64 {
+        (borrow_amount as u128 * self.profit_threshold as u128 / 10_000) as u64
+    }
+}
+
+/// execution-engine `ProfitConfig` fields used for execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfitConfig {
+    pub treasury: Pubkey,
+}
+
+impl ProfitConfig {
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Reader::anchor(data, "ProfitConfig")?;
+        Ok(Self {
+            treasury: r.pubkey()?,
+        })
+    }
+}
+
+/// flash-loan `FlashLoanPool` fields used for execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlashLoanPool {
+    pub pool_token_account: Pubkey,
+}
+
+impl FlashLoanPool {
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut r = Reader::anchor(data, "FlashLoanPool")?;
+        r.skip(32)?; // authority
+        Ok(Self {
+            pool_token_account: r.pubkey()?,
+        })
+    }
+}
+
+/// Bounds-checked little-endian reader
+struct Reader<'a> {
+    account: &'static str,
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Reader positioned after a verified Anchor discriminator
+    fn anchor(data: &'a [u8], account: &'static str) -> Result<Self> {
+        let mut reader = Self {
+            account,
+            data,
+            offset: 0,
+        };
+        if reader.bytes::<8>()? != account_discriminator(account) {
+            bail!("{}: discriminator mismatch", account);
+        }
+        Ok(reader)
+    }
+
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let end = self.offset + N;
+        let slice = self.data.get(self.offset..end).ok_or_else(|| {
+            anyhow!(
+                "{}: expected at least {} bytes, got {}",
+                self.account,
+                end,
+                self.data.len()
+            )
+        })?;
+        self.offset = end;
+        Ok(slice.try_into().expect("slice has N bytes"))
+    }
+
+    fn skip(&mut self, len: usize) -> Result<()> {
+        if self.offset + len > self.data.len() {
+            bail!(
+                "{}: expected at least {} bytes, got {}",
+                self.account,
+                self.offset + len,
+                self.data.len()
+            );
+        }
+        self.offset += len;
+        Ok(())
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.bytes()?))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes()?))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.bytes()?))
+    }
+
+    fn pubkey(&mut self) -> Result<Pubkey> {
+        Ok(Pubkey::new_from_array(self.bytes()?))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Borsh-encode a strategy the way strategy-registry stores it
+    pub(crate) fn encode_strategy(creator: Pubkey, profit_threshold: u16, status: u8, max_borrow: u64) -> Vec<u8> {
+        let mut data = account_discriminator("StrategyAccount").to_vec();
+        data.extend_from_slice(creator.as_ref());
+        data.extend_from_slice(&1u64.to_le_bytes()); // strategy_id
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(&[0, 1]); // dexs
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&[3u8; 64]); // token_pairs
+        data.extend_from_slice(&profit_threshold.to_le_bytes());
+        data.extend_from_slice(&100u16.to_le_bytes()); // max_slippage
+        data.push(status);
+        data.extend_from_slice(&[0u8; 8 * 4]); // metrics
+        data.push(255); // bump
+        data.extend_from_slice(&1u32.to_le_bytes()); // version
+        data.push(0); // emergency_paused
+        data.extend_from_slice(&[0u8; 8 + 4 + 8 + 8 + 4]); // rate limits
+        data.extend_from_slice(&max_borrow.to_le_bytes());
+        data
+    }
+
+    pub(crate) fn encode_profit_config(treasury: Pubkey) -> Vec<u8> {
+        let mut data = account_discriminator("ProfitConfig").to_vec();
+        data.extend_from_slice(treasury.as_ref());
+        data.extend_from_slice(&[0u8; 8 * 3 + 1]);
+        data
+    }
+
+    pub(crate) fn encode_flash_loan_pool(pool_token_account: Pubkey) -> Vec<u8> {
+        let mut data = account_discriminator("FlashLoanPool").to_vec();
+        data.extend_from_slice(&[1u8; 32]); // authority
+        data.extend_from_slice(pool_token_account.as_ref());
+        data.extend_from_slice(&9u16.to_le_bytes()); // fee_bps
+        data.extend_from_slice(&[0u8; 64]);
+        data
+    }
+
+    #[test]
+    fn test_decode_strategy() {
+        let creator = Pubkey::new_unique();
+        let strategy = Strategy::decode(&encode_strategy(creator, 50, 1, 5_000_000_000)).unwrap();
+
+        assert_eq!(
+            strategy,
+            Strategy {
+                creator,
+                profit_threshold: 50,
+                status: StrategyStatus::Approved,
+                emergency_paused: false,
+                max_borrow: 5_000_000_000,
+            }
+        );
+        assert!(strategy.is_executable());
+        assert_eq!(strategy.min_profit_for(1_000_000_000), 5_000_000);
+    }
+
+    #[test]
+    fn test_decode_rejects_wrong_account_and_short_data() {
+        let data = encode_strategy(Pubkey::new_unique(), 50, 1, 0);
+
+        assert!(ProfitConfig::decode(&data).is_err());
+        assert!(Strategy::decode(&data[..100]).is_err());
+    }
+
+    #[test]
+    fn test_decode_flash_loan_pool() {
+        let token_account = Pubkey::new_unique();
+        let pool = FlashLoanPool::decode(&encode_flash_loan_pool(token_account)).unwrap();
+
+        assert_eq!(pool.pool_token_account, token_account);
+    }
+}
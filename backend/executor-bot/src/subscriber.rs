@@ -0,0 +1,55 @@
+/*!
+ * Redis "opportunities" subscription
+ *
+ * Opportunities are handled one at a time in arrival order; anything that
+ * waited too long is skipped as stale by the executor. The subscription is
+ * re-established after connection errors.
+ */
+
+use anyhow::Result;
+use futures::StreamExt;
+use redis::Client;
+use std::time::Duration;
+use tokio::time;
+use tracing::{error, info, warn};
+
+use crate::executor::Executor;
+use crate::recorder::OutcomeRecorder;
+use crate::rpc::ExecutorRpc;
+use crate::types::ArbitrageOpportunity;
+
+/// Channel the pool monitor publishes opportunities to
+pub const OPPORTUNITIES_CHANNEL: &str = "opportunities";
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+pub async fn run<R: ExecutorRpc, O: OutcomeRecorder>(redis_url: &str, executor: &Executor<R, O>) -> Result<()> {
+    let client = Client::open(redis_url)?;
+
+    loop {
+        if let Err(e) = subscribe(&client, executor).await {
+            error!("Opportunity subscription error: {}", e);
+        }
+        warn!("Resubscribing in {:?}", RECONNECT_DELAY);
+        time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+async fn subscribe<R: ExecutorRpc, O: OutcomeRecorder>(client: &Client, executor: &Executor<R, O>) -> Result<()> {
+    let mut pubsub = client.get_async_connection().await?.into_pubsub();
+    pubsub.subscribe(OPPORTUNITIES_CHANNEL).await?;
+    info!("Subscribed to \"{}\"", OPPORTUNITIES_CHANNEL);
+
+    let mut messages = pubsub.on_message();
+    while let Some(message) = messages.next().await {
+        let payload: String = message.get_payload()?;
+        match serde_json::from_str::<ArbitrageOpportunity>(&payload) {
+            Ok(opportunity) => {
+                executor.handle(&opportunity).await;
+            }
+            Err(e) => warn!("Ignoring malformed opportunity: {}", e),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+/*!
+ * Shared data types
+ *
+ * `ArbitrageOpportunity` mirrors what the pool monitor publishes on the
+ * "opportunities" channel.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DexType {
+    Raydium,
+    Orca,
+    Meteora,
+    Phoenix,
+    Lifinity,
+}
+
+impl fmt::Display for DexType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DexType::Raydium => write!(f, "raydium"),
+            DexType::Orca => write!(f, "orca"),
+            DexType::Meteora => write!(f, "meteora"),
+            DexType::Phoenix => write!(f, "phoenix"),
+            DexType::Lifinity => write!(f, "lifinity"),
+        }
+    }
+}
+
+/// One swap of an arbitrage route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hop {
+    pub dex: DexType,
+    pub pool: String,
+    pub token_in: String,
+    pub token_out: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArbitrageOpportunity {
+    pub id: String,
+    pub buy_dex: DexType,
+    pub buy_pool: String,
+    pub sell_dex: DexType,
+    pub sell_pool: String,
+    pub token_a: String,
+    pub token_b: String,
+    pub buy_price: f64,
+    pub sell_price: f64,
+    pub profit_bps: u16,          // Profit in basis points (1 bps = 0.01%)
+    pub expected_profit: u64,     // Expected profit in base units of the borrowed token
+    pub suggested_amount: u64,    // Suggested borrow in base units of the borrowed token
+    pub timestamp: i64,
+    /// Swaps in execution order, starting and ending with the borrowed token
+    #[serde(default)]
+    pub hops: Vec<Hop>,
+    /// Approved strategy accounts that can execute this route
+    #[serde(default)]
+    pub strategies: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionStatus {
+    /// Not attempted (stale, unsupported route, below the profit floor, ...)
+    Skipped,
+    /// Dry run: simulation succeeded, nothing was sent
+    Simulated,
+    /// Simulation failed, nothing was sent
+    SimulationFailed,
+    /// Sent and confirmed
+    Confirmed,
+    /// RPC error, or sent but failed or never confirmed
+    Failed,
+}
+
+/// Result of handling one opportunity, recorded for every opportunity received
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionOutcome {
+    pub opportunity_id: String,
+    pub status: ExecutionStatus,
+    pub strategy: Option<String>,
+    pub borrow_amount: u64,
+    pub min_profit: u64,
+    pub signature: Option<String>,
+    /// Block engine bundle ID, when submitted as a bundle
+    pub bundle_id: Option<String>,
+    pub units_consumed: Option<u64>,
+    pub compute_unit_limit: Option<u32>,
+    /// Priority fee in micro-lamports per compute unit
+    pub compute_unit_price: Option<u64>,
+    /// Skip reason or error
+    pub detail: Option<String>,
+    pub timestamp: i64,
+}
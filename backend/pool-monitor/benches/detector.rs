@@ -0,0 +1,120 @@
+/*!
+ * Opportunity detector update throughput with 10k pools
+ *
+ * Run with `cargo bench --bench detector`.
+ */
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use mevrebels_pool_monitor::config::Config;
+use mevrebels_pool_monitor::graph::SOL_MINT;
+use mevrebels_pool_monitor::opportunity::OpportunityDetector;
+use mevrebels_pool_monitor::types::{DexType, PoolPrice};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+use tokio::sync::mpsc;
+
+const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+const TOKENS: usize = 2_500;
+const TASKS: usize = 8;
+
+fn pool(dex: DexType, address: String, token_a: &str, token_b: &str, price: f64) -> PoolPrice {
+    PoolPrice {
+        dex,
+        pool_address: address,
+        token_a: token_a.to_string(),
+        token_b: token_b.to_string(),
+        price,
+        liquidity_a: 1_000_000_000,
+        liquidity_b: 1_000_000_000,
+        timestamp: 0,
+    }
+}
+
+/// SOL/USDC plus four pools per token (two against SOL, two against USDC),
+/// priced consistently so updates never produce opportunities
+fn pools() -> Vec<PoolPrice> {
+    let mut pools = vec![pool(
+        DexType::Raydium,
+        "sol-usdc".to_string(),
+        SOL_MINT,
+        USDC_MINT,
+        100.0,
+    )];
+
+    for index in 0..TOKENS {
+        let token = format!("token-{}", index);
+        let sol_price = 0.001 * (1.0 + index as f64 / TOKENS as f64);
+        pools.push(pool(DexType::Raydium, format!("{}-sol-ray", token), &token, SOL_MINT, sol_price));
+        pools.push(pool(DexType::Orca, format!("{}-sol-orca", token), &token, SOL_MINT, sol_price));
+        pools.push(pool(DexType::Meteora, format!("{}-usdc-met", token), &token, USDC_MINT, sol_price * 100.0));
+        pools.push(pool(DexType::Orca, format!("{}-usdc-orca", token), USDC_MINT, &token, 1.0 / (sol_price * 100.0)));
+    }
+
+    pools
+}
+
+fn detector(runtime: &Runtime, pools: &[PoolPrice]) -> Arc<OpportunityDetector> {
+    std::env::set_var("HELIUS_RPC", "http://localhost:8899");
+    let config = Arc::new(Config::from_env().expect("bench config"));
+
+    let (sender, mut receiver) = mpsc::channel(config.opportunity_channel_capacity);
+    runtime.spawn(async move { while receiver.recv().await.is_some() {} });
+
+    let detector = Arc::new(OpportunityDetector::new(config, sender));
+    runtime.block_on(async {
+        for price in pools {
+            detector.update_pool_price(price.clone()).await;
+        }
+    });
+    detector
+}
+
+fn bench_updates(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let pools = Arc::new(pools());
+    let detector = detector(&runtime, &pools);
+
+    let mut group = c.benchmark_group("update_pool_price");
+
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("10k_pools", |b| {
+        let mut next = 0;
+        b.iter(|| {
+            let price = pools[next % pools.len()].clone();
+            next += 1;
+            runtime.block_on(detector.update_pool_price(price));
+        })
+    });
+
+    // Every pool once, spread over concurrent monitor tasks
+    group.throughput(Throughput::Elements(pools.len() as u64));
+    group.sample_size(20);
+    group.bench_function("10k_pools_8_tasks", |b| {
+        b.iter(|| {
+            runtime.block_on(async {
+                let handles: Vec<_> = (0..TASKS)
+                    .map(|task| {
+                        let detector = detector.clone();
+                        let pools = pools.clone();
+                        tokio::spawn(async move {
+                            for price in pools.iter().skip(task).step_by(TASKS) {
+                                detector.update_pool_price(price.clone()).await;
+                            }
+                        })
+                    })
+                    .collect();
+                for handle in handles {
+                    handle.await.unwrap();
+                }
+            })
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_updates);
+criterion_main!(benches);
@@ -0,0 +1,244 @@
+#!/usr/bin/env python3
+"""
+Writes the account fixtures used by the decoder golden tests.
+
+Each account is encoded field by field from the on-chain layout, so the
+expected values in src/decoders/*.rs can be read straight off this file.
+To test against a live account instead, dump it with
+`solana account <address> --output-file <name>.bin` and update the
+expected values in the matching test.
+"""
+
+import hashlib
+import os
+import struct
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+
+B58 = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+
+
+def pubkey(text):
+    """Decode a base58 pubkey"""
+    n = 0
+    for c in text:
+        n = n * 58 + B58.index(c)
+    return n.to_bytes(32, "big")
+
+
+def key(seed):
+    """Deterministic placeholder pubkey: 32 bytes of `seed`"""
+    return bytes([seed]) * 32
+
+
+def anchor_discriminator(name):
+    return hashlib.sha256(f"account:{name}".encode()).digest()[:8]
+
+
+def u8(v): return struct.pack("<B", v)
+def u16(v): return struct.pack("<H", v)
+def u32(v): return struct.pack("<I", v)
+def i32(v): return struct.pack("<i", v)
+def u64(v): return struct.pack("<Q", v)
+def i64(v): return struct.pack("<q", v)
+def u128(v): return v.to_bytes(16, "little")
+def i128(v): return v.to_bytes(16, "little", signed=True)
+
+
+SOL = pubkey("So11111111111111111111111111111111111111112")
+USDC = pubkey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")
+
+
+def raydium_amm_v4():
+    data = b"".join([
+        u64(6),            # status (swap only)
+        u64(254),          # nonce
+        u64(7),            # order_num
+        u64(3),            # depth
+        u64(9),            # coin_decimals
+        u64(6),            # pc_decimals
+        u64(1),            # state
+        u64(0),            # reset_flag
+        u64(1),            # min_size
+        u64(500),          # vol_max_cut_ratio
+        u64(500000),       # amount_wave
+        u64(1_000_000),    # coin_lot_size
+        u64(1),            # pc_lot_size
+        u64(1),            # min_price_multiplier
+        u64(1_000_000_000),  # max_price_multiplier
+        u64(1_000_000_000),  # sys_decimal_value
+        # fees
+        u64(5), u64(1000),   # min_separate
+        u64(25), u64(10000),  # trade_fee
+        u64(12), u64(100),   # pnl
+        u64(25), u64(10000),  # swap_fee
+        # state data
+        u64(1_500_000),      # need_take_pnl_coin
+        u64(250_000),        # need_take_pnl_pc
+        u64(0), u64(0),      # total_pnl_pc, total_pnl_coin
+        u64(1_650_000_000),  # pool_open_time
+        u64(0), u64(0),      # punish_pc_amount, punish_coin_amount
+        u64(0),              # orderbook_to_init_time
+        u128(0), u128(0),    # swap_coin_in_amount, swap_pc_out_amount
+        u64(0),              # swap_acc_pc_fee
+        u128(0), u128(0),    # swap_pc_in_amount, swap_coin_out_amount
+        u64(0),              # swap_acc_coin_fee
+        key(1),              # coin_vault
+        key(2),              # pc_vault
+        SOL,                 # coin_vault_mint
+        USDC,                # pc_vault_mint
+        key(3),              # lp_mint
+        key(4),              # open_orders
+        key(5),              # market
+        key(6),              # market_program
+        key(7),              # target_orders
+        bytes(64),           # padding1
+        key(8),              # amm_owner
+        u64(2_000_000_000),  # lp_reserve
+        bytes(24),           # padding2
+    ])
+    assert len(data) == 752
+    return data
+
+
+def whirlpool():
+    reward = key(0) * 3 + u128(0) + u128(0)
+    data = b"".join([
+        anchor_discriminator("Whirlpool"),
+        key(10),                 # whirlpools_config
+        u8(255),                 # whirlpool_bump
+        u16(64),                 # tick_spacing
+        u16(64),                 # tick_spacing_seed
+        u16(3000),               # fee_rate (0.3%)
+        u16(1300),               # protocol_fee_rate
+        u128(123_456_789_012_345),  # liquidity
+        u128(7_144_393_258_922_745_604),  # sqrt_price (sqrt(0.15) raw, 150 USDC/SOL)
+        i32(-18972),             # tick_current_index
+        u64(11), u64(22),        # protocol_fee_owed_a/b
+        SOL,                     # token_mint_a
+        key(11),                 # token_vault_a
+        u128(33),                # fee_growth_global_a
+        USDC,                    # token_mint_b
+        key(12),                 # token_vault_b
+        u128(44),                # fee_growth_global_b
+        u64(1_700_000_000),      # reward_last_updated_timestamp
+        reward * 3,              # reward_infos
+    ])
+    assert len(data) == 653
+    return data
+
+
+def tick(initialized, liquidity_net, liquidity_gross):
+    return b"".join([
+        u8(initialized),
+        i128(liquidity_net),
+        u128(liquidity_gross),
+        u128(0), u128(0),        # fee_growth_outside_a/b
+        u128(0) * 3,             # reward_growths_outside
+    ])
+
+
+def tick_array():
+    ticks = [tick(0, 0, 0) for _ in range(88)]
+    ticks[0] = tick(1, 5_000_000, 5_000_000)
+    ticks[44] = tick(1, -2_000_000, 2_000_000)
+    ticks[87] = tick(1, -3_000_000, 3_000_000)
+    data = b"".join([
+        anchor_discriminator("TickArray"),
+        i32(-22528),             # start_tick_index (-4 * 88 * 64)
+        b"".join(ticks),
+        key(13),                 # whirlpool
+    ])
+    assert len(data) == 9988
+    return data
+
+
+def lb_pair():
+    static_parameters = b"".join([
+        u16(10000), u16(30), u16(600), u16(5000),
+        u32(40000), u32(350000), i32(-443636), i32(443636),
+        u16(500), u8(0), bytes(5),
+    ])
+    variable_parameters = b"".join([
+        u32(1234), u32(567), i32(-1800), bytes(4), i64(1_700_000_100), bytes(8),
+    ])
+    # mint, vault, funder, reward_duration, reward_duration_end, reward_rate,
+    # last_update_time, cumulative_seconds_with_empty_liquidity_reward
+    reward = key(0) * 3 + u64(0) + u64(0) + u128(0) + u64(0) + u64(0)
+    bitmap = [0] * 16
+    bitmap[7] = 1 << 63
+    data = b"".join([
+        anchor_discriminator("LbPair"),
+        static_parameters,
+        variable_parameters,
+        u8(252),                 # bump_seed
+        u16(25),                 # bin_step_seed
+        u8(0),                   # pair_type
+        i32(-1800),              # active_id
+        u16(25),                 # bin_step
+        u8(0),                   # status
+        u8(0),                   # require_base_factor_seed
+        u16(10000),              # base_factor_seed
+        u8(0),                   # activation_type
+        u8(0),                   # creator_pool_on_off_control
+        SOL,                     # token_x_mint
+        USDC,                    # token_y_mint
+        key(20),                 # reserve_x
+        key(21),                 # reserve_y
+        u64(55), u64(66),        # protocol_fee amount_x/y
+        bytes(32),               # _padding_1
+        reward * 2,              # reward_infos
+        key(22),                 # oracle
+        b"".join(u64(w) for w in bitmap),  # bin_array_bitmap
+        i64(1_700_000_200),      # last_updated_at
+        bytes(32),               # _padding_2
+        key(0),                  # pre_activation_swap_address
+        key(23),                 # base_key
+        u64(0), u64(0),          # activation_point, pre_activation_duration
+        bytes(8), u64(0),        # _padding_3, _padding_4
+        key(24),                 # creator
+        u8(0), u8(0),            # token_mint_x/y_program_flag
+        bytes(22),               # _reserved
+    ])
+    assert len(data) == 904
+    return data
+
+
+def bin_entry(amount_x, amount_y, price):
+    return b"".join([
+        u64(amount_x), u64(amount_y), u128(price), u128(amount_x + amount_y),
+        u128(0) * 2, u128(0), u128(0), u128(0), u128(0),
+    ])
+
+
+def bin_array():
+    bins = [bin_entry(0, 0, 0) for _ in range(70)]
+    # Bins -1820..-1751; active bin -1800 sits at offset 20
+    bins[19] = bin_entry(0, 900_000_000, 11_640_000_000_000_000_000)
+    bins[20] = bin_entry(2_000_000_000, 300_000_000, 11_669_000_000_000_000_000)
+    bins[21] = bin_entry(3_000_000_000, 0, 11_698_000_000_000_000_000)
+    data = b"".join([
+        anchor_discriminator("BinArray"),
+        i64(-26),                # index (bins -1820..-1751)
+        u8(1),                   # version
+        bytes(7),                # _padding
+        key(25),                 # lb_pair
+        b"".join(bins),
+    ])
+    assert len(data) == 10136
+    return data
+
+
+FIXTURES = {
+    "raydium_amm_v4.bin": raydium_amm_v4,
+    "orca_whirlpool.bin": whirlpool,
+    "orca_tick_array.bin": tick_array,
+    "meteora_lb_pair.bin": lb_pair,
+    "meteora_bin_array.bin": bin_array,
+}
+
+if __name__ == "__main__":
+    for name, build in FIXTURES.items():
+        with open(os.path.join(HERE, name), "wb") as f:
+            f.write(build())
+        print(f"wrote {name}")
@@ -11,6 +11,16 @@ pub struct Config {
     pub redis_url: String,
     pub min_profit_bps: u16,
     pub opportunity_ttl_seconds: u64,
+    pub opportunity_channel_capacity: usize,
+    pub publish_batch_size: usize,
+    pub publish_batch_window_ms: u64,
+    pub watched_pools_file: Option<String>,
+    pub flash_loan_fee_bps: u16,
+    pub max_trade_amount: u64,
+    pub max_hops: usize,
+    pub strategy_snapshot_file: Option<String>,
+    pub strategy_registry_program_id: Option<String>,
+    pub strategy_refresh_seconds: u64,
     pub raydium_ws: String,
     pub orca_api: String,
     pub meteora_api: String,
@@ -33,9 +43,66 @@ impl Config {
                 .unwrap_or_else(|_| "10".to_string())
                 .parse()
                 .expect("OPPORTUNITY_TTL_SECONDS must be a valid u64"),
+            opportunity_channel_capacity: env::var("OPPORTUNITY_CHANNEL_CAPACITY")
+                .unwrap_or_else(|_| "1024".to_string())
+                .parse()
+                .expect("OPPORTUNITY_CHANNEL_CAPACITY must be a valid usize"),
+            publish_batch_size: env::var("PUBLISH_BATCH_SIZE")
+                .unwrap_or_else(|_| "32".to_string())
+                .parse()
+                .expect("PUBLISH_BATCH_SIZE must be a valid usize"),
+            publish_batch_window_ms: env::var("PUBLISH_BATCH_WINDOW_MS")
+                .unwrap_or_else(|_| "50".to_string())
+                .parse()
+                .expect("PUBLISH_BATCH_WINDOW_MS must be a valid u64"),
+            watched_pools_file: env::var("WATCHED_POOLS_FILE").ok(),
+            flash_loan_fee_bps: env::var("FLASH_LOAN_FEE_BPS")
+                .unwrap_or_else(|_| "9".to_string())
+                .parse()
+                .expect("FLASH_LOAN_FEE_BPS must be a valid u16"),
+            max_trade_amount: env::var("MAX_TRADE_AMOUNT")
+                .unwrap_or_else(|_| "1000000000000".to_string())
+                .parse()
+                .expect("MAX_TRADE_AMOUNT must be a valid u64"),
+            max_hops: env::var("MAX_HOPS")
+                .unwrap_or_else(|_| "3".to_string())
+                .parse()
+                .expect("MAX_HOPS must be a valid usize"),
+            strategy_snapshot_file: env::var("STRATEGY_SNAPSHOT_FILE").ok(),
+            strategy_registry_program_id: env::var("STRATEGY_REGISTRY_PROGRAM_ID").ok(),
+            strategy_refresh_seconds: env::var("STRATEGY_REFRESH_SECONDS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .expect("STRATEGY_REFRESH_SECONDS must be a valid u64"),
             raydium_ws: "wss://api.raydium.io/v2/ws".to_string(),
             orca_api: "https://api.orca.so".to_string(),
             meteora_api: "https://dlmm-api.meteora.ag".to_string(),
         })
     }
 }
+
+#[cfg(test)]
+impl Config {
+    /// Defaults for unit tests (no network endpoints)
+    pub fn for_tests() -> Self {
+        Config {
+            helius_rpc: String::new(),
+            redis_url: String::new(),
+            min_profit_bps: 50,
+            opportunity_ttl_seconds: 10,
+            opportunity_channel_capacity: 16,
+            publish_batch_size: 32,
+            publish_batch_window_ms: 50,
+            watched_pools_file: None,
+            flash_loan_fee_bps: 9,
+            max_trade_amount: 1_000_000_000_000,
+            max_hops: 3,
+            strategy_snapshot_file: None,
+            strategy_registry_program_id: None,
+            strategy_refresh_seconds: 60,
+            raydium_ws: String::new(),
+            orca_api: String::new(),
+            meteora_api: String::new(),
+        }
+    }
+}
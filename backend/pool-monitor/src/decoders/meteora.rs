@@ -0,0 +1,327 @@
+/*!
+ * Meteora DLMM account layouts
+ *
+ * Anchor accounts owned by LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo.
+ */
+
+use super::{reader, DecodeError, Reader};
+use solana_sdk::pubkey::Pubkey;
+
+pub const NUM_REWARDS: usize = 2;
+pub const MAX_BIN_PER_ARRAY: usize = 70;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaticParameters {
+    pub base_factor: u16,
+    pub filter_period: u16,
+    pub decay_period: u16,
+    pub reduction_factor: u16,
+    pub variable_fee_control: u32,
+    pub max_volatility_accumulator: u32,
+    pub min_bin_id: i32,
+    pub max_bin_id: i32,
+    pub protocol_share: u16,
+    pub base_fee_power_factor: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariableParameters {
+    pub volatility_accumulator: u32,
+    pub volatility_reference: u32,
+    pub index_reference: i32,
+    pub last_update_timestamp: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub funder: Pubkey,
+    pub reward_duration: u64,
+    pub reward_duration_end: u64,
+    pub reward_rate: u128,
+    pub last_update_time: u64,
+    pub cumulative_seconds_with_empty_liquidity_reward: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LbPair {
+    pub parameters: StaticParameters,
+    pub v_parameters: VariableParameters,
+    pub bump_seed: u8,
+    pub bin_step_seed: [u8; 2],
+    pub pair_type: u8,
+    pub active_id: i32,
+    /// Price step between bins in basis points
+    pub bin_step: u16,
+    pub status: u8,
+    pub require_base_factor_seed: u8,
+    pub base_factor_seed: [u8; 2],
+    pub activation_type: u8,
+    pub creator_pool_on_off_control: u8,
+    pub token_x_mint: Pubkey,
+    pub token_y_mint: Pubkey,
+    pub reserve_x: Pubkey,
+    pub reserve_y: Pubkey,
+    pub protocol_fee_amount_x: u64,
+    pub protocol_fee_amount_y: u64,
+    pub reward_infos: [RewardInfo; NUM_REWARDS],
+    pub oracle: Pubkey,
+    pub bin_array_bitmap: [u64; 16],
+    pub last_updated_at: i64,
+    pub pre_activation_swap_address: Pubkey,
+    pub base_key: Pubkey,
+    pub activation_point: u64,
+    pub pre_activation_duration: u64,
+    pub creator: Pubkey,
+    pub token_mint_x_program_flag: u8,
+    pub token_mint_y_program_flag: u8,
+}
+
+impl LbPair {
+    pub const LEN: usize = 904;
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut r = reader(data, "LbPair", Self::LEN, true)?;
+
+        let parameters = StaticParameters {
+            base_factor: r.u16(),
+            filter_period: r.u16(),
+            decay_period: r.u16(),
+            reduction_factor: r.u16(),
+            variable_fee_control: r.u32(),
+            max_volatility_accumulator: r.u32(),
+            min_bin_id: r.i32(),
+            max_bin_id: r.i32(),
+            protocol_share: r.u16(),
+            base_fee_power_factor: r.u8(),
+        };
+        r.skip(5);
+
+        let volatility_accumulator = r.u32();
+        let volatility_reference = r.u32();
+        let index_reference = r.i32();
+        r.skip(4);
+        let last_update_timestamp = r.i64();
+        r.skip(8);
+        let v_parameters = VariableParameters {
+            volatility_accumulator,
+            volatility_reference,
+            index_reference,
+            last_update_timestamp,
+        };
+
+        let bump_seed = r.u8();
+        let bin_step_seed = r.bytes();
+        let pair_type = r.u8();
+        let active_id = r.i32();
+        let bin_step = r.u16();
+        let status = r.u8();
+        let require_base_factor_seed = r.u8();
+        let base_factor_seed = r.bytes();
+        let activation_type = r.u8();
+        let creator_pool_on_off_control = r.u8();
+        let token_x_mint = r.pubkey();
+        let token_y_mint = r.pubkey();
+        let reserve_x = r.pubkey();
+        let reserve_y = r.pubkey();
+        let protocol_fee_amount_x = r.u64();
+        let protocol_fee_amount_y = r.u64();
+        r.skip(32);
+        let reward_infos = std::array::from_fn(|_| RewardInfo {
+            mint: r.pubkey(),
+            vault: r.pubkey(),
+            funder: r.pubkey(),
+            reward_duration: r.u64(),
+            reward_duration_end: r.u64(),
+            reward_rate: r.u128(),
+            last_update_time: r.u64(),
+            cumulative_seconds_with_empty_liquidity_reward: r.u64(),
+        });
+        let oracle = r.pubkey();
+        let bin_array_bitmap = std::array::from_fn(|_| r.u64());
+        let last_updated_at = r.i64();
+        r.skip(32);
+        let pre_activation_swap_address = r.pubkey();
+        let base_key = r.pubkey();
+        let activation_point = r.u64();
+        let pre_activation_duration = r.u64();
+        r.skip(16);
+        let creator = r.pubkey();
+        let token_mint_x_program_flag = r.u8();
+        let token_mint_y_program_flag = r.u8();
+
+        Ok(Self {
+            parameters,
+            v_parameters,
+            bump_seed,
+            bin_step_seed,
+            pair_type,
+            active_id,
+            bin_step,
+            status,
+            require_base_factor_seed,
+            base_factor_seed,
+            activation_type,
+            creator_pool_on_off_control,
+            token_x_mint,
+            token_y_mint,
+            reserve_x,
+            reserve_y,
+            protocol_fee_amount_x,
+            protocol_fee_amount_y,
+            reward_infos,
+            oracle,
+            bin_array_bitmap,
+            last_updated_at,
+            pre_activation_swap_address,
+            base_key,
+            activation_point,
+            pre_activation_duration,
+            creator,
+            token_mint_x_program_flag,
+            token_mint_y_program_flag,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bin {
+    pub amount_x: u64,
+    pub amount_y: u64,
+    /// Q64.64 price of x in y (raw units)
+    pub price: u128,
+    pub liquidity_supply: u128,
+    pub reward_per_token_stored: [u128; NUM_REWARDS],
+    pub fee_amount_x_per_token_stored: u128,
+    pub fee_amount_y_per_token_stored: u128,
+    pub amount_x_in: u128,
+    pub amount_y_in: u128,
+}
+
+impl Bin {
+    fn read(r: &mut Reader) -> Self {
+        Self {
+            amount_x: r.u64(),
+            amount_y: r.u64(),
+            price: r.u128(),
+            liquidity_supply: r.u128(),
+            reward_per_token_stored: [r.u128(), r.u128()],
+            fee_amount_x_per_token_stored: r.u128(),
+            fee_amount_y_per_token_stored: r.u128(),
+            amount_x_in: r.u128(),
+            amount_y_in: r.u128(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinArray {
+    pub index: i64,
+    pub version: u8,
+    pub lb_pair: Pubkey,
+    pub bins: Vec<Bin>,
+}
+
+impl BinArray {
+    pub const LEN: usize = 10136;
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut r = reader(data, "BinArray", Self::LEN, true)?;
+
+        let index = r.i64();
+        let version = r.u8();
+        r.skip(7);
+        let lb_pair = r.pubkey();
+        let bins = (0..MAX_BIN_PER_ARRAY).map(|_| Bin::read(&mut r)).collect();
+
+        Ok(Self {
+            index,
+            version,
+            lb_pair,
+            bins,
+        })
+    }
+
+    /// Bin array index holding `bin_id`
+    pub fn index_for_bin(bin_id: i32) -> i64 {
+        (bin_id as i64).div_euclid(MAX_BIN_PER_ARRAY as i64)
+    }
+
+    /// Bin with id `bin_id` if it falls inside this array
+    pub fn bin(&self, bin_id: i32) -> Option<&Bin> {
+        let offset = bin_id as i64 - self.index * MAX_BIN_PER_ARRAY as i64;
+        if offset < 0 {
+            return None;
+        }
+        self.bins.get(offset as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const LB_PAIR: &[u8] = include_bytes!("../../fixtures/accounts/meteora_lb_pair.bin");
+    const BIN_ARRAY: &[u8] = include_bytes!("../../fixtures/accounts/meteora_bin_array.bin");
+
+    #[test]
+    fn test_decode_lb_pair_fixture() {
+        let pair = LbPair::decode(LB_PAIR).unwrap();
+
+        assert_eq!(pair.parameters.base_factor, 10000);
+        assert_eq!(pair.parameters.filter_period, 30);
+        assert_eq!(pair.parameters.variable_fee_control, 40000);
+        assert_eq!(pair.parameters.min_bin_id, -443636);
+        assert_eq!(pair.parameters.max_bin_id, 443636);
+        assert_eq!(pair.parameters.protocol_share, 500);
+        assert_eq!(pair.v_parameters.volatility_accumulator, 1234);
+        assert_eq!(pair.v_parameters.index_reference, -1800);
+        assert_eq!(pair.v_parameters.last_update_timestamp, 1_700_000_100);
+        assert_eq!(pair.bump_seed, 252);
+        assert_eq!(pair.bin_step_seed, 25u16.to_le_bytes());
+        assert_eq!(pair.active_id, -1800);
+        assert_eq!(pair.bin_step, 25);
+        assert_eq!(pair.base_factor_seed, 10000u16.to_le_bytes());
+        assert_eq!(
+            pair.token_x_mint,
+            Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap()
+        );
+        assert_eq!(
+            pair.token_y_mint,
+            Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap()
+        );
+        assert_eq!(pair.reserve_x, Pubkey::new_from_array([20; 32]));
+        assert_eq!(pair.reserve_y, Pubkey::new_from_array([21; 32]));
+        assert_eq!(pair.protocol_fee_amount_x, 55);
+        assert_eq!(pair.protocol_fee_amount_y, 66);
+        assert_eq!(pair.oracle, Pubkey::new_from_array([22; 32]));
+        assert_eq!(pair.bin_array_bitmap[7], 1 << 63);
+        assert_eq!(pair.last_updated_at, 1_700_000_200);
+        assert_eq!(pair.base_key, Pubkey::new_from_array([23; 32]));
+        assert_eq!(pair.creator, Pubkey::new_from_array([24; 32]));
+    }
+
+    #[test]
+    fn test_decode_bin_array_fixture() {
+        let array = BinArray::decode(BIN_ARRAY).unwrap();
+
+        assert_eq!(array.index, -26);
+        assert_eq!(array.version, 1);
+        assert_eq!(array.lb_pair, Pubkey::new_from_array([25; 32]));
+        assert_eq!(array.bins.len(), MAX_BIN_PER_ARRAY);
+
+        assert_eq!(BinArray::index_for_bin(-1800), -26);
+        let active = array.bin(-1800).unwrap();
+        assert_eq!(active.amount_x, 2_000_000_000);
+        assert_eq!(active.amount_y, 300_000_000);
+        assert_eq!(active.price, 11_669_000_000_000_000_000);
+        assert_eq!(active.liquidity_supply, 2_300_000_000);
+
+        assert_eq!(array.bin(-1801).unwrap().amount_y, 900_000_000);
+        assert_eq!(array.bin(-1799).unwrap().amount_x, 3_000_000_000);
+        assert!(array.bin(-1821).is_none());
+        assert!(array.bin(-1750).is_none());
+    }
+}
@@ -0,0 +1,152 @@
+/*!
+ * Raw account decoders
+ *
+ * Parses pool, tick array, bin array and strategy accounts straight from
+ * their on-chain byte layout (little endian, no padding between fields).
+ */
+
+pub mod meteora;
+pub mod orca;
+pub mod raydium;
+pub mod strategy;
+
+use solana_sdk::hash::hashv;
+use solana_sdk::pubkey::Pubkey;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DecodeError {
+    #[error("{account}: expected {expected} bytes, got {actual}")]
+    InvalidLength {
+        account: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("{account}: discriminator mismatch")]
+    InvalidDiscriminator { account: &'static str },
+
+    #[error("{account}: invalid {field} variant {value}")]
+    InvalidVariant {
+        account: &'static str,
+        field: &'static str,
+        value: u8,
+    },
+}
+
+/// Anchor account discriminator: sha256("account:<Name>")[..8]
+pub fn anchor_discriminator(name: &str) -> [u8; 8] {
+    let hash = hashv(&[format!("account:{}", name).as_bytes()]);
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash.to_bytes()[..8]);
+    discriminator
+}
+
+/// Check length and (for Anchor accounts) the discriminator, returning a reader
+/// positioned after it
+fn reader<'a>(
+    data: &'a [u8],
+    account: &'static str,
+    len: usize,
+    anchor: bool,
+) -> Result<Reader<'a>, DecodeError> {
+    if data.len() < len {
+        return Err(DecodeError::InvalidLength {
+            account,
+            expected: len,
+            actual: data.len(),
+        });
+    }
+
+    let mut reader = Reader { data, offset: 0 };
+    if anchor && reader.bytes::<8>() != anchor_discriminator(account) {
+        return Err(DecodeError::InvalidDiscriminator { account });
+    }
+
+    Ok(reader)
+}
+
+/// Sequential little-endian reader over a length-checked buffer
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut out = [0u8; N];
+        out.copy_from_slice(&self.data[self.offset..self.offset + N]);
+        self.offset += N;
+        out
+    }
+
+    fn skip(&mut self, len: usize) {
+        self.offset += len;
+    }
+
+    fn u8(&mut self) -> u8 {
+        self.bytes::<1>()[0]
+    }
+
+    fn bool(&mut self) -> bool {
+        self.u8() != 0
+    }
+
+    fn u16(&mut self) -> u16 {
+        u16::from_le_bytes(self.bytes())
+    }
+
+    fn u32(&mut self) -> u32 {
+        u32::from_le_bytes(self.bytes())
+    }
+
+    fn i32(&mut self) -> i32 {
+        i32::from_le_bytes(self.bytes())
+    }
+
+    fn u64(&mut self) -> u64 {
+        u64::from_le_bytes(self.bytes())
+    }
+
+    fn i64(&mut self) -> i64 {
+        i64::from_le_bytes(self.bytes())
+    }
+
+    fn u128(&mut self) -> u128 {
+        u128::from_le_bytes(self.bytes())
+    }
+
+    fn i128(&mut self) -> i128 {
+        i128::from_le_bytes(self.bytes())
+    }
+
+    fn pubkey(&mut self) -> Pubkey {
+        Pubkey::new_from_array(self.bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_account_is_rejected() {
+        let err = orca::Whirlpool::decode(&[0u8; 100]).unwrap_err();
+        assert_eq!(
+            err,
+            DecodeError::InvalidLength {
+                account: "Whirlpool",
+                expected: orca::Whirlpool::LEN,
+                actual: 100
+            }
+        );
+    }
+
+    #[test]
+    fn test_wrong_discriminator_is_rejected() {
+        // A tick array is not a whirlpool
+        let data = include_bytes!("../../fixtures/accounts/orca_tick_array.bin");
+        let err = orca::Whirlpool::decode(data).unwrap_err();
+        assert_eq!(err, DecodeError::InvalidDiscriminator { account: "Whirlpool" });
+    }
+}
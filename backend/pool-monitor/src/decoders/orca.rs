@@ -0,0 +1,211 @@
+/*!
+ * Orca Whirlpool account layouts
+ *
+ * Anchor accounts owned by whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc.
+ */
+
+use super::{reader, DecodeError, Reader};
+use solana_sdk::pubkey::Pubkey;
+
+pub const NUM_REWARDS: usize = 3;
+pub const TICK_ARRAY_SIZE: usize = 88;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhirlpoolRewardInfo {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub emissions_per_second_x64: u128,
+    pub growth_global_x64: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Whirlpool {
+    pub whirlpools_config: Pubkey,
+    pub whirlpool_bump: u8,
+    pub tick_spacing: u16,
+    pub tick_spacing_seed: [u8; 2],
+    /// Hundredths of a basis point (3000 = 0.3%)
+    pub fee_rate: u16,
+    pub protocol_fee_rate: u16,
+    pub liquidity: u128,
+    /// Q64.64 square root of the token_b / token_a price (raw units)
+    pub sqrt_price: u128,
+    pub tick_current_index: i32,
+    pub protocol_fee_owed_a: u64,
+    pub protocol_fee_owed_b: u64,
+    pub token_mint_a: Pubkey,
+    pub token_vault_a: Pubkey,
+    pub fee_growth_global_a: u128,
+    pub token_mint_b: Pubkey,
+    pub token_vault_b: Pubkey,
+    pub fee_growth_global_b: u128,
+    pub reward_last_updated_timestamp: u64,
+    pub reward_infos: [WhirlpoolRewardInfo; NUM_REWARDS],
+}
+
+impl Whirlpool {
+    pub const LEN: usize = 653;
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut r = reader(data, "Whirlpool", Self::LEN, true)?;
+
+        Ok(Self {
+            whirlpools_config: r.pubkey(),
+            whirlpool_bump: r.u8(),
+            tick_spacing: r.u16(),
+            tick_spacing_seed: r.bytes(),
+            fee_rate: r.u16(),
+            protocol_fee_rate: r.u16(),
+            liquidity: r.u128(),
+            sqrt_price: r.u128(),
+            tick_current_index: r.i32(),
+            protocol_fee_owed_a: r.u64(),
+            protocol_fee_owed_b: r.u64(),
+            token_mint_a: r.pubkey(),
+            token_vault_a: r.pubkey(),
+            fee_growth_global_a: r.u128(),
+            token_mint_b: r.pubkey(),
+            token_vault_b: r.pubkey(),
+            fee_growth_global_b: r.u128(),
+            reward_last_updated_timestamp: r.u64(),
+            reward_infos: std::array::from_fn(|_| WhirlpoolRewardInfo {
+                mint: r.pubkey(),
+                vault: r.pubkey(),
+                authority: r.pubkey(),
+                emissions_per_second_x64: r.u128(),
+                growth_global_x64: r.u128(),
+            }),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tick {
+    pub initialized: bool,
+    pub liquidity_net: i128,
+    pub liquidity_gross: u128,
+    pub fee_growth_outside_a: u128,
+    pub fee_growth_outside_b: u128,
+    pub reward_growths_outside: [u128; NUM_REWARDS],
+}
+
+impl Tick {
+    fn read(r: &mut Reader) -> Self {
+        Self {
+            initialized: r.bool(),
+            liquidity_net: r.i128(),
+            liquidity_gross: r.u128(),
+            fee_growth_outside_a: r.u128(),
+            fee_growth_outside_b: r.u128(),
+            reward_growths_outside: [r.u128(), r.u128(), r.u128()],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TickArray {
+    pub start_tick_index: i32,
+    pub ticks: Vec<Tick>,
+    pub whirlpool: Pubkey,
+}
+
+impl TickArray {
+    pub const LEN: usize = 9988;
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut r = reader(data, "TickArray", Self::LEN, true)?;
+
+        let start_tick_index = r.i32();
+        let ticks = (0..TICK_ARRAY_SIZE).map(|_| Tick::read(&mut r)).collect();
+        let whirlpool = r.pubkey();
+
+        Ok(Self {
+            start_tick_index,
+            ticks,
+            whirlpool,
+        })
+    }
+
+    /// Tick at `tick_index` if it falls inside this array
+    pub fn tick(&self, tick_index: i32, tick_spacing: u16) -> Option<&Tick> {
+        let offset = tick_index.checked_sub(self.start_tick_index)?;
+        if offset < 0 || offset % tick_spacing as i32 != 0 {
+            return None;
+        }
+        self.ticks.get((offset / tick_spacing as i32) as usize)
+    }
+
+    /// Initialized ticks as (tick_index, tick), in ascending order
+    pub fn initialized_ticks(&self, tick_spacing: u16) -> impl Iterator<Item = (i32, &Tick)> {
+        let start = self.start_tick_index;
+        self.ticks
+            .iter()
+            .enumerate()
+            .filter(|(_, tick)| tick.initialized)
+            .map(move |(i, tick)| (start + i as i32 * tick_spacing as i32, tick))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const WHIRLPOOL: &[u8] = include_bytes!("../../fixtures/accounts/orca_whirlpool.bin");
+    const TICK_ARRAY: &[u8] = include_bytes!("../../fixtures/accounts/orca_tick_array.bin");
+
+    #[test]
+    fn test_decode_whirlpool_fixture() {
+        let pool = Whirlpool::decode(WHIRLPOOL).unwrap();
+
+        assert_eq!(pool.whirlpools_config, Pubkey::new_from_array([10; 32]));
+        assert_eq!(pool.whirlpool_bump, 255);
+        assert_eq!(pool.tick_spacing, 64);
+        assert_eq!(pool.tick_spacing_seed, 64u16.to_le_bytes());
+        assert_eq!(pool.fee_rate, 3000);
+        assert_eq!(pool.protocol_fee_rate, 1300);
+        assert_eq!(pool.liquidity, 123_456_789_012_345);
+        assert_eq!(pool.sqrt_price, 7_144_393_258_922_745_604);
+        assert_eq!(pool.tick_current_index, -18972);
+        assert_eq!(pool.protocol_fee_owed_a, 11);
+        assert_eq!(pool.protocol_fee_owed_b, 22);
+        assert_eq!(
+            pool.token_mint_a,
+            Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap()
+        );
+        assert_eq!(pool.token_vault_a, Pubkey::new_from_array([11; 32]));
+        assert_eq!(pool.fee_growth_global_a, 33);
+        assert_eq!(
+            pool.token_mint_b,
+            Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap()
+        );
+        assert_eq!(pool.token_vault_b, Pubkey::new_from_array([12; 32]));
+        assert_eq!(pool.fee_growth_global_b, 44);
+        assert_eq!(pool.reward_last_updated_timestamp, 1_700_000_000);
+        assert_eq!(pool.reward_infos[2].mint, Pubkey::default());
+    }
+
+    #[test]
+    fn test_decode_tick_array_fixture() {
+        let array = TickArray::decode(TICK_ARRAY).unwrap();
+
+        assert_eq!(array.start_tick_index, -22528);
+        assert_eq!(array.ticks.len(), TICK_ARRAY_SIZE);
+        assert_eq!(array.whirlpool, Pubkey::new_from_array([13; 32]));
+
+        let initialized: Vec<(i32, i128)> = array
+            .initialized_ticks(64)
+            .map(|(index, tick)| (index, tick.liquidity_net))
+            .collect();
+        assert_eq!(
+            initialized,
+            vec![(-22528, 5_000_000), (-19712, -2_000_000), (-16960, -3_000_000)]
+        );
+
+        let tick = array.tick(-19712, 64).unwrap();
+        assert_eq!(tick.liquidity_gross, 2_000_000);
+        assert!(array.tick(-19700, 64).is_none());
+        assert!(array.tick(-16896, 64).is_none());
+    }
+}
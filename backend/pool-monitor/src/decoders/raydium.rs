@@ -0,0 +1,233 @@
+/*!
+ * Raydium AMM v4 account layout
+ *
+ * `AmmInfo` is a plain (non-Anchor) account of 752 bytes owned by
+ * 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8.
+ */
+
+use super::{reader, DecodeError};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fees {
+    pub min_separate_numerator: u64,
+    pub min_separate_denominator: u64,
+    pub trade_fee_numerator: u64,
+    pub trade_fee_denominator: u64,
+    pub pnl_numerator: u64,
+    pub pnl_denominator: u64,
+    pub swap_fee_numerator: u64,
+    pub swap_fee_denominator: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateData {
+    pub need_take_pnl_coin: u64,
+    pub need_take_pnl_pc: u64,
+    pub total_pnl_pc: u64,
+    pub total_pnl_coin: u64,
+    pub pool_open_time: u64,
+    pub punish_pc_amount: u64,
+    pub punish_coin_amount: u64,
+    pub orderbook_to_init_time: u64,
+    pub swap_coin_in_amount: u128,
+    pub swap_pc_out_amount: u128,
+    pub swap_acc_pc_fee: u64,
+    pub swap_pc_in_amount: u128,
+    pub swap_coin_out_amount: u128,
+    pub swap_acc_coin_fee: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmmInfo {
+    pub status: u64,
+    pub nonce: u64,
+    pub order_num: u64,
+    pub depth: u64,
+    pub coin_decimals: u64,
+    pub pc_decimals: u64,
+    pub state: u64,
+    pub reset_flag: u64,
+    pub min_size: u64,
+    pub vol_max_cut_ratio: u64,
+    pub amount_wave: u64,
+    pub coin_lot_size: u64,
+    pub pc_lot_size: u64,
+    pub min_price_multiplier: u64,
+    pub max_price_multiplier: u64,
+    pub sys_decimal_value: u64,
+    pub fees: Fees,
+    pub state_data: StateData,
+    pub coin_vault: Pubkey,
+    pub pc_vault: Pubkey,
+    pub coin_vault_mint: Pubkey,
+    pub pc_vault_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub open_orders: Pubkey,
+    pub market: Pubkey,
+    pub market_program: Pubkey,
+    pub target_orders: Pubkey,
+    pub amm_owner: Pubkey,
+    pub lp_reserve: u64,
+}
+
+impl AmmInfo {
+    pub const LEN: usize = 752;
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut r = reader(data, "AmmInfo", Self::LEN, false)?;
+
+        let status = r.u64();
+        let nonce = r.u64();
+        let order_num = r.u64();
+        let depth = r.u64();
+        let coin_decimals = r.u64();
+        let pc_decimals = r.u64();
+        let state = r.u64();
+        let reset_flag = r.u64();
+        let min_size = r.u64();
+        let vol_max_cut_ratio = r.u64();
+        let amount_wave = r.u64();
+        let coin_lot_size = r.u64();
+        let pc_lot_size = r.u64();
+        let min_price_multiplier = r.u64();
+        let max_price_multiplier = r.u64();
+        let sys_decimal_value = r.u64();
+
+        let fees = Fees {
+            min_separate_numerator: r.u64(),
+            min_separate_denominator: r.u64(),
+            trade_fee_numerator: r.u64(),
+            trade_fee_denominator: r.u64(),
+            pnl_numerator: r.u64(),
+            pnl_denominator: r.u64(),
+            swap_fee_numerator: r.u64(),
+            swap_fee_denominator: r.u64(),
+        };
+
+        let state_data = StateData {
+            need_take_pnl_coin: r.u64(),
+            need_take_pnl_pc: r.u64(),
+            total_pnl_pc: r.u64(),
+            total_pnl_coin: r.u64(),
+            pool_open_time: r.u64(),
+            punish_pc_amount: r.u64(),
+            punish_coin_amount: r.u64(),
+            orderbook_to_init_time: r.u64(),
+            swap_coin_in_amount: r.u128(),
+            swap_pc_out_amount: r.u128(),
+            swap_acc_pc_fee: r.u64(),
+            swap_pc_in_amount: r.u128(),
+            swap_coin_out_amount: r.u128(),
+            swap_acc_coin_fee: r.u64(),
+        };
+
+        let coin_vault = r.pubkey();
+        let pc_vault = r.pubkey();
+        let coin_vault_mint = r.pubkey();
+        let pc_vault_mint = r.pubkey();
+        let lp_mint = r.pubkey();
+        let open_orders = r.pubkey();
+        let market = r.pubkey();
+        let market_program = r.pubkey();
+        let target_orders = r.pubkey();
+        r.skip(64); // padding1
+        let amm_owner = r.pubkey();
+        let lp_reserve = r.u64();
+
+        Ok(Self {
+            status,
+            nonce,
+            order_num,
+            depth,
+            coin_decimals,
+            pc_decimals,
+            state,
+            reset_flag,
+            min_size,
+            vol_max_cut_ratio,
+            amount_wave,
+            coin_lot_size,
+            pc_lot_size,
+            min_price_multiplier,
+            max_price_multiplier,
+            sys_decimal_value,
+            fees,
+            state_data,
+            coin_vault,
+            pc_vault,
+            coin_vault_mint,
+            pc_vault_mint,
+            lp_mint,
+            open_orders,
+            market,
+            market_program,
+            target_orders,
+            amm_owner,
+            lp_reserve,
+        })
+    }
+
+    /// Swap fee in basis points
+    pub fn swap_fee_bps(&self) -> u64 {
+        if self.fees.swap_fee_denominator == 0 {
+            return 0;
+        }
+        self.fees.swap_fee_numerator * 10_000 / self.fees.swap_fee_denominator
+    }
+
+    /// Tradable reserves: vault balances minus PnL owed to the protocol
+    pub fn reserves(&self, coin_vault_amount: u64, pc_vault_amount: u64) -> (u64, u64) {
+        (
+            coin_vault_amount.saturating_sub(self.state_data.need_take_pnl_coin),
+            pc_vault_amount.saturating_sub(self.state_data.need_take_pnl_pc),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const FIXTURE: &[u8] = include_bytes!("../../fixtures/accounts/raydium_amm_v4.bin");
+
+    #[test]
+    fn test_decode_amm_info_fixture() {
+        let amm = AmmInfo::decode(FIXTURE).unwrap();
+
+        assert_eq!(amm.status, 6);
+        assert_eq!(amm.nonce, 254);
+        assert_eq!(amm.coin_decimals, 9);
+        assert_eq!(amm.pc_decimals, 6);
+        assert_eq!(amm.coin_lot_size, 1_000_000);
+        assert_eq!(amm.fees.trade_fee_numerator, 25);
+        assert_eq!(amm.fees.trade_fee_denominator, 10_000);
+        assert_eq!(amm.fees.pnl_numerator, 12);
+        assert_eq!(amm.swap_fee_bps(), 25);
+        assert_eq!(amm.state_data.need_take_pnl_coin, 1_500_000);
+        assert_eq!(amm.state_data.need_take_pnl_pc, 250_000);
+        assert_eq!(amm.state_data.pool_open_time, 1_650_000_000);
+        assert_eq!(amm.coin_vault, Pubkey::new_from_array([1; 32]));
+        assert_eq!(amm.pc_vault, Pubkey::new_from_array([2; 32]));
+        assert_eq!(
+            amm.coin_vault_mint,
+            Pubkey::from_str("So11111111111111111111111111111111111111112").unwrap()
+        );
+        assert_eq!(
+            amm.pc_vault_mint,
+            Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap()
+        );
+        assert_eq!(amm.lp_mint, Pubkey::new_from_array([3; 32]));
+        assert_eq!(amm.market, Pubkey::new_from_array([5; 32]));
+        assert_eq!(amm.target_orders, Pubkey::new_from_array([7; 32]));
+        assert_eq!(amm.amm_owner, Pubkey::new_from_array([8; 32]));
+        assert_eq!(amm.lp_reserve, 2_000_000_000);
+    }
+
+    #[test]
+    fn test_reserves_exclude_pending_pnl() {
+        let amm = AmmInfo::decode(FIXTURE).unwrap();
+        assert_eq!(amm.reserves(10_000_000_000, 1_000_000), (9_998_500_000, 750_000));
+    }
+}
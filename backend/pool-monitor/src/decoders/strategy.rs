@@ -0,0 +1,223 @@
+/*!
+ * strategy-registry `StrategyAccount` layout
+ *
+ * Anchor (Borsh) account owned by the strategy-registry program. The `dexs`
+ * and `token_pairs` vectors make it variable length, so the size is checked
+ * again once their lengths are read.
+ */
+
+use super::{reader, DecodeError};
+use crate::types::DexType;
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrategyStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Paused,
+    Retired,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenPair {
+    pub token_a: Pubkey,
+    pub token_b: Pubkey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrategyAccount {
+    pub creator: Pubkey,
+    pub strategy_id: u64,
+    pub dexs: Vec<DexType>,
+    pub token_pairs: Vec<TokenPair>,
+    pub profit_threshold: u16,
+    pub max_slippage: u16,
+    pub status: StrategyStatus,
+    pub total_profit: u64,
+    pub execution_count: u64,
+    pub success_count: u64,
+    pub last_execution: i64,
+    pub bump: u8,
+    pub version: u32,
+    pub emergency_paused: bool,
+    pub min_slots_between_executions: u64,
+    pub max_executions_per_epoch: u32,
+    pub last_execution_slot: u64,
+    pub execution_epoch: u64,
+    pub epoch_execution_count: u32,
+    pub max_borrow: u64,
+}
+
+impl StrategyAccount {
+    /// Account size for the given vector lengths (mirrors the program)
+    pub const fn space(num_dexs: usize, num_token_pairs: usize) -> usize {
+        8 + 32 + 8 + 4 + num_dexs + 4 + num_token_pairs * 64 + 2 + 2 + 1 + 8 + 8 + 8 + 8 + 1
+            + 4 + 1 + 8 + 4 + 8 + 8 + 4 + 8
+    }
+
+    pub fn decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut r = reader(data, "StrategyAccount", Self::space(0, 0), true)?;
+
+        let creator = r.pubkey();
+        let strategy_id = r.u64();
+
+        let num_dexs = r.u32() as usize;
+        check_len(data, Self::space(num_dexs, 0))?;
+        let dexs = (0..num_dexs)
+            .map(|_| dex_type(r.u8()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let num_token_pairs = r.u32() as usize;
+        check_len(data, Self::space(num_dexs, num_token_pairs))?;
+        let token_pairs = (0..num_token_pairs)
+            .map(|_| TokenPair {
+                token_a: r.pubkey(),
+                token_b: r.pubkey(),
+            })
+            .collect();
+
+        Ok(Self {
+            creator,
+            strategy_id,
+            dexs,
+            token_pairs,
+            profit_threshold: r.u16(),
+            max_slippage: r.u16(),
+            status: status(r.u8())?,
+            total_profit: r.u64(),
+            execution_count: r.u64(),
+            success_count: r.u64(),
+            last_execution: r.i64(),
+            bump: r.u8(),
+            version: r.u32(),
+            emergency_paused: r.bool(),
+            min_slots_between_executions: r.u64(),
+            max_executions_per_epoch: r.u32(),
+            last_execution_slot: r.u64(),
+            execution_epoch: r.u64(),
+            epoch_execution_count: r.u32(),
+            max_borrow: r.u64(),
+        })
+    }
+}
+
+fn check_len(data: &[u8], expected: usize) -> Result<(), DecodeError> {
+    if data.len() < expected {
+        return Err(DecodeError::InvalidLength {
+            account: "StrategyAccount",
+            expected,
+            actual: data.len(),
+        });
+    }
+    Ok(())
+}
+
+fn dex_type(value: u8) -> Result<DexType, DecodeError> {
+    Ok(match value {
+        0 => DexType::Raydium,
+        1 => DexType::Orca,
+        2 => DexType::Meteora,
+        3 => DexType::Phoenix,
+        4 => DexType::Lifinity,
+        _ => return Err(invalid_variant("dexs", value)),
+    })
+}
+
+fn status(value: u8) -> Result<StrategyStatus, DecodeError> {
+    Ok(match value {
+        0 => StrategyStatus::Pending,
+        1 => StrategyStatus::Approved,
+        2 => StrategyStatus::Rejected,
+        3 => StrategyStatus::Paused,
+        4 => StrategyStatus::Retired,
+        _ => return Err(invalid_variant("status", value)),
+    })
+}
+
+fn invalid_variant(field: &'static str, value: u8) -> DecodeError {
+    DecodeError::InvalidVariant {
+        account: "StrategyAccount",
+        field,
+        value,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::decoders::anchor_discriminator;
+
+    /// Borsh-encode a strategy the way the program stores it
+    pub(crate) fn encode(
+        dexs: &[u8],
+        token_pairs: &[(Pubkey, Pubkey)],
+        profit_threshold: u16,
+        status: u8,
+        max_borrow: u64,
+    ) -> Vec<u8> {
+        let mut data = anchor_discriminator("StrategyAccount").to_vec();
+        data.extend_from_slice(&[7u8; 32]); // creator
+        data.extend_from_slice(&42u64.to_le_bytes()); // strategy_id
+        data.extend_from_slice(&(dexs.len() as u32).to_le_bytes());
+        data.extend_from_slice(dexs);
+        data.extend_from_slice(&(token_pairs.len() as u32).to_le_bytes());
+        for (token_a, token_b) in token_pairs {
+            data.extend_from_slice(token_a.as_ref());
+            data.extend_from_slice(token_b.as_ref());
+        }
+        data.extend_from_slice(&profit_threshold.to_le_bytes());
+        data.extend_from_slice(&100u16.to_le_bytes()); // max_slippage
+        data.push(status);
+        data.extend_from_slice(&[0u8; 8 * 4]); // metrics
+        data.push(254); // bump
+        data.extend_from_slice(&3u32.to_le_bytes()); // version
+        data.push(0); // emergency_paused
+        data.extend_from_slice(&[0u8; 8 + 4 + 8 + 8 + 4]); // rate limits
+        data.extend_from_slice(&max_borrow.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_decode_strategy() {
+        let sol = Pubkey::new_unique();
+        let usdc = Pubkey::new_unique();
+        let data = encode(&[0, 1], &[(sol, usdc)], 50, 1, 5_000_000_000);
+        assert_eq!(data.len(), StrategyAccount::space(2, 1));
+
+        let strategy = StrategyAccount::decode(&data).unwrap();
+        assert_eq!(strategy.creator, Pubkey::new_from_array([7; 32]));
+        assert_eq!(strategy.strategy_id, 42);
+        assert!(matches!(strategy.dexs[..], [DexType::Raydium, DexType::Orca]));
+        assert_eq!(strategy.token_pairs, vec![TokenPair { token_a: sol, token_b: usdc }]);
+        assert_eq!(strategy.profit_threshold, 50);
+        assert_eq!(strategy.max_slippage, 100);
+        assert_eq!(strategy.status, StrategyStatus::Approved);
+        assert_eq!(strategy.version, 3);
+        assert_eq!(strategy.max_borrow, 5_000_000_000);
+        assert_eq!(strategy.bump, 254);
+    }
+
+    #[test]
+    fn test_truncated_vectors_are_rejected() {
+        let data = encode(&[0, 1], &[(Pubkey::new_unique(), Pubkey::new_unique())], 50, 1, 0);
+        let err = StrategyAccount::decode(&data[..data.len() - 1]).unwrap_err();
+        assert_eq!(
+            err,
+            DecodeError::InvalidLength {
+                account: "StrategyAccount",
+                expected: StrategyAccount::space(2, 1),
+                actual: data.len() - 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_unknown_variant_is_rejected() {
+        let data = encode(&[9], &[], 50, 1, 0);
+        assert!(matches!(
+            StrategyAccount::decode(&data),
+            Err(DecodeError::InvalidVariant { field: "dexs", value: 9, .. })
+        ));
+    }
+}
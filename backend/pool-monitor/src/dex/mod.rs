@@ -5,3 +5,4 @@
 pub mod raydium;
 pub mod orca;
 pub mod meteora;
+pub mod onchain;
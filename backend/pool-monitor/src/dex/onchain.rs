@@ -0,0 +1,435 @@
+/*!
+ * On-chain pool monitor
+ *
+ * Subscribes to pool state and vault token accounts over the RPC websocket
+ * and reprices a pool as soon as any of its accounts change, instead of
+ * waiting for the next REST poll. Orca tick arrays and Meteora bin arrays
+ * listed for a pool are streamed too, so its swap model can cross ticks/bins.
+ */
+
+use crate::config::Config;
+use crate::decoders::{
+    meteora::{BinArray, LbPair},
+    orca::{TickArray, Whirlpool},
+    raydium::AmmInfo,
+};
+use crate::math::{
+    whirlpool::{active_reserves, sqrt_price_to_price_x64},
+    x64_to_f64,
+};
+use crate::opportunity::OpportunityDetector;
+use crate::quote::{
+    constant_product::{ConstantProductModel, RAYDIUM_FEE_DENOMINATOR, RAYDIUM_FEE_NUMERATOR},
+    dlmm::DlmmModel,
+    whirlpool::WhirlpoolModel,
+    PoolModel,
+};
+use crate::subscription::{
+    websocket_url, AccountHandler, AccountSubscriber, AccountUpdate, SubscriptionRequest,
+};
+use crate::types::{DexType, PoolPrice};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info, warn};
+
+/// SPL token account: mint (32) + owner (32) + amount (u64)
+const TOKEN_AMOUNT_OFFSET: usize = 64;
+
+/// Pool to watch, as listed in WATCHED_POOLS_FILE
+#[derive(Debug, Clone, Deserialize)]
+pub struct WatchedPool {
+    pub dex: DexType,
+    pub pool_address: String,
+    pub token_a: String,
+    pub token_b: String,
+    pub decimals_a: u8,
+    pub decimals_b: u8,
+    pub vault_a: String,
+    pub vault_b: String,
+    /// Orca tick arrays around the current price (needed to quote across ticks)
+    #[serde(default)]
+    pub tick_arrays: Vec<String>,
+    /// Meteora bin arrays around the active bin (needed to quote at all)
+    #[serde(default)]
+    pub bin_arrays: Vec<String>,
+}
+
+/// Load the watched pool list from a JSON file
+pub fn load_watched_pools(path: &str) -> Result<Vec<WatchedPool>> {
+    let contents = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum AccountRole {
+    Pool,
+    VaultA,
+    VaultB,
+    TickArray,
+    BinArray,
+}
+
+/// Latest decoded account data for one pool
+#[derive(Debug, Default)]
+struct PoolState {
+    /// Raydium pool state (PnL owed to the protocol is excluded from reserves)
+    amm: Option<AmmInfo>,
+    whirlpool: Option<Whirlpool>,
+    lb_pair: Option<LbPair>,
+    reserve_a: Option<u64>,
+    reserve_b: Option<u64>,
+    tick_arrays: HashMap<String, TickArray>,
+    bin_arrays: HashMap<String, BinArray>,
+}
+
+pub struct OnChainMonitor {
+    config: Arc<Config>,
+    detector: Arc<OpportunityDetector>,
+    pools: Vec<WatchedPool>,
+    accounts: HashMap<String, (usize, AccountRole)>,
+    states: Mutex<Vec<PoolState>>,
+}
+
+impl OnChainMonitor {
+    pub fn new(
+        config: Arc<Config>,
+        detector: Arc<OpportunityDetector>,
+        pools: Vec<WatchedPool>,
+    ) -> Self {
+        let mut accounts = HashMap::new();
+        for (index, pool) in pools.iter().enumerate() {
+            accounts.insert(pool.pool_address.clone(), (index, AccountRole::Pool));
+            accounts.insert(pool.vault_a.clone(), (index, AccountRole::VaultA));
+            accounts.insert(pool.vault_b.clone(), (index, AccountRole::VaultB));
+            for address in &pool.tick_arrays {
+                accounts.insert(address.clone(), (index, AccountRole::TickArray));
+            }
+            for address in &pool.bin_arrays {
+                accounts.insert(address.clone(), (index, AccountRole::BinArray));
+            }
+        }
+
+        let states = pools.iter().map(|_| PoolState::default()).collect();
+
+        Self {
+            config,
+            detector,
+            pools,
+            accounts,
+            states: Mutex::new(states),
+        }
+    }
+
+    pub async fn start(&self) -> Result<()> {
+        info!("Starting on-chain monitor for {} pools...", self.pools.len());
+
+        let requests = self
+            .accounts
+            .keys()
+            .map(|pubkey| SubscriptionRequest::Account(pubkey.clone()))
+            .collect();
+
+        let subscriber = AccountSubscriber::new(websocket_url(&self.config.helius_rpc), requests);
+        subscriber.run(self).await;
+
+        Ok(())
+    }
+
+    /// Record an account update and return the new pool price (and swap
+    /// model, when one can be built) once known
+    fn apply_update(&self, update: &AccountUpdate) -> Option<(PoolPrice, Option<PoolModel>)> {
+        let &(index, role) = self.accounts.get(&update.pubkey)?;
+        let pool = &self.pools[index];
+
+        let mut states = self.states.lock().unwrap();
+        let state = &mut states[index];
+
+        match role {
+            AccountRole::Pool => decode_pool(pool, &update.data, state),
+            AccountRole::VaultA => state.reserve_a = decode_token_amount(&update.data),
+            AccountRole::VaultB => state.reserve_b = decode_token_amount(&update.data),
+            AccountRole::TickArray => match TickArray::decode(&update.data) {
+                Ok(array) => {
+                    state.tick_arrays.insert(update.pubkey.clone(), array);
+                }
+                Err(e) => warn!("Could not decode tick array {}: {}", update.pubkey, e),
+            },
+            AccountRole::BinArray => match BinArray::decode(&update.data) {
+                Ok(array) => {
+                    state.bin_arrays.insert(update.pubkey.clone(), array);
+                }
+                Err(e) => warn!("Could not decode bin array {}: {}", update.pubkey, e),
+            },
+        }
+
+        let price = pool_price(pool, state)?;
+        Some((price, pool_model(pool, state)))
+    }
+}
+
+#[async_trait]
+impl AccountHandler for OnChainMonitor {
+    async fn on_account_update(&self, update: AccountUpdate) {
+        match self.apply_update(&update) {
+            Some((price, Some(model))) => self.detector.update_pool_model(price, model).await,
+            Some((price, None)) => self.detector.update_pool_price(price).await,
+            None => debug!("Waiting for remaining accounts of {}", update.pubkey),
+        }
+    }
+}
+
+fn decode_token_amount(data: &[u8]) -> Option<u64> {
+    let bytes = data.get(TOKEN_AMOUNT_OFFSET..TOKEN_AMOUNT_OFFSET + 8)?;
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+/// Read pricing state from the pool account
+fn decode_pool(pool: &WatchedPool, data: &[u8], state: &mut PoolState) {
+    let decoded = match pool.dex {
+        DexType::Raydium => AmmInfo::decode(data).map(|amm| state.amm = Some(amm)),
+        DexType::Orca => Whirlpool::decode(data).map(|whirlpool| state.whirlpool = Some(whirlpool)),
+        DexType::Meteora => LbPair::decode(data).map(|pair| state.lb_pair = Some(pair)),
+        _ => Ok(()),
+    };
+
+    if let Err(e) = decoded {
+        warn!("Could not decode pool {}: {}", pool.pool_address, e);
+    }
+}
+
+/// Swap model for trade sizing, if enough accounts are known to build one
+fn pool_model(pool: &WatchedPool, state: &PoolState) -> Option<PoolModel> {
+    match pool.dex {
+        DexType::Orca => {
+            let whirlpool = state.whirlpool.as_ref()?;
+            let arrays: Vec<&TickArray> = state.tick_arrays.values().collect();
+            Some(PoolModel::Whirlpool(WhirlpoolModel::from_accounts(whirlpool, &arrays)))
+        }
+        DexType::Meteora => {
+            // Without bin arrays there is no liquidity to quote against
+            if state.bin_arrays.is_empty() {
+                return None;
+            }
+            let pair = state.lb_pair.as_ref()?;
+            let arrays: Vec<&BinArray> = state.bin_arrays.values().collect();
+            Some(PoolModel::Dlmm(DlmmModel::from_accounts(pair, &arrays)))
+        }
+        _ => {
+            let vault_a = state.reserve_a?;
+            let vault_b = state.reserve_b?;
+            let model = match &state.amm {
+                Some(amm) => ConstantProductModel::from_amm(amm, vault_a, vault_b),
+                None => ConstantProductModel {
+                    reserve_a: vault_a,
+                    reserve_b: vault_b,
+                    fee_numerator: RAYDIUM_FEE_NUMERATOR,
+                    fee_denominator: RAYDIUM_FEE_DENOMINATOR,
+                },
+            };
+            Some(PoolModel::ConstantProduct(model))
+        }
+    }
+}
+
+fn pool_price(pool: &WatchedPool, state: &PoolState) -> Option<PoolPrice> {
+    let vault_a = state.reserve_a?;
+    let vault_b = state.reserve_b?;
+
+    let (price, reserve_a, reserve_b) = match pool.dex {
+        DexType::Orca => {
+            let whirlpool = state.whirlpool.as_ref()?;
+            let price_x64 =
+                sqrt_price_to_price_x64(whirlpool.sqrt_price, pool.decimals_a, pool.decimals_b)?;
+            let (reserve_a, reserve_b) =
+                active_reserves(whirlpool.liquidity, whirlpool.sqrt_price, vault_a, vault_b);
+            (x64_to_f64(price_x64), reserve_a, reserve_b)
+        }
+        DexType::Meteora => {
+            let pair = state.lb_pair.as_ref()?;
+            let base = 1.0 + pair.bin_step as f64 / 10_000.0;
+            let decimals_adjustment = 10f64.powi(pool.decimals_a as i32 - pool.decimals_b as i32);
+            (base.powi(pair.active_id) * decimals_adjustment, vault_a, vault_b)
+        }
+        _ => {
+            let (reserve_a, reserve_b) = match &state.amm {
+                Some(amm) => amm.reserves(vault_a, vault_b),
+                None => (vault_a, vault_b),
+            };
+            if reserve_a == 0 {
+                return None;
+            }
+            let a = reserve_a as f64 / 10f64.powi(pool.decimals_a as i32);
+            let b = reserve_b as f64 / 10f64.powi(pool.decimals_b as i32);
+            (b / a, reserve_a, reserve_b)
+        }
+    };
+
+    if !price.is_finite() || price <= 0.0 {
+        return None;
+    }
+
+    Some(PoolPrice {
+        dex: pool.dex.clone(),
+        pool_address: pool.pool_address.clone(),
+        token_a: pool.token_a.clone(),
+        token_b: pool.token_b.clone(),
+        price,
+        liquidity_a: reserve_a,
+        liquidity_b: reserve_b,
+        timestamp: Utc::now().timestamp(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+
+    fn watched(dex: DexType) -> WatchedPool {
+        WatchedPool {
+            dex,
+            pool_address: "pool".to_string(),
+            token_a: "SOL".to_string(),
+            token_b: "USDC".to_string(),
+            decimals_a: 9,
+            decimals_b: 6,
+            vault_a: "vault-a".to_string(),
+            vault_b: "vault-b".to_string(),
+            tick_arrays: Vec::new(),
+            bin_arrays: Vec::new(),
+        }
+    }
+
+    fn monitor(pool: WatchedPool) -> OnChainMonitor {
+        let config = Arc::new(Config::for_tests());
+        let (sender, _) = mpsc::channel(16);
+        let detector = Arc::new(OpportunityDetector::new(config.clone(), sender));
+        OnChainMonitor::new(config, detector, vec![pool])
+    }
+
+    fn token_account(amount: u64) -> Vec<u8> {
+        let mut data = vec![0u8; 165];
+        data[TOKEN_AMOUNT_OFFSET..TOKEN_AMOUNT_OFFSET + 8].copy_from_slice(&amount.to_le_bytes());
+        data
+    }
+
+    fn update(pubkey: &str, data: Vec<u8>) -> AccountUpdate {
+        AccountUpdate { pubkey: pubkey.to_string(), slot: 1, data }
+    }
+
+    #[test]
+    fn test_raydium_priced_from_vault_reserves() {
+        let monitor = monitor(watched(DexType::Raydium));
+
+        // 10 SOL and 1,500 USDC
+        assert!(monitor.apply_update(&update("vault-a", token_account(10_000_000_000))).is_none());
+        let price = monitor.apply_update(&update("vault-b", token_account(1_500_000_000))).unwrap().0;
+
+        assert!((price.price - 150.0).abs() < 1e-9);
+        assert_eq!(price.liquidity_a, 10_000_000_000);
+        assert_eq!(price.liquidity_b, 1_500_000_000);
+    }
+
+    #[test]
+    fn test_raydium_reserves_exclude_pending_pnl() {
+        let monitor = monitor(watched(DexType::Raydium));
+        let amm = include_bytes!("../../fixtures/accounts/raydium_amm_v4.bin").to_vec();
+
+        // Fixture owes 0.0015 SOL and 0.25 USDC of PnL
+        monitor.apply_update(&update("pool", amm));
+        monitor.apply_update(&update("vault-a", token_account(10_001_500_000)));
+        let price = monitor.apply_update(&update("vault-b", token_account(1_500_250_000))).unwrap().0;
+
+        assert!((price.price - 150.0).abs() < 1e-9);
+        assert_eq!(price.liquidity_a, 10_000_000_000);
+    }
+
+    #[test]
+    fn test_orca_priced_from_sqrt_price() {
+        let monitor = monitor(watched(DexType::Orca));
+        let whirlpool = include_bytes!("../../fixtures/accounts/orca_whirlpool.bin").to_vec();
+
+        monitor.apply_update(&update("vault-a", token_account(u64::MAX)));
+        monitor.apply_update(&update("vault-b", token_account(1_000_000)));
+        let price = monitor.apply_update(&update("pool", whirlpool)).unwrap().0;
+
+        // Fixture sqrt_price is sqrt(0.15) in Q64.64: 150 USDC/SOL after decimals
+        assert!((price.price - 150.0).abs() < 1e-9);
+
+        // In-range liquidity L = 123_456_789_012_345: L / sqrt(P) of SOL, capped
+        // at the USDC vault balance
+        let (virtual_a, _) = crate::math::whirlpool::virtual_reserves(
+            123_456_789_012_345,
+            7_144_393_258_922_745_604,
+        );
+        assert_eq!(price.liquidity_a as u128, virtual_a);
+        assert_eq!(price.liquidity_b, 1_000_000);
+    }
+
+    #[test]
+    fn test_meteora_priced_from_active_bin() {
+        let monitor = monitor(watched(DexType::Meteora));
+        let lb_pair = include_bytes!("../../fixtures/accounts/meteora_lb_pair.bin").to_vec();
+
+        monitor.apply_update(&update("pool", lb_pair));
+        monitor.apply_update(&update("vault-a", token_account(1)));
+        let (price, model) = monitor.apply_update(&update("vault-b", token_account(1))).unwrap();
+
+        // Fixture: active_id -1800, bin_step 25
+        let expected = 1.0025f64.powi(-1800) * 1_000.0;
+        assert!((price.price - expected).abs() < 1e-9);
+
+        // No bin arrays watched, so no liquidity to quote against
+        assert!(model.is_none());
+    }
+
+    #[test]
+    fn test_watched_arrays_feed_swap_model() {
+        let mut pool = watched(DexType::Meteora);
+        pool.bin_arrays = vec!["bins".to_string()];
+        let monitor = monitor(pool);
+
+        monitor.apply_update(&update("pool", include_bytes!("../../fixtures/accounts/meteora_lb_pair.bin").to_vec()));
+        monitor.apply_update(&update("vault-a", token_account(1)));
+        monitor.apply_update(&update("vault-b", token_account(1)));
+        let (_, model) = monitor
+            .apply_update(&update("bins", include_bytes!("../../fixtures/accounts/meteora_bin_array.bin").to_vec()))
+            .unwrap();
+
+        match model {
+            Some(PoolModel::Dlmm(model)) => assert!(!model.bins.is_empty()),
+            other => panic!("expected DLMM model, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_raydium_model_uses_amm_fees() {
+        let monitor = monitor(watched(DexType::Raydium));
+
+        monitor.apply_update(&update("pool", include_bytes!("../../fixtures/accounts/raydium_amm_v4.bin").to_vec()));
+        monitor.apply_update(&update("vault-a", token_account(10_001_500_000)));
+        let (_, model) = monitor.apply_update(&update("vault-b", token_account(1_500_250_000))).unwrap();
+
+        match model {
+            Some(PoolModel::ConstantProduct(model)) => {
+                assert_eq!(model.reserve_a, 10_000_000_000);
+                assert_eq!(model.reserve_b, 1_500_000_000);
+            }
+            other => panic!("expected constant product model, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unknown_and_truncated_accounts_are_ignored() {
+        let monitor = monitor(watched(DexType::Raydium));
+
+        assert!(monitor.apply_update(&update("other", token_account(1))).is_none());
+        monitor.apply_update(&update("pool", vec![0u8; 10]));
+        monitor.apply_update(&update("vault-a", vec![0u8; 10]));
+        assert!(monitor.apply_update(&update("vault-b", token_account(1))).is_none());
+    }
+}
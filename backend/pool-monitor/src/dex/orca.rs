@@ -5,6 +5,10 @@
  */
 
 use crate::config::Config;
+use crate::math::{
+    whirlpool::{sqrt_price_to_price_x64, virtual_reserves},
+    x64_to_f64,
+};
 use crate::opportunity::OpportunityDetector;
 use crate::types::{DexType, PoolPrice};
 use anyhow::Result;
@@ -45,6 +49,9 @@ struct WhirlpoolData {
     token_vault_b: String,
     #[serde(rename = "sqrtPrice")]
     sqrt_price: String,
+    /// In-range liquidity (L) of the current tick
+    #[serde(default)]
+    liquidity: Option<String>,
 }
 
 pub struct OrcaMonitor {
@@ -106,23 +113,30 @@ impl OrcaMonitor {
     }
 
     fn parse_whirlpool(&self, whirlpool: &OrcaWhirlpool) -> Option<PoolPrice> {
-        // Parse sqrt_price to calculate actual price
-        // In Whirlpool, price = (sqrt_price / 2^64)^2
-        let sqrt_price_str = &whirlpool.whirlpool_data.sqrt_price;
-        let sqrt_price_u128 = sqrt_price_str.parse::<u128>().ok()?;
+        let sqrt_price = whirlpool.whirlpool_data.sqrt_price.parse::<u128>().ok()?;
 
-        if sqrt_price_u128 == 0 {
+        if sqrt_price == 0 {
             return None;
         }
 
-        // Calculate price (simplified calculation)
-        // Real implementation should use Q64.64 fixed-point math
-        let sqrt_price_f64 = sqrt_price_u128 as f64 / (1u128 << 64) as f64;
-        let price = sqrt_price_f64 * sqrt_price_f64;
+        // Exact Q64.64 price of one whole token A in token B
+        let price_x64 = sqrt_price_to_price_x64(
+            sqrt_price,
+            whirlpool.token_a.decimals,
+            whirlpool.token_b.decimals,
+        )?;
+        let price = x64_to_f64(price_x64);
 
-        // For now, use mock liquidity values (real implementation would fetch vault balances)
-        let liquidity_a = 1_000_000_000u64; // 1 SOL equivalent
-        let liquidity_b = 1_000_000_000u64;
+        if price <= 0.0 {
+            return None;
+        }
+
+        // Depth at the current tick (vault balances are only known on-chain)
+        let liquidity = match &whirlpool.whirlpool_data.liquidity {
+            Some(liquidity) => liquidity.parse::<u128>().ok()?,
+            None => 0,
+        };
+        let (liquidity_a, liquidity_b) = virtual_reserves(liquidity, sqrt_price);
 
         Some(PoolPrice {
             dex: DexType::Orca,
@@ -130,9 +144,45 @@ impl OrcaMonitor {
             token_a: whirlpool.token_a.mint.clone(),
             token_b: whirlpool.token_b.mint.clone(),
             price,
-            liquidity_a,
-            liquidity_b,
+            liquidity_a: liquidity_a.min(u64::MAX as u128) as u64,
+            liquidity_b: liquidity_b.min(u64::MAX as u128) as u64,
             timestamp: Utc::now().timestamp(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+
+    fn monitor() -> OrcaMonitor {
+        let config = Arc::new(Config::for_tests());
+        let (sender, _) = mpsc::channel(16);
+        let detector = Arc::new(OpportunityDetector::new(config.clone(), sender));
+        OrcaMonitor::new(config, detector)
+    }
+
+    #[test]
+    fn test_parse_whirlpool_adjusts_decimals_and_liquidity() {
+        let whirlpool: OrcaWhirlpool = serde_json::from_value(serde_json::json!({
+            "address": "pool",
+            "tokenA": { "mint": "SOL", "decimals": 9 },
+            "tokenB": { "mint": "USDC", "decimals": 6 },
+            "whirlpool_data": {
+                "tokenVaultA": "vault-a",
+                "tokenVaultB": "vault-b",
+                "sqrtPrice": "7144393258922745604",
+                "liquidity": "1000000000000"
+            }
+        }))
+        .unwrap();
+
+        let price = monitor().parse_whirlpool(&whirlpool).unwrap();
+
+        assert!((price.price - 150.0).abs() < 1e-9);
+        // L / sqrt(P) and L * sqrt(P) in raw units
+        assert_eq!(price.liquidity_a, 2_581_988_897_471);
+        assert_eq!(price.liquidity_b, 387_298_334_620);
+    }
+}
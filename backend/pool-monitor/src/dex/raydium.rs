@@ -6,6 +6,10 @@
 
 use crate::config::Config;
 use crate::opportunity::OpportunityDetector;
+use crate::quote::constant_product::{
+    ConstantProductModel, RAYDIUM_FEE_DENOMINATOR, RAYDIUM_FEE_NUMERATOR,
+};
+use crate::quote::PoolModel;
 use crate::types::{DexType, PoolPrice};
 use anyhow::Result;
 use chrono::Utc;
@@ -75,7 +79,13 @@ impl RaydiumMonitor {
                     // Process each pool
                     for pool in pools {
                         if let Some(price) = self.parse_pool(&pool) {
-                            self.detector.update_pool_price(price).await;
+                            let model = PoolModel::ConstantProduct(ConstantProductModel {
+                                reserve_a: price.liquidity_a,
+                                reserve_b: price.liquidity_b,
+                                fee_numerator: RAYDIUM_FEE_NUMERATOR,
+                                fee_denominator: RAYDIUM_FEE_DENOMINATOR,
+                            });
+                            self.detector.update_pool_model(price, model).await;
                         }
                     }
                 }
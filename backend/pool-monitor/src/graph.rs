@@ -0,0 +1,467 @@
+/*!
+ * Token graph for multi-hop arbitrage
+ *
+ * Tokens are nodes and every pool contributes an edge in each direction,
+ * weighted by -ln(rate after fees). A cycle whose weights sum below zero
+ * returns more of the start token than it consumes.
+ *
+ * Cycles are searched outwards from the updated pool rather than from the
+ * start token, so the cost of an update does not grow with the number of
+ * pools paired against SOL.
+ *
+ * Adjacency is sharded by token, so a pool update only locks the shards of
+ * its two tokens and searches from other monitors keep running. Searches
+ * copy what they need out of a shard instead of holding it while recursing.
+ */
+
+use crate::types::PoolPrice;
+use dashmap::DashMap;
+use std::collections::HashMap;
+
+/// Wrapped SOL mint, the start and end of every cycle
+pub const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// One swap of a cycle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleHop {
+    pub pool: String,
+    pub token_in: String,
+    pub token_out: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cycle {
+    pub hops: Vec<CycleHop>,
+    /// Sum of edge weights; the cycle returns exp(-weight) per unit in
+    pub weight: f64,
+}
+
+impl Cycle {
+    /// Output per unit of input after fees (> 1 when profitable)
+    pub fn rate(&self) -> f64 {
+        (-self.weight).exp()
+    }
+}
+
+/// Simple path between a token and the start token
+#[derive(Debug, Default)]
+struct Path {
+    hops: Vec<CycleHop>,
+    weight: f64,
+}
+
+impl Path {
+    fn tokens<'a>(&'a self, start: &'a str) -> impl Iterator<Item = &'a str> {
+        self.hops
+            .iter()
+            .flat_map(|hop| [hop.token_in.as_str(), hop.token_out.as_str()])
+            .filter(move |token| *token != start)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct TokenGraph {
+    /// Edge weight per token_in -> token_out -> pool
+    edges: DashMap<String, HashMap<String, HashMap<String, f64>>>,
+    /// Tokens of each pool, to remove its edges when its price turns invalid
+    pools: DashMap<String, (String, String)>,
+}
+
+impl TokenGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert or reprice the edges of `pool`
+    ///
+    /// `fee_rate` is the swap fee as a fraction of the input. Pools with a
+    /// non-positive or non-finite price are removed. Repricing overwrites the
+    /// edges in place, so concurrent searches never see the pool missing.
+    pub fn upsert_pool(&self, pool: &str, price: &PoolPrice, fee_rate: f64) {
+        let rate_b_per_a = price.price * (1.0 - fee_rate);
+        let rate_a_per_b = (1.0 / price.price) * (1.0 - fee_rate);
+        if !(rate_b_per_a.is_finite() && rate_a_per_b.is_finite())
+            || rate_b_per_a <= 0.0
+            || rate_a_per_b <= 0.0
+        {
+            self.remove_pool(pool);
+            return;
+        }
+
+        self.add_edge(pool, &price.token_a, &price.token_b, rate_b_per_a);
+        self.add_edge(pool, &price.token_b, &price.token_a, rate_a_per_b);
+        self.pools.insert(
+            pool.to_string(),
+            (price.token_a.clone(), price.token_b.clone()),
+        );
+    }
+
+    fn add_edge(&self, pool: &str, token_in: &str, token_out: &str, rate: f64) {
+        self.edges
+            .entry(token_in.to_string())
+            .or_default()
+            .entry(token_out.to_string())
+            .or_default()
+            .insert(pool.to_string(), -rate.ln());
+    }
+
+    fn remove_pool(&self, pool: &str) {
+        let Some((_, (token_a, token_b))) = self.pools.remove(pool) else {
+            return;
+        };
+        for (token_in, token_out) in [(&token_a, &token_b), (&token_b, &token_a)] {
+            if let Some(mut outgoing) = self.edges.get_mut(token_in) {
+                if let Some(pools) = outgoing.get_mut(token_out) {
+                    pools.remove(pool);
+                    if pools.is_empty() {
+                        outgoing.remove(token_out);
+                    }
+                }
+            }
+            // Separate step: removing while holding the shard would deadlock
+            self.edges.remove_if(token_in, |_, outgoing| outgoing.is_empty());
+        }
+    }
+
+    /// Pools and weights for swapping `token_in` into `token_out`
+    fn swaps(&self, token_in: &str, token_out: &str) -> Vec<(String, f64)> {
+        self.edges
+            .get(token_in)
+            .and_then(|outgoing| {
+                outgoing
+                    .get(token_out)
+                    .map(|pools| pools.iter().map(|(pool, weight)| (pool.clone(), *weight)).collect())
+            })
+            .unwrap_or_default()
+    }
+
+    /// Profitable cycles from `start` back to itself that trade through `pool`
+    ///
+    /// Simple cycles of `min_hops..=max_hops` swaps: no token other than
+    /// `start` is visited twice and no pool is used twice. Each direction of
+    /// `pool` is joined with the paths from `start` to its input token and
+    /// from its output token back to `start`.
+    pub fn profitable_cycles(
+        &self,
+        start: &str,
+        pool: &str,
+        min_hops: usize,
+        max_hops: usize,
+    ) -> Vec<Cycle> {
+        let mut cycles = Vec::new();
+        let Some((token_a, token_b)) = self.pools.get(pool).map(|tokens| tokens.clone()) else {
+            return cycles;
+        };
+        if max_hops == 0 {
+            return cycles;
+        }
+
+        for (token_in, token_out) in [(&token_a, &token_b), (&token_b, &token_a)] {
+            let Some(weight) = self
+                .swaps(token_in, token_out)
+                .into_iter()
+                .find(|(edge_pool, _)| edge_pool == pool)
+                .map(|(_, weight)| weight)
+            else {
+                continue;
+            };
+
+            // Each side needs a swap unless it is already at `start`; bounding
+            // one side by the other keeps hub tokens from being walked for
+            // paths that could never fit in max_hops
+            let min_head = usize::from(token_in != start);
+            let min_tail = usize::from(token_out != start);
+            if min_head + 1 + min_tail > max_hops {
+                continue;
+            }
+            let heads = self.paths(token_in, start, pool, max_hops - 1 - min_tail, false);
+            let tails = self.paths(token_out, start, pool, max_hops - 1 - min_head, true);
+
+            for head in &heads {
+                for tail in &tails {
+                    let hops = head.hops.len() + 1 + tail.hops.len();
+                    let total = head.weight + weight + tail.weight;
+                    if hops < min_hops || hops > max_hops || total >= 0.0 {
+                        continue;
+                    }
+
+                    let shares_token = head
+                        .tokens(start)
+                        .any(|token| tail.tokens(start).any(|other| other == token));
+                    let shares_pool = head
+                        .hops
+                        .iter()
+                        .any(|hop| tail.hops.iter().any(|other| other.pool == hop.pool));
+                    if shares_token || shares_pool {
+                        continue;
+                    }
+
+                    let swap = CycleHop {
+                        pool: pool.to_string(),
+                        token_in: token_in.clone(),
+                        token_out: token_out.clone(),
+                    };
+                    cycles.push(Cycle {
+                        hops: head
+                            .hops
+                            .iter()
+                            .cloned()
+                            .chain(std::iter::once(swap))
+                            .chain(tail.hops.iter().cloned())
+                            .collect(),
+                        weight: total,
+                    });
+                }
+            }
+        }
+
+        cycles
+    }
+
+    /// Simple paths of at most `max_len` swaps between `token` and `start`
+    /// that avoid `skip_pool`, in trade order
+    ///
+    /// `forward` paths trade from `token` to `start`; otherwise they trade
+    /// from `start` to `token`.
+    fn paths(
+        &self,
+        token: &str,
+        start: &str,
+        skip_pool: &str,
+        max_len: usize,
+        forward: bool,
+    ) -> Vec<Path> {
+        let mut paths = Vec::new();
+        if token == start {
+            paths.push(Path::default());
+            return paths;
+        }
+
+        let mut hops = Vec::new();
+        self.extend_paths(
+            token, token, start, skip_pool, max_len, forward, 0.0, &mut hops, &mut paths,
+        );
+
+        if !forward {
+            for path in &mut paths {
+                path.hops.reverse();
+            }
+        }
+        paths
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn extend_paths(
+        &self,
+        origin: &str,
+        token: &str,
+        start: &str,
+        skip_pool: &str,
+        remaining: usize,
+        forward: bool,
+        weight: f64,
+        hops: &mut Vec<CycleHop>,
+        paths: &mut Vec<Path>,
+    ) {
+        if remaining == 0 {
+            return;
+        }
+
+        let hop = |pool: &String, next: &str| {
+            let (token_in, token_out) = if forward { (token, next) } else { (next, token) };
+            CycleHop {
+                pool: pool.clone(),
+                token_in: token_in.to_string(),
+                token_out: token_out.to_string(),
+            }
+        };
+        let swaps = |next: &str, hops: &[CycleHop]| {
+            let (token_in, token_out) = if forward { (token, next) } else { (next, token) };
+            let mut swaps = self.swaps(token_in, token_out);
+            swaps.retain(|(pool, _)| pool != skip_pool && !hops.iter().any(|hop| &hop.pool == pool));
+            swaps
+        };
+
+        // Last swap: look up pools into `start` directly instead of walking
+        // its (potentially huge) neighbour list
+        for (pool, edge_weight) in swaps(start, hops) {
+            let mut path = hops.clone();
+            path.push(hop(&pool, start));
+            paths.push(Path {
+                hops: path,
+                weight: weight + edge_weight,
+            });
+        }
+
+        if remaining == 1 {
+            return;
+        }
+
+        // Copy the neighbours so no shard is held while recursing
+        let Some(neighbours) = self
+            .edges
+            .get(token)
+            .map(|outgoing| outgoing.keys().cloned().collect::<Vec<_>>())
+        else {
+            return;
+        };
+        for next in &neighbours {
+            let visited = next == start
+                || next == origin
+                || hops.iter().any(|hop| &hop.token_in == next || &hop.token_out == next);
+            if visited {
+                continue;
+            }
+
+            for (pool, edge_weight) in swaps(next, hops) {
+                hops.push(hop(&pool, next));
+                self.extend_paths(
+                    origin,
+                    next,
+                    start,
+                    skip_pool,
+                    remaining - 1,
+                    forward,
+                    weight + edge_weight,
+                    hops,
+                    paths,
+                );
+                hops.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::DexType;
+
+    fn price(token_a: &str, token_b: &str, price: f64) -> PoolPrice {
+        PoolPrice {
+            dex: DexType::Raydium,
+            pool_address: String::new(),
+            token_a: token_a.to_string(),
+            token_b: token_b.to_string(),
+            price,
+            liquidity_a: 0,
+            liquidity_b: 0,
+            timestamp: 0,
+        }
+    }
+
+    fn triangle(usdc_per_bonk: f64, fee_rate: f64) -> TokenGraph {
+        let graph = TokenGraph::new();
+        graph.upsert_pool("sol-usdc", &price(SOL_MINT, "USDC", 100.0), fee_rate);
+        graph.upsert_pool("bonk-sol", &price("BONK", SOL_MINT, 0.0001), fee_rate);
+        graph.upsert_pool("bonk-usdc", &price("BONK", "USDC", usdc_per_bonk), fee_rate);
+        graph
+    }
+
+    #[test]
+    fn test_finds_triangular_cycle() {
+        // SOL -> USDC -> BONK -> SOL returns 100 / 0.009 * 0.0001 = 1.111 SOL
+        let graph = triangle(0.009, 0.0);
+        let cycles = graph.profitable_cycles(SOL_MINT, "bonk-usdc", 3, 3);
+
+        assert_eq!(cycles.len(), 1);
+        let cycle = &cycles[0];
+        let pools: Vec<&str> = cycle.hops.iter().map(|hop| hop.pool.as_str()).collect();
+        assert_eq!(pools, ["sol-usdc", "bonk-usdc", "bonk-sol"]);
+        assert_eq!(cycle.hops[0].token_in, SOL_MINT);
+        assert_eq!(cycle.hops[2].token_out, SOL_MINT);
+        assert!((cycle.rate() - 100.0 / 0.009 * 0.0001).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_consistent_prices_have_no_cycle() {
+        let graph = triangle(0.01, 0.001);
+        assert!(graph.profitable_cycles(SOL_MINT, "bonk-usdc", 3, 4).is_empty());
+    }
+
+    #[test]
+    fn test_fees_remove_small_cycles() {
+        // 1% gross edge, 0.3% fee on each of three hops
+        let graph = triangle(0.0099, 0.003);
+        let cycles = graph.profitable_cycles(SOL_MINT, "bonk-usdc", 3, 3);
+        assert_eq!(cycles.len(), 1);
+
+        graph.upsert_pool("bonk-usdc", &price("BONK", "USDC", 0.0099), 0.005);
+        assert!(graph.profitable_cycles(SOL_MINT, "bonk-usdc", 3, 3).is_empty());
+    }
+
+    #[test]
+    fn test_cycles_must_use_updated_pool_and_respect_bounds() {
+        let graph = triangle(0.009, 0.0);
+        graph.upsert_pool("other", &price("JUP", "USDC", 1.0), 0.0);
+
+        assert!(graph.profitable_cycles(SOL_MINT, "other", 3, 3).is_empty());
+        assert!(graph.profitable_cycles(SOL_MINT, "bonk-usdc", 3, 2).is_empty());
+    }
+
+    #[test]
+    fn test_longer_cycles_up_to_max_hops() {
+        // SOL -> USDC -> BONK -> JUP -> SOL returns 100 / 0.009 * 0.01 * 0.01 = 1.111 SOL
+        let graph = TokenGraph::new();
+        graph.upsert_pool("sol-usdc", &price(SOL_MINT, "USDC", 100.0), 0.0);
+        graph.upsert_pool("bonk-usdc", &price("BONK", "USDC", 0.009), 0.0);
+        graph.upsert_pool("bonk-jup", &price("BONK", "JUP", 0.01), 0.0);
+        graph.upsert_pool("jup-sol", &price("JUP", SOL_MINT, 0.01), 0.0);
+
+        assert!(graph.profitable_cycles(SOL_MINT, "bonk-jup", 3, 3).is_empty());
+
+        let cycles = graph.profitable_cycles(SOL_MINT, "bonk-jup", 3, 4);
+        assert_eq!(cycles.len(), 1);
+        let pools: Vec<&str> = cycles[0].hops.iter().map(|hop| hop.pool.as_str()).collect();
+        assert_eq!(pools, ["sol-usdc", "bonk-usdc", "bonk-jup", "jup-sol"]);
+        assert!((cycles[0].rate() - 100.0 / 0.009 * 0.01 * 0.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_updates_and_searches_run_concurrently() {
+        let graph = triangle(0.009, 0.0);
+
+        // Writers reprice disjoint pools while readers search the shared ones
+        std::thread::scope(|scope| {
+            for writer in 0..4 {
+                let graph = &graph;
+                scope.spawn(move || {
+                    for step in 0..200 {
+                        let token = format!("TOKEN{writer}");
+                        let pool = format!("pool{writer}");
+                        let rate = if step % 10 == 5 { 0.0 } else { 1.0 + step as f64 };
+                        graph.upsert_pool(&pool, &price(&token, SOL_MINT, rate), 0.0);
+                    }
+                });
+            }
+            for _ in 0..4 {
+                let graph = &graph;
+                scope.spawn(move || {
+                    for _ in 0..200 {
+                        assert_eq!(graph.profitable_cycles(SOL_MINT, "bonk-usdc", 3, 4).len(), 1);
+                    }
+                });
+            }
+        });
+
+        // Each writer's last price was valid, so its pool is still listed
+        for writer in 0..4 {
+            assert!(graph.pools.contains_key(&format!("pool{writer}")));
+        }
+    }
+
+    #[test]
+    fn test_repricing_replaces_edges() {
+        let graph = triangle(0.009, 0.001);
+        graph.upsert_pool("bonk-usdc", &price("BONK", "USDC", 0.01), 0.001);
+        assert!(graph.profitable_cycles(SOL_MINT, "bonk-usdc", 3, 3).is_empty());
+
+        graph.upsert_pool("bonk-usdc", &price("BONK", "USDC", 0.0), 0.0);
+        let edges: usize = graph
+            .edges
+            .iter()
+            .map(|outgoing| outgoing.values().map(HashMap::len).sum::<usize>())
+            .sum();
+        assert_eq!(edges, 4);
+    }
+}
@@ -0,0 +1,17 @@
+/*!
+ * MEVrebels Pool Monitor library
+ *
+ * Exposes the monitor's modules to the binary and to benchmarks.
+ */
+
+pub mod config;
+pub mod decoders;
+pub mod dex;
+pub mod graph;
+pub mod math;
+pub mod opportunity;
+pub mod publisher;
+pub mod quote;
+pub mod strategies;
+pub mod subscription;
+pub mod types;
@@ -4,22 +4,24 @@
  * Monitors DEX pools (Raydium, Orca, Meteora) for arbitrage opportunities
  */
 
-mod config;
-mod dex;
-mod opportunity;
-mod publisher;
-mod types;
-
 use anyhow::Result;
 use std::sync::Arc;
 use tokio::signal;
+use tokio::sync::mpsc;
 use tracing::{error, info};
 use tracing_subscriber;
 
-use crate::config::Config;
-use crate::dex::{raydium::RaydiumMonitor, orca::OrcaMonitor, meteora::MeteoraMonitor};
-use crate::opportunity::OpportunityDetector;
-use crate::publisher::OpportunityPublisher;
+use mevrebels_pool_monitor::config::Config;
+use mevrebels_pool_monitor::dex::{
+    meteora::MeteoraMonitor,
+    onchain::{load_watched_pools, OnChainMonitor},
+    orca::OrcaMonitor,
+    raydium::RaydiumMonitor,
+};
+use mevrebels_pool_monitor::opportunity::OpportunityDetector;
+use mevrebels_pool_monitor::publisher::{OpportunityPublisher, RedisSink};
+use mevrebels_pool_monitor::strategies::{load_strategies, refresh_strategies, StrategySource};
+use std::time::Duration;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -38,42 +40,74 @@ async fn main() -> Result<()> {
     info!("Configuration loaded");
 
     // Initialize Redis publisher
-    let publisher = Arc::new(OpportunityPublisher::new(&config.redis_url).await?);
+    let sink = RedisSink::new(&config.redis_url, config.opportunity_ttl_seconds).await?;
+    let publisher = OpportunityPublisher::new(sink, &config);
     info!("Redis publisher initialized");
 
-    // Initialize opportunity detector
-    let detector = Arc::new(OpportunityDetector::new(config.clone()));
-    info!("Opportunity detector initialized");
+    // Detector -> publisher channel (bounded for backpressure)
+    let (opportunity_tx, opportunity_rx) = mpsc::channel(config.opportunity_channel_capacity);
 
-    // Start DEX monitors
-    let raydium_monitor = RaydiumMonitor::new(config.clone(), detector.clone());
-    let orca_monitor = OrcaMonitor::new(config.clone(), detector.clone());
-    let meteora_monitor = MeteoraMonitor::new(config.clone(), detector.clone());
+    // Initialize opportunity detector
+    let mut detector = OpportunityDetector::new(config.clone(), opportunity_tx);
+    let mut strategy_handle = None;
 
-    info!("Starting DEX monitors...");
+    // Filter by approved strategies before any monitor starts
+    if let Some(source) = StrategySource::from_config(&config) {
+        let book = load_strategies(&source).await?;
+        detector = detector.with_strategies(book.clone());
 
-    // Spawn monitor tasks
-    let raydium_handle = tokio::spawn(async move {
-        if let Err(e) = raydium_monitor.start().await {
-            error!("Raydium monitor error: {}", e);
-        }
-    });
+        let interval = Duration::from_secs(config.strategy_refresh_seconds);
+        strategy_handle = Some(tokio::spawn(refresh_strategies(book, source, interval)));
+    }
 
-    let orca_handle = tokio::spawn(async move {
-        if let Err(e) = orca_monitor.start().await {
-            error!("Orca monitor error: {}", e);
-        }
-    });
+    let detector = Arc::new(detector);
+    info!("Opportunity detector initialized");
 
-    let meteora_handle = tokio::spawn(async move {
-        if let Err(e) = meteora_monitor.start().await {
-            error!("Meteora monitor error: {}", e);
-        }
-    });
+    // Start DEX monitors
+    let mut monitor_handles = Vec::new();
+
+    if let Some(path) = &config.watched_pools_file {
+        // Stream pool accounts over the RPC websocket
+        let pools = load_watched_pools(path)?;
+        let onchain_monitor = OnChainMonitor::new(config.clone(), detector.clone(), pools);
+
+        info!("Starting on-chain pool monitor...");
+
+        monitor_handles.push(tokio::spawn(async move {
+            if let Err(e) = onchain_monitor.start().await {
+                error!("On-chain monitor error: {}", e);
+            }
+        }));
+    } else {
+        // Fall back to polling the DEX REST APIs
+        let raydium_monitor = RaydiumMonitor::new(config.clone(), detector.clone());
+        let orca_monitor = OrcaMonitor::new(config.clone(), detector.clone());
+        let meteora_monitor = MeteoraMonitor::new(config.clone(), detector.clone());
+
+        info!("Starting DEX monitors...");
+
+        monitor_handles.push(tokio::spawn(async move {
+            if let Err(e) = raydium_monitor.start().await {
+                error!("Raydium monitor error: {}", e);
+            }
+        }));
+
+        monitor_handles.push(tokio::spawn(async move {
+            if let Err(e) = orca_monitor.start().await {
+                error!("Orca monitor error: {}", e);
+            }
+        }));
+
+        monitor_handles.push(tokio::spawn(async move {
+            if let Err(e) = meteora_monitor.start().await {
+                error!("Meteora monitor error: {}", e);
+            }
+        }));
+    }
 
     // Spawn opportunity publisher task
     let publisher_handle = tokio::spawn(async move {
-        publisher.start_publishing().await;
+        publisher.start_publishing(opportunity_rx).await;
     });
 
     info!("✅ Pool Monitor started successfully");
@@ -84,10 +118,13 @@ async fn main() -> Result<()> {
     info!("Shutdown signal received, stopping monitors...");
 
     // Cancel tasks
-    raydium_handle.abort();
-    orca_handle.abort();
-    meteora_handle.abort();
+    for handle in monitor_handles {
+        handle.abort();
+    }
     publisher_handle.abort();
+    if let Some(handle) = strategy_handle {
+        handle.abort();
+    }
 
     info!("Pool Monitor stopped");
     Ok(())
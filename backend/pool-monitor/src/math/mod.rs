@@ -0,0 +1,250 @@
+/*!
+ * Fixed-point math for pool pricing
+ *
+ * Prices and square-root prices are Q64.64 fixed-point `u128`s. Intermediate
+ * products go through `U256` so nothing is rounded until the final division.
+ */
+
+pub mod whirlpool;
+
+/// 1.0 in Q64.64
+pub const Q64: u128 = 1 << 64;
+
+/// Minimal 256-bit unsigned integer (little-endian 64-bit limbs)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct U256([u64; 4]);
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl U256 {
+    pub fn from_u128(value: u128) -> Self {
+        U256([value as u64, (value >> 64) as u64, 0, 0])
+    }
+
+    /// Full 256-bit product of two u128s
+    pub fn mul_u128(a: u128, b: u128) -> Self {
+        let a = [a as u64, (a >> 64) as u64];
+        let b = [b as u64, (b >> 64) as u64];
+        let mut limbs = [0u64; 4];
+
+        for (i, &x) in a.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &y) in b.iter().enumerate() {
+                let sum = x as u128 * y as u128 + limbs[i + j] as u128 + carry;
+                limbs[i + j] = sum as u64;
+                carry = sum >> 64;
+            }
+            limbs[i + 2] = carry as u64;
+        }
+
+        U256(limbs)
+    }
+
+    pub fn checked_mul_u64(self, multiplier: u64) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        let mut carry = 0u128;
+
+        for (out, &limb) in limbs.iter_mut().zip(self.0.iter()) {
+            let product = limb as u128 * multiplier as u128 + carry;
+            *out = product as u64;
+            carry = product >> 64;
+        }
+
+        (carry == 0).then_some(U256(limbs))
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        let mut carry = false;
+        for (i, out) in limbs.iter_mut().enumerate() {
+            let (sum, overflow_a) = self.0[i].overflowing_add(other.0[i]);
+            let (sum, overflow_b) = sum.overflowing_add(carry as u64);
+            *out = sum;
+            carry = overflow_a || overflow_b;
+        }
+        (!carry).then_some(U256(limbs))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        let mut borrow = false;
+        for (i, out) in limbs.iter_mut().enumerate() {
+            let (diff, underflow_a) = self.0[i].overflowing_sub(other.0[i]);
+            let (diff, underflow_b) = diff.overflowing_sub(borrow as u64);
+            *out = diff;
+            borrow = underflow_a || underflow_b;
+        }
+        (!borrow).then_some(U256(limbs))
+    }
+
+    /// Quotient and remainder of division by a non-zero U256
+    pub fn div_rem(self, divisor: Self) -> (Self, Self) {
+        if let Some(divisor) = divisor.to_u128() {
+            let (quotient, remainder) = self.div_rem_u128(divisor);
+            return (quotient, U256::from_u128(remainder));
+        }
+
+        // Divisor >= 2^128, so the remainder never needs more than 256 bits
+        let mut quotient = [0u64; 4];
+        let mut remainder = U256::from_u128(0);
+        for bit in (0..256).rev() {
+            remainder = remainder.shl1();
+            remainder.0[0] |= (self.0[bit / 64] >> (bit % 64)) & 1;
+            if remainder >= divisor {
+                remainder = remainder.checked_sub(divisor).unwrap();
+                quotient[bit / 64] |= 1 << (bit % 64);
+            }
+        }
+
+        (U256(quotient), remainder)
+    }
+
+    fn shl1(self) -> Self {
+        U256([
+            self.0[0] << 1,
+            self.0[1] << 1 | self.0[0] >> 63,
+            self.0[2] << 1 | self.0[1] >> 63,
+            self.0[3] << 1 | self.0[2] >> 63,
+        ])
+    }
+
+    /// Quotient and remainder of division by a non-zero u128
+    pub fn div_rem_u128(self, divisor: u128) -> (Self, u128) {
+        assert!(divisor != 0, "division by zero");
+
+        if self.0[2] == 0 && self.0[3] == 0 {
+            let value = self.low_u128();
+            return (U256::from_u128(value / divisor), value % divisor);
+        }
+
+        // Shift-subtract long division; the remainder stays below 2 * divisor
+        let mut quotient = [0u64; 4];
+        let mut remainder = 0u128;
+        for bit in (0..256).rev() {
+            let carry = remainder >> 127;
+            remainder = (remainder << 1) | ((self.0[bit / 64] >> (bit % 64)) & 1) as u128;
+            if carry == 1 || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient[bit / 64] |= 1 << (bit % 64);
+            }
+        }
+
+        (U256(quotient), remainder)
+    }
+
+    pub fn to_u128(self) -> Option<u128> {
+        (self.0[2] == 0 && self.0[3] == 0).then(|| self.low_u128())
+    }
+
+    fn low_u128(self) -> u128 {
+        self.0[0] as u128 | (self.0[1] as u128) << 64
+    }
+}
+
+/// floor(a * b / denominator); None if the denominator is zero or the result overflows
+pub fn mul_div_floor(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    U256::mul_u128(a, b).div_rem_u128(denominator).0.to_u128()
+}
+
+/// ceil(a * b / denominator); None if the denominator is zero or the result overflows
+pub fn mul_div_ceil(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    let (quotient, remainder) = U256::mul_u128(a, b).div_rem_u128(denominator);
+    let quotient = quotient.to_u128()?;
+    if remainder == 0 {
+        Some(quotient)
+    } else {
+        quotient.checked_add(1)
+    }
+}
+
+/// Convert a Q64.64 value to f64 (for display and the f64 detector)
+pub fn x64_to_f64(value: u128) -> f64 {
+    value as f64 / Q64 as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigUint;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn to_big(value: U256) -> BigUint {
+        value
+            .0
+            .iter()
+            .rev()
+            .fold(BigUint::from(0u8), |acc, &limb| (acc << 64) + BigUint::from(limb))
+    }
+
+    #[test]
+    fn test_u256_matches_bigint() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..10_000 {
+            let a: u128 = rng.gen::<u128>() >> rng.gen_range(0..128);
+            let b: u128 = rng.gen::<u128>() >> rng.gen_range(0..128);
+            let m: u64 = rng.gen::<u64>() >> rng.gen_range(0..64);
+            let d: u128 = (rng.gen::<u128>() >> rng.gen_range(0..128)).max(1);
+
+            let product = U256::mul_u128(a, b);
+            let expected = BigUint::from(a) * BigUint::from(b);
+            assert_eq!(to_big(product), expected);
+
+            let (quotient, remainder) = product.div_rem_u128(d);
+            assert_eq!(to_big(quotient), &expected / BigUint::from(d));
+            assert_eq!(BigUint::from(remainder), &expected % BigUint::from(d));
+
+            let other = U256::mul_u128(d, b);
+            let other_big = BigUint::from(d) * BigUint::from(b);
+            let (quotient, remainder) = product.div_rem(other.max(U256::from_u128(1)));
+            if other_big > BigUint::from(0u8) {
+                assert_eq!(to_big(quotient), &expected / &other_big);
+                assert_eq!(to_big(remainder), &expected % &other_big);
+            }
+
+            let sum = &expected + &other_big;
+            match product.checked_add(other) {
+                Some(value) => assert_eq!(to_big(value), sum),
+                None => assert!(sum.bits() > 256),
+            }
+            match product.checked_sub(other) {
+                Some(value) => assert_eq!(to_big(value), &expected - &other_big),
+                None => assert!(expected < other_big),
+            }
+            assert_eq!(product.cmp(&other), expected.cmp(&other_big));
+
+            let scaled = &expected * BigUint::from(m);
+            match product.checked_mul_u64(m) {
+                Some(value) => assert_eq!(to_big(value), scaled),
+                None => assert!(scaled.bits() > 256),
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_div_floor_edges() {
+        assert_eq!(mul_div_floor(u128::MAX, u128::MAX, u128::MAX), Some(u128::MAX));
+        assert_eq!(mul_div_floor(u128::MAX, 2, 1), None);
+        assert_eq!(mul_div_floor(7, 3, 2), Some(10));
+        assert_eq!(mul_div_floor(1, 1, 0), None);
+        assert_eq!(mul_div_ceil(7, 3, 2), Some(11));
+        assert_eq!(mul_div_ceil(6, 3, 2), Some(9));
+        assert_eq!(mul_div_ceil(u128::MAX, u128::MAX, u128::MAX), Some(u128::MAX));
+    }
+}
@@ -0,0 +1,291 @@
+/*!
+ * Orca Whirlpool price math
+ *
+ * `sqrt_price` is the Q64.64 square root of the raw token_b / token_a price.
+ * Prices here are Q64.64 as well and adjusted to whole tokens using the mint
+ * decimals: price = sqrt_price^2 / 2^128 * 10^(decimals_a - decimals_b).
+ */
+
+use super::{mul_div_ceil, mul_div_floor, Q64, U256};
+
+/// Sqrt price bounds enforced by the Whirlpool program
+pub const MIN_SQRT_PRICE_X64: u128 = 4_295_048_016;
+pub const MAX_SQRT_PRICE_X64: u128 = 79_226_673_515_401_279_992_447_579_055;
+
+pub const MIN_TICK_INDEX: i32 = -443_636;
+pub const MAX_TICK_INDEX: i32 = 443_636;
+
+/// sqrt(1.0001)^(2^i) in Q64.64, rounded down
+const SQRT_TICK_POWERS_X64: [u128; 19] = [
+    18_447_666_387_855_959_850,
+    18_448_588_748_116_922_571,
+    18_450_433_606_991_734_263,
+    18_454_123_878_217_468_680,
+    18_461_506_635_090_006_701,
+    18_476_281_010_653_910_144,
+    18_505_865_242_158_250_041,
+    18_565_175_891_880_433_522,
+    18_684_368_066_214_940_582,
+    18_925_053_041_275_764_671,
+    19_415_764_168_677_886_926,
+    20_435_687_552_633_177_494,
+    22_639_080_592_224_303_007,
+    27_784_196_929_998_399_742,
+    41_848_122_137_994_986_128,
+    94_936_283_578_220_370_716,
+    488_590_176_327_622_479_860,
+    12_941_056_668_319_229_769_860,
+    9_078_618_265_828_848_800_676_189,
+];
+
+/// Q64.64 sqrt price at a tick: sqrt(1.0001)^tick
+///
+/// Built from the binary expansion of |tick|, so the result can sit a few
+/// units below the program's own value; fine for quoting.
+pub fn tick_index_to_sqrt_price(tick_index: i32) -> Option<u128> {
+    if !(MIN_TICK_INDEX..=MAX_TICK_INDEX).contains(&tick_index) {
+        return None;
+    }
+
+    let abs_tick = tick_index.unsigned_abs();
+    let mut sqrt_price = Q64;
+    for (bit, &factor) in SQRT_TICK_POWERS_X64.iter().enumerate() {
+        if abs_tick & (1 << bit) != 0 {
+            sqrt_price = mul_div_floor(sqrt_price, factor, Q64)?;
+        }
+    }
+
+    if tick_index < 0 {
+        mul_div_floor(Q64, Q64, sqrt_price)
+    } else {
+        Some(sqrt_price)
+    }
+}
+
+/// Token A between two sqrt prices: L * (1 / lower - 1 / upper)
+///
+/// Each reciprocal is rounded down, so the result is within one unit.
+pub fn amount_a_delta(liquidity: u128, lower: u128, upper: u128, round_up: bool) -> Option<u128> {
+    let scaled = U256::mul_u128(liquidity, Q64);
+    let (at_lower, remainder) = scaled.div_rem_u128(lower);
+    let (at_upper, _) = scaled.div_rem_u128(upper);
+    let delta = at_lower.checked_sub(at_upper)?.to_u128()?;
+
+    if round_up && remainder != 0 {
+        delta.checked_add(1)
+    } else {
+        Some(delta)
+    }
+}
+
+/// Token B between two sqrt prices: L * (upper - lower)
+pub fn amount_b_delta(liquidity: u128, lower: u128, upper: u128, round_up: bool) -> Option<u128> {
+    let diff = upper.checked_sub(lower)?;
+    if round_up {
+        mul_div_ceil(liquidity, diff, Q64)
+    } else {
+        mul_div_floor(liquidity, diff, Q64)
+    }
+}
+
+/// Sqrt price after adding `amount` of token A (price moves down), rounded up
+pub fn next_sqrt_price_from_a_input(sqrt_price: u128, liquidity: u128, amount: u128) -> Option<u128> {
+    // L / (L / sqrt_price + amount)
+    let scaled = U256::mul_u128(liquidity, Q64);
+    let denominator = scaled
+        .div_rem_u128(sqrt_price)
+        .0
+        .checked_add(U256::from_u128(amount))?;
+    let (quotient, remainder) = scaled.div_rem(denominator);
+    let quotient = quotient.to_u128()?;
+
+    if remainder == U256::from_u128(0) {
+        Some(quotient)
+    } else {
+        quotient.checked_add(1)
+    }
+}
+
+/// Sqrt price after adding `amount` of token B (price moves up), rounded down
+pub fn next_sqrt_price_from_b_input(sqrt_price: u128, liquidity: u128, amount: u128) -> Option<u128> {
+    sqrt_price.checked_add(mul_div_floor(amount, Q64, liquidity)?)
+}
+
+/// Q64.64 price of one whole token_a in whole token_b, rounded down
+///
+/// Returns None if the price does not fit in Q64.64.
+pub fn sqrt_price_to_price_x64(sqrt_price: u128, decimals_a: u8, decimals_b: u8) -> Option<u128> {
+    // sqrt_price^2 is Q128.128; dividing by 2^64 leaves Q64.64
+    let squared = U256::mul_u128(sqrt_price, sqrt_price);
+
+    let (numerator, denominator) = if decimals_a >= decimals_b {
+        let scale = 10u64.checked_pow((decimals_a - decimals_b) as u32)?;
+        (squared.checked_mul_u64(scale)?, Q64)
+    } else {
+        let scale = 10u128.checked_pow((decimals_b - decimals_a) as u32)?;
+        (squared, Q64.checked_mul(scale)?)
+    };
+
+    numerator.div_rem_u128(denominator).0.to_u128()
+}
+
+/// Token amounts backing `liquidity` at the current price (virtual reserves)
+///
+/// amount_a = L / sqrt(P), amount_b = L * sqrt(P), rounded down and
+/// saturating at u128::MAX.
+pub fn virtual_reserves(liquidity: u128, sqrt_price: u128) -> (u128, u128) {
+    let amount_a = mul_div_floor(liquidity, Q64, sqrt_price).unwrap_or(u128::MAX);
+    let amount_b = mul_div_floor(liquidity, sqrt_price, Q64).unwrap_or(u128::MAX);
+    (amount_a, amount_b)
+}
+
+/// Depth at the current tick: virtual reserves of the in-range liquidity,
+/// capped by what the vaults actually hold
+pub fn active_reserves(liquidity: u128, sqrt_price: u128, vault_a: u64, vault_b: u64) -> (u64, u64) {
+    let (amount_a, amount_b) = virtual_reserves(liquidity, sqrt_price);
+    (
+        amount_a.min(vault_a as u128) as u64,
+        amount_b.min(vault_b as u128) as u64,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::x64_to_f64;
+    use num_bigint::BigUint;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    /// Arbitrary-precision reference for sqrt_price_to_price_x64
+    fn reference_price_x64(sqrt_price: u128, decimals_a: u8, decimals_b: u8) -> Option<u128> {
+        let ten = BigUint::from(10u8);
+        let numerator = BigUint::from(sqrt_price).pow(2) * ten.pow(decimals_a as u32);
+        let denominator = (BigUint::from(1u8) << 64) * ten.pow(decimals_b as u32);
+        u128::try_from(numerator / denominator).ok()
+    }
+
+    fn reference_virtual_reserves(liquidity: u128, sqrt_price: u128) -> (u128, u128) {
+        let l = BigUint::from(liquidity);
+        let q64 = BigUint::from(1u8) << 64;
+        let a = &l * &q64 / BigUint::from(sqrt_price);
+        let b = &l * BigUint::from(sqrt_price) / &q64;
+        (
+            u128::try_from(a).unwrap_or(u128::MAX),
+            u128::try_from(b).unwrap_or(u128::MAX),
+        )
+    }
+
+    fn random_sqrt_price(rng: &mut StdRng) -> u128 {
+        rng.gen_range(MIN_SQRT_PRICE_X64..=MAX_SQRT_PRICE_X64) >> rng.gen_range(0..64)
+    }
+
+    #[test]
+    fn test_price_matches_reference() {
+        let mut rng = StdRng::seed_from_u64(44);
+
+        for _ in 0..10_000 {
+            let sqrt_price = random_sqrt_price(&mut rng).max(MIN_SQRT_PRICE_X64);
+            let decimals_a = rng.gen_range(0..=12);
+            let decimals_b = rng.gen_range(0..=12);
+
+            assert_eq!(
+                sqrt_price_to_price_x64(sqrt_price, decimals_a, decimals_b),
+                reference_price_x64(sqrt_price, decimals_a, decimals_b),
+                "sqrt_price={} decimals=({}, {})",
+                sqrt_price,
+                decimals_a,
+                decimals_b
+            );
+        }
+    }
+
+    #[test]
+    fn test_virtual_reserves_match_reference() {
+        let mut rng = StdRng::seed_from_u64(45);
+
+        for _ in 0..10_000 {
+            let sqrt_price = random_sqrt_price(&mut rng).max(MIN_SQRT_PRICE_X64);
+            let liquidity: u128 = rng.gen::<u128>() >> rng.gen_range(0..128);
+
+            assert_eq!(
+                virtual_reserves(liquidity, sqrt_price),
+                reference_virtual_reserves(liquidity, sqrt_price)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sol_usdc_price_with_decimals() {
+        // sqrt(0.15) in Q64.64: 0.15 raw = 150 USDC (6 decimals) per SOL (9 decimals)
+        let price = sqrt_price_to_price_x64(7_144_393_258_922_745_604, 9, 6).unwrap();
+        assert!((x64_to_f64(price) - 150.0).abs() < 1e-9);
+
+        // Same pool quoted the other way round
+        let inverse = sqrt_price_to_price_x64(7_144_393_258_922_745_604, 6, 9).unwrap();
+        assert!((x64_to_f64(inverse) - 0.00015).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_price_bounds() {
+        // ~2^-64 raw price is the smallest Q64.64 step
+        assert_eq!(sqrt_price_to_price_x64(MIN_SQRT_PRICE_X64, 0, 0), Some(1));
+        // ~2^64 raw price still fits, scaling it up by 10^6 does not
+        assert!(sqrt_price_to_price_x64(MAX_SQRT_PRICE_X64, 0, 0).is_some());
+        assert!(sqrt_price_to_price_x64(MAX_SQRT_PRICE_X64, 6, 0).is_none());
+    }
+
+    #[test]
+    fn test_tick_sqrt_price_matches_reference() {
+        assert_eq!(tick_index_to_sqrt_price(0), Some(Q64));
+        assert!(tick_index_to_sqrt_price(MAX_TICK_INDEX + 1).is_none());
+
+        // Bounds line up with the program's sqrt price limits
+        let max = tick_index_to_sqrt_price(MAX_TICK_INDEX).unwrap();
+        let min = tick_index_to_sqrt_price(MIN_TICK_INDEX).unwrap();
+        assert!(max.abs_diff(MAX_SQRT_PRICE_X64) < 1 << 40);
+        assert!(min.abs_diff(MIN_SQRT_PRICE_X64) <= 1);
+
+        let mut rng = StdRng::seed_from_u64(46);
+        for _ in 0..10_000 {
+            let tick = rng.gen_range(MIN_TICK_INDEX..=MAX_TICK_INDEX);
+            let actual = x64_to_f64(tick_index_to_sqrt_price(tick).unwrap());
+            let expected = 1.0001f64.powf(tick as f64 / 2.0);
+            assert!(
+                ((actual - expected) / expected).abs() < 1e-9,
+                "tick={} actual={} expected={}",
+                tick,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_swap_step_round_trips() {
+        let mut rng = StdRng::seed_from_u64(47);
+
+        for _ in 0..10_000 {
+            let sqrt_price = rng.gen_range(Q64 / 1_000..Q64 * 1_000);
+            let liquidity = rng.gen_range(1_000_000u128..1u128 << 80);
+            let amount = rng.gen_range(1u128..1u128 << 64);
+
+            // Selling A moves the price down and uses up at most `amount` of A
+            let lower = next_sqrt_price_from_a_input(sqrt_price, liquidity, amount).unwrap();
+            assert!(lower <= sqrt_price);
+            let used_a = amount_a_delta(liquidity, lower, sqrt_price, false).unwrap();
+            assert!(used_a <= amount + 1, "used {} of {}", used_a, amount);
+
+            // Selling B moves the price up and uses up at most `amount` of B
+            let upper = next_sqrt_price_from_b_input(sqrt_price, liquidity, amount).unwrap();
+            assert!(upper >= sqrt_price);
+            let used_b = amount_b_delta(liquidity, sqrt_price, upper, false).unwrap();
+            assert!(used_b <= amount);
+        }
+    }
+
+    #[test]
+    fn test_active_reserves_capped_by_vaults() {
+        // L = 1e12 at price 1.0: 1e12 of each token in range
+        assert_eq!(active_reserves(1_000_000_000_000, Q64, u64::MAX, 5), (1_000_000_000_000, 5));
+    }
+}
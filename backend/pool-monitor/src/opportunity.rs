@@ -1,117 +1,237 @@
 /*!
  * Arbitrage opportunity detection
+ *
+ * Two-pool routes are found by comparing pools of the same SOL pair, so the
+ * borrowed SOL is sold on one pool and bought back on the other. Longer
+ * routes (triangular and up to MAX_HOPS swaps) are found by searching the
+ * token graph for profitable cycles from SOL through the updated pool.
+ *
+ * When every pool of a route has a swap model, the trade is sized by
+ * simulating the route (fees and price impact included). Otherwise a flat
+ * 1 SOL trade at the mid price is assumed.
+ *
+ * Prices, models and the token graph live in sharded maps, with pools
+ * indexed by their normalized token pair, so an update only touches the
+ * pools it can trade against and concurrent monitors rarely contend on the
+ * same lock.
+ *
+ * When approved strategies are loaded, opportunities are annotated with the
+ * strategies that can execute them and dropped if there are none.
+ *
+ * Detected opportunities are sent to the publisher over a bounded channel.
  */
 
 use crate::config::Config;
-use crate::types::{ArbitrageOpportunity, DexType, PoolPrice};
+use crate::graph::{Cycle, TokenGraph, SOL_MINT};
+use crate::quote::{optimal_trade_size, Leg, PoolModel, TradeSize};
+use crate::strategies::StrategyBook;
+use crate::types::{ArbitrageOpportunity, DexType, Hop, PoolPrice};
 use chrono::Utc;
-use std::collections::HashMap;
+use dashmap::DashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tracing::{debug, info};
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 pub struct OpportunityDetector {
     config: Arc<Config>,
-    pool_prices: Arc<RwLock<HashMap<String, PoolPrice>>>,
-    opportunities: Arc<RwLock<Vec<ArbitrageOpportunity>>>,
+    pool_prices: DashMap<String, PoolPrice>,
+    /// Pool keys per normalized (mint_a, mint_b) pair
+    pair_index: DashMap<(String, String), HashSet<String>>,
+    pool_models: DashMap<String, PoolModel>,
+    graph: TokenGraph,
+    strategies: Option<Arc<StrategyBook>>,
+    sender: mpsc::Sender<ArbitrageOpportunity>,
+}
+
+fn pool_key(dex: &DexType, pool_address: &str) -> String {
+    format!("{}:{}", dex, pool_address)
+}
+
+/// Token pair in a fixed order, so (A, B) and (B, A) pools share an entry
+fn pair_key(price: &PoolPrice) -> (String, String) {
+    if price.token_a <= price.token_b {
+        (price.token_a.clone(), price.token_b.clone())
+    } else {
+        (price.token_b.clone(), price.token_a.clone())
+    }
 }
 
 impl OpportunityDetector {
-    pub fn new(config: Arc<Config>) -> Self {
+    pub fn new(config: Arc<Config>, sender: mpsc::Sender<ArbitrageOpportunity>) -> Self {
         Self {
             config,
-            pool_prices: Arc::new(RwLock::new(HashMap::new())),
-            opportunities: Arc::new(RwLock::new(Vec::new())),
+            pool_prices: DashMap::new(),
+            pair_index: DashMap::new(),
+            pool_models: DashMap::new(),
+            graph: TokenGraph::new(),
+            strategies: None,
+            sender,
         }
     }
 
+    /// Only forward opportunities that an approved strategy accepts
+    pub fn with_strategies(mut self, strategies: Arc<StrategyBook>) -> Self {
+        self.strategies = Some(strategies);
+        self
+    }
+
+    /// Update pool price along with a swap model for trade sizing
+    pub async fn update_pool_model(&self, price: PoolPrice, model: PoolModel) {
+        self.pool_models
+            .insert(pool_key(&price.dex, &price.pool_address), model);
+
+        self.update_pool_price(price).await;
+    }
+
     /// Update pool price and detect opportunities
     pub async fn update_pool_price(&self, price: PoolPrice) {
-        let key = format!("{}:{}", price.dex, price.pool_address);
+        let key = pool_key(&price.dex, &price.pool_address);
 
-        // Update price in cache
-        {
-            let mut prices = self.pool_prices.write().await;
-            prices.insert(key.clone(), price.clone());
+        // Update price in cache and index the pool under its pair
+        if self.pool_prices.insert(key.clone(), price.clone()).is_none() {
+            self.pair_index
+                .entry(pair_key(&price))
+                .or_default()
+                .insert(key.clone());
         }
 
+        // Reprice the pool's graph edges (mid price only when the fee is unknown)
+        let fee_rate = self
+            .pool_models
+            .get(&key)
+            .map_or(0.0, |model| model.fee_rate());
+        self.graph.upsert_pool(&key, &price, fee_rate);
+
         // Detect opportunities with this new price
-        self.detect_opportunities(&price).await;
+        let mut opportunities = self.detect_opportunities(&key, &price);
+
+        // Keep the routes an approved strategy can execute
+        if let Some(strategies) = &self.strategies {
+            let detected = opportunities.len();
+            opportunities = opportunities
+                .into_iter()
+                .filter_map(|opportunity| strategies.annotate(opportunity))
+                .collect();
+            if opportunities.len() < detected {
+                debug!(
+                    "Dropped {} opportunities no approved strategy accepts",
+                    detected - opportunities.len()
+                );
+            }
+        }
+
+        // Forward to the publisher; waits while the channel is full
+        for opportunity in opportunities {
+            if self.sender.send(opportunity).await.is_err() {
+                warn!("Opportunity publisher stopped, dropping opportunity");
+                break;
+            }
+        }
     }
 
     /// Detect arbitrage opportunities for a given pool price
-    async fn detect_opportunities(&self, new_price: &PoolPrice) {
-        let prices = self.pool_prices.read().await;
-
-        // Find all pools with the same token pair
-        let matching_pools: Vec<&PoolPrice> = prices
-            .values()
-            .filter(|p| {
-                (p.token_a == new_price.token_a && p.token_b == new_price.token_b)
-                    || (p.token_a == new_price.token_b && p.token_b == new_price.token_a)
-            })
-            .filter(|p| {
-                // Don't compare pool with itself
-                !(p.dex.to_string() == new_price.dex.to_string()
-                    && p.pool_address == new_price.pool_address)
-            })
+    fn detect_opportunities(&self, key: &str, new_price: &PoolPrice) -> Vec<ArbitrageOpportunity> {
+        let mut opportunities = Vec::new();
+
+        // Find all other pools with the same token pair
+        let matching_keys: Vec<String> = self
+            .pair_index
+            .get(&pair_key(new_price))
+            .map(|keys| keys.iter().filter(|other| *other != key).cloned().collect())
+            .unwrap_or_default();
+        let matching_pools: Vec<PoolPrice> = matching_keys
+            .iter()
+            .filter_map(|other| self.pool_prices.get(other).map(|price| price.clone()))
             .collect();
 
-        // Compare prices and find opportunities
-        for other_price in matching_pools {
-            // Handle inverted pairs
-            let (buy_price, sell_price) = if new_price.token_a == other_price.token_a {
-                (new_price.price, other_price.price)
+        // Flash loans are SOL only, so two-pool routes must start and end at SOL
+        let sol_price = |price: &PoolPrice| {
+            if price.token_a == SOL_MINT {
+                Some((price.token_b.clone(), price.price))
+            } else if price.token_b == SOL_MINT {
+                Some((price.token_a.clone(), 1.0 / price.price))
             } else {
-                (new_price.price, 1.0 / other_price.price)
-            };
+                None
+            }
+        };
 
-            // Calculate profit in basis points
-            let profit_bps = if buy_price < sell_price {
-                ArbitrageOpportunity::calculate_profit_bps(buy_price, sell_price)
-            } else {
-                ArbitrageOpportunity::calculate_profit_bps(sell_price, buy_price)
-            };
-
-            // Check if profit meets minimum threshold
-            if profit_bps >= self.config.min_profit_bps {
-                let (buy_dex, buy_pool, sell_dex, sell_pool) = if buy_price < sell_price {
-                    (
-                        new_price.dex.clone(),
-                        new_price.pool_address.clone(),
-                        other_price.dex.clone(),
-                        other_price.pool_address.clone(),
-                    )
+        // Compare prices and find opportunities
+        if let Some((token, new_sol_price)) = sol_price(new_price) {
+            for other_price in &matching_pools {
+                // Price of 1 SOL in `token` on each pool (handles inverted pairs)
+                let Some((_, other_sol_price)) = sol_price(other_price) else {
+                    continue;
+                };
+
+                // Sell SOL where it is dear, buy it back where it is cheap
+                let (buy, sell, buy_price, sell_price) = if new_sol_price > other_sol_price {
+                    (new_price, other_price, other_sol_price, new_sol_price)
                 } else {
-                    (
-                        other_price.dex.clone(),
-                        other_price.pool_address.clone(),
-                        new_price.dex.clone(),
-                        new_price.pool_address.clone(),
-                    )
+                    (other_price, new_price, new_sol_price, other_sol_price)
                 };
 
-                // Calculate expected profit
-                // Assume 1 SOL (1_000_000_000 lamports) trade amount for simplicity
-                let trade_amount_lamports = 1_000_000_000u64;
-                let profit_ratio = (profit_bps as f64) / 10000.0;
-                let expected_profit = (trade_amount_lamports as f64 * profit_ratio) as u64;
+                // Calculate profit in basis points
+                let profit_bps = ArbitrageOpportunity::calculate_profit_bps(buy_price, sell_price);
+
+                // Check if profit meets minimum threshold
+                if profit_bps < self.config.min_profit_bps {
+                    continue;
+                }
+
+                // Borrow SOL, buy `token` on `buy`, sell it back for SOL on `sell`
+                let hops = vec![
+                    Hop {
+                        dex: buy.dex.clone(),
+                        pool: buy.pool_address.clone(),
+                        token_in: SOL_MINT.to_string(),
+                        token_out: token.clone(),
+                    },
+                    Hop {
+                        dex: sell.dex.clone(),
+                        pool: sell.pool_address.clone(),
+                        token_in: token.clone(),
+                        token_out: SOL_MINT.to_string(),
+                    },
+                ];
+
+                let (expected_profit, trade_amount) =
+                    match self.size_route(&hops) {
+                        Some(Some(trade)) => (trade.profit, trade.amount_in),
+                        Some(None) => {
+                            debug!(
+                                "No profitable size for {} → {} after fees and price impact",
+                                buy.pool_address, sell.pool_address
+                            );
+                            continue;
+                        }
+                        None => {
+                            // No swap models: assume 1 SOL (1_000_000_000 lamports) at mid price
+                            let trade_amount_lamports = 1_000_000_000u64;
+                            let profit_ratio = (profit_bps as f64) / 10000.0;
+                            let expected_profit =
+                                (trade_amount_lamports as f64 * profit_ratio) as u64;
+                            (expected_profit, trade_amount_lamports)
+                        }
+                    };
 
                 let opportunity = ArbitrageOpportunity {
                     id: Uuid::new_v4().to_string(),
-                    buy_dex,
-                    buy_pool,
-                    sell_dex,
-                    sell_pool,
-                    token_a: new_price.token_a.clone(),
-                    token_b: new_price.token_b.clone(),
-                    buy_price: buy_price.min(sell_price),
-                    sell_price: buy_price.max(sell_price),
+                    buy_dex: buy.dex.clone(),
+                    buy_pool: buy.pool_address.clone(),
+                    sell_dex: sell.dex.clone(),
+                    sell_pool: sell.pool_address.clone(),
+                    token_a: SOL_MINT.to_string(),
+                    token_b: token.clone(),
+                    buy_price,
+                    sell_price,
                     profit_bps,
                     expected_profit,
-                    suggested_amount: trade_amount_lamports,
+                    suggested_amount: trade_amount,
                     timestamp: Utc::now().timestamp(),
+                    hops,
+                    strategies: Vec::new(),
                 };
 
                 info!(
@@ -122,33 +242,343 @@ impl OpportunityDetector {
                     opportunity.expected_profit
                 );
 
-                // Store opportunity
-                let mut opportunities = self.opportunities.write().await;
-                opportunities.push(opportunity.clone());
+                opportunities.push(opportunity);
+            }
+        }
 
-                // Clean up old opportunities (older than TTL)
-                let ttl_threshold = Utc::now().timestamp() - self.config.opportunity_ttl_seconds as i64;
-                opportunities.retain(|opp| opp.timestamp > ttl_threshold);
+        if self.config.max_hops >= 3 {
+            let cycles = self
+                .graph
+                .profitable_cycles(SOL_MINT, key, 3, self.config.max_hops);
+            for cycle in cycles {
+                if let Some(opportunity) = self.cycle_opportunity(&cycle) {
+                    opportunities.push(opportunity);
+                }
             }
         }
+
+        opportunities
+    }
+
+    /// Opportunity for a profitable SOL cycle of three or more swaps
+    fn cycle_opportunity(&self, cycle: &Cycle) -> Option<ArbitrageOpportunity> {
+        let rate = cycle.rate();
+        let profit_bps = ((rate - 1.0) * 10000.0).min(u16::MAX as f64) as u16;
+        if profit_bps < self.config.min_profit_bps {
+            return None;
+        }
+
+        let hops = cycle
+            .hops
+            .iter()
+            .map(|hop| {
+                let price = self.pool_prices.get(&hop.pool)?;
+                Some(Hop {
+                    dex: price.dex.clone(),
+                    pool: price.pool_address.clone(),
+                    token_in: hop.token_in.clone(),
+                    token_out: hop.token_out.clone(),
+                })
+            })
+            .collect::<Option<Vec<Hop>>>()?;
+
+        let (expected_profit, trade_amount) = match self.size_route(&hops) {
+            Some(Some(trade)) => (trade.profit, trade.amount_in),
+            Some(None) => {
+                debug!("No profitable size for {}-hop cycle after fees and price impact", hops.len());
+                return None;
+            }
+            None => {
+                // No swap models: assume 1 SOL (1_000_000_000 lamports) at mid price
+                let trade_amount_lamports = 1_000_000_000u64;
+                let expected_profit = (trade_amount_lamports as f64 * (rate - 1.0)) as u64;
+                (expected_profit, trade_amount_lamports)
+            }
+        };
+
+        let first = hops.first()?;
+        let last = hops.last()?;
+        let opportunity = ArbitrageOpportunity {
+            id: Uuid::new_v4().to_string(),
+            buy_dex: first.dex.clone(),
+            buy_pool: first.pool.clone(),
+            sell_dex: last.dex.clone(),
+            sell_pool: last.pool.clone(),
+            token_a: SOL_MINT.to_string(),
+            token_b: first.token_out.clone(),
+            // SOL in vs SOL out per unit, after fees
+            buy_price: 1.0,
+            sell_price: rate,
+            profit_bps,
+            expected_profit,
+            suggested_amount: trade_amount,
+            timestamp: Utc::now().timestamp(),
+            hops,
+            strategies: Vec::new(),
+        };
+
+        info!(
+            "🔺 {}-hop cycle detected: {} (profit: {} bps, expected: {} lamports)",
+            opportunity.hops.len(),
+            opportunity.route_key(),
+            opportunity.profit_bps,
+            opportunity.expected_profit
+        );
+
+        Some(opportunity)
+    }
+
+    /// Simulate borrowing the first hop's input and swapping through every hop
+    ///
+    /// Returns None when any pool has no swap model, Some(None) when no
+    /// trade size is profitable net of swap and flash loan fees.
+    fn size_route(&self, hops: &[Hop]) -> Option<Option<TradeSize>> {
+        // Copy the models out so no map shard stays locked while simulating
+        let models = hops
+            .iter()
+            .map(|hop| {
+                let key = pool_key(&hop.dex, &hop.pool);
+                let a_to_b = self.pool_prices.get(&key)?.token_a == hop.token_in;
+                Some((self.pool_models.get(&key)?.clone(), a_to_b))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let legs: Vec<Leg> = models
+            .iter()
+            .map(|(pool, a_to_b)| Leg {
+                pool,
+                a_to_b: *a_to_b,
+            })
+            .collect();
+
+        Some(optimal_trade_size(
+            &legs,
+            self.config.flash_loan_fee_bps,
+            self.config.max_trade_amount,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quote::constant_product::ConstantProductModel;
+    use crate::strategies::{ApprovedStrategy, StrategyPair};
+
+    fn test_config() -> Arc<Config> {
+        Arc::new(Config::for_tests())
+    }
+
+    fn pool_price(dex: DexType, pool: &str, price: f64) -> PoolPrice {
+        PoolPrice {
+            dex,
+            pool_address: pool.to_string(),
+            token_a: SOL_MINT.to_string(),
+            token_b: "USDC".to_string(),
+            price,
+            liquidity_a: 0,
+            liquidity_b: 0,
+            timestamp: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_opportunity_is_sent_to_publisher() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let detector = OpportunityDetector::new(test_config(), sender);
+
+        detector.update_pool_price(pool_price(DexType::Raydium, "ray", 100.0)).await;
+        detector.update_pool_price(pool_price(DexType::Orca, "orca", 101.0)).await;
+
+        let opportunity = receiver.try_recv().expect("opportunity should be queued");
+        assert_eq!(opportunity.sell_pool, "ray");
+        assert!(opportunity.profit_bps >= 50);
+
+        // Borrow SOL, sell it for USDC on Orca, buy it back on Raydium
+        assert_eq!(opportunity.hops.len(), 2);
+        assert_eq!((opportunity.hops[0].pool.as_str(), opportunity.hops[0].token_in.as_str()), ("orca", SOL_MINT));
+        assert_eq!((opportunity.hops[1].pool.as_str(), opportunity.hops[1].token_in.as_str()), ("ray", "USDC"));
+        assert_eq!(opportunity.hops[1].token_out, SOL_MINT);
+        assert_eq!(opportunity.suggested_amount, 1_000_000_000);
+    }
+
+    fn pair_price(dex: DexType, pool: &str, token_a: &str, token_b: &str, price: f64) -> PoolPrice {
+        PoolPrice {
+            token_a: token_a.to_string(),
+            token_b: token_b.to_string(),
+            ..pool_price(dex, pool, price)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pair_index_matches_inverted_pools_only() {
+        let (sender, mut receiver) = mpsc::channel(4);
+        let detector = OpportunityDetector::new(test_config(), sender);
+
+        detector.update_pool_price(pool_price(DexType::Raydium, "ray", 100.0)).await;
+        detector.update_pool_price(pair_price(DexType::Orca, "other", "JUP", "USDC", 101.0)).await;
+        assert!(receiver.try_recv().is_err());
+
+        // USDC/SOL at 1/101 is SOL/USDC at 101: USDC is cheap on the new pool
+        detector.update_pool_price(pair_price(DexType::Orca, "inverted", "USDC", SOL_MINT, 1.0 / 101.0)).await;
+        let opportunity = receiver.try_recv().unwrap();
+        assert_eq!(opportunity.buy_pool, "inverted");
+        assert_eq!(opportunity.sell_pool, "ray");
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_pairs_without_sol_are_not_traded_pairwise() {
+        let (sender, mut receiver) = mpsc::channel(4);
+        let detector = OpportunityDetector::new(test_config(), sender);
+
+        // 5% gap, but the flash loan cannot borrow either token
+        detector.update_pool_price(pair_price(DexType::Raydium, "jup-ray", "JUP", "USDC", 1.00)).await;
+        detector.update_pool_price(pair_price(DexType::Orca, "jup-orca", "JUP", "USDC", 1.05)).await;
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_triangular_cycle_is_detected() {
+        let (sender, mut receiver) = mpsc::channel(4);
+        let detector = OpportunityDetector::new(test_config(), sender);
+
+        // SOL -> USDC -> BONK -> SOL returns 100 / 0.009 * 0.0001 = 1.111 SOL
+        detector.update_pool_price(pair_price(DexType::Raydium, "sol-usdc", SOL_MINT, "USDC", 100.0)).await;
+        detector.update_pool_price(pair_price(DexType::Orca, "bonk-sol", "BONK", SOL_MINT, 0.0001)).await;
+        assert!(receiver.try_recv().is_err());
+        detector.update_pool_price(pair_price(DexType::Meteora, "bonk-usdc", "BONK", "USDC", 0.009)).await;
+
+        let opportunity = receiver.try_recv().expect("cycle should be queued");
+        assert!(receiver.try_recv().is_err());
+        assert_eq!(opportunity.route_key(), "raydium:sol-usdc->meteora:bonk-usdc->orca:bonk-sol");
+        assert_eq!(opportunity.hops[0].token_in, SOL_MINT);
+        assert_eq!(opportunity.hops[2].token_out, SOL_MINT);
+        assert_eq!(opportunity.profit_bps, 1111);
+        assert_eq!(opportunity.suggested_amount, 1_000_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_cycle_is_sized_when_all_pools_are_modelled() {
+        let (sender, mut receiver) = mpsc::channel(4);
+        let detector = OpportunityDetector::new(test_config(), sender);
+
+        // Same triangle with 1,000 SOL of depth on every pool and 0.25% fees
+        let pools = [
+            (pair_price(DexType::Raydium, "sol-usdc", SOL_MINT, "USDC", 100.0), constant_product(1_000_000_000_000, 100_000_000_000_000)),
+            (pair_price(DexType::Orca, "bonk-sol", "BONK", SOL_MINT, 0.0001), constant_product(10_000_000_000_000_000, 1_000_000_000_000)),
+            (pair_price(DexType::Meteora, "bonk-usdc", "BONK", "USDC", 0.009), constant_product(10_000_000_000_000_000, 90_000_000_000_000)),
+        ];
+        for (price, model) in pools {
+            detector.update_pool_model(price, model).await;
+        }
+
+        let opportunity = receiver.try_recv().unwrap();
+        assert_eq!(opportunity.hops.len(), 3);
+        assert!(opportunity.suggested_amount > 1_000_000_000 && opportunity.suggested_amount < 1_000_000_000_000);
+        assert!(opportunity.expected_profit > 0);
     }
 
-    /// Get all current opportunities
-    pub async fn get_opportunities(&self) -> Vec<ArbitrageOpportunity> {
-        let opportunities = self.opportunities.read().await;
-        opportunities.clone()
+    fn constant_product(reserve_a: u64, reserve_b: u64) -> PoolModel {
+        PoolModel::ConstantProduct(ConstantProductModel {
+            reserve_a,
+            reserve_b,
+            fee_numerator: 25,
+            fee_denominator: 10_000,
+        })
     }
 
-    /// Get opportunity by ID
-    pub async fn get_opportunity(&self, id: &str) -> Option<ArbitrageOpportunity> {
-        let opportunities = self.opportunities.read().await;
-        opportunities.iter().find(|opp| opp.id == id).cloned()
+    #[tokio::test]
+    async fn test_modelled_pools_are_sized_by_simulation() {
+        let (sender, mut receiver) = mpsc::channel(4);
+        let detector = OpportunityDetector::new(test_config(), sender);
+
+        // 1 A : 100 B vs 1 A : 110 B
+        detector
+            .update_pool_model(pool_price(DexType::Raydium, "ray", 100.0), constant_product(1_000_000_000, 100_000_000_000))
+            .await;
+        detector
+            .update_pool_model(pool_price(DexType::Orca, "orca", 110.0), constant_product(1_000_000_000, 110_000_000_000))
+            .await;
+
+        let opportunity = receiver.try_recv().unwrap();
+        assert_eq!(opportunity.buy_pool, "orca");
+        assert_eq!(opportunity.sell_pool, "ray");
+        // Optimum borrow is ~2.2e7 lamports of SOL for ~1.0e6 profit
+        assert!(opportunity.suggested_amount > 20_000_000 && opportunity.suggested_amount < 24_000_000);
+        assert!(opportunity.expected_profit > 950_000 && opportunity.expected_profit < 1_050_000);
     }
 
-    /// Clear all opportunities
-    pub async fn clear_opportunities(&self) {
-        let mut opportunities = self.opportunities.write().await;
-        opportunities.clear();
-        debug!("Cleared all opportunities");
+    #[tokio::test]
+    async fn test_unprofitable_after_fees_is_dropped() {
+        let (sender, mut receiver) = mpsc::channel(4);
+        let detector = OpportunityDetector::new(test_config(), sender);
+
+        // 0.55% gap clears MIN_PROFIT_BPS but not two 0.25% swap fees plus the flash loan fee
+        detector
+            .update_pool_model(pool_price(DexType::Raydium, "ray", 100.0), constant_product(1_000_000_000, 100_000_000_000))
+            .await;
+        detector
+            .update_pool_model(pool_price(DexType::Orca, "orca", 100.55), constant_product(1_000_000_000, 100_550_000_000))
+            .await;
+
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_only_routes_of_approved_strategies_are_sent() {
+        let strategy = |pubkey: &str, dexs: Vec<DexType>| ApprovedStrategy {
+            pubkey: pubkey.to_string(),
+            dexs,
+            token_pairs: vec![StrategyPair {
+                token_a: "USDC".to_string(),
+                token_b: SOL_MINT.to_string(),
+            }],
+            profit_threshold: 50,
+            max_borrow: 100_000_000_000,
+        };
+        let book = Arc::new(StrategyBook::new(vec![
+            strategy("ray-orca", vec![DexType::Raydium, DexType::Orca]),
+            strategy("ray-met", vec![DexType::Raydium, DexType::Meteora]),
+        ]));
+
+        let (sender, mut receiver) = mpsc::channel(4);
+        let detector = OpportunityDetector::new(test_config(), sender).with_strategies(book.clone());
+
+        detector.update_pool_price(pool_price(DexType::Raydium, "ray", 100.0)).await;
+        detector.update_pool_price(pool_price(DexType::Orca, "orca", 101.0)).await;
+
+        let opportunity = receiver.try_recv().expect("ray-orca accepts the route");
+        assert_eq!(opportunity.strategies, vec!["ray-orca".to_string()]);
+
+        // No strategy trades on Lifinity
+        book.replace(vec![strategy("ray-orca", vec![DexType::Raydium, DexType::Orca])]);
+        detector.update_pool_price(pool_price(DexType::Lifinity, "lif", 102.0)).await;
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_detector_waits_when_channel_is_full() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let detector = Arc::new(OpportunityDetector::new(test_config(), sender));
+
+        detector.update_pool_price(pool_price(DexType::Raydium, "ray", 100.0)).await;
+        detector.update_pool_price(pool_price(DexType::Orca, "orca", 101.0)).await;
+
+        // Channel is full, so the next opportunity blocks until it is drained
+        let pending = tokio::spawn({
+            let detector = detector.clone();
+            async move {
+                detector.update_pool_price(pool_price(DexType::Meteora, "met", 102.0)).await;
+            }
+        });
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+        assert!(!pending.is_finished());
+
+        receiver.recv().await.unwrap();
+        receiver.recv().await.unwrap();
+        receiver.recv().await.unwrap();
+        pending.await.unwrap();
     }
 }
@@ -1,84 +1,306 @@
 /*!
  * Redis publisher for broadcasting opportunities
+ *
+ * The detector sends opportunities over a bounded channel (it waits when the
+ * publisher falls behind). The publisher drains the channel in batches, drops
+ * duplicate routes and hands each batch to an `OpportunitySink`.
  */
 
 use anyhow::Result;
-use redis::{AsyncCommands, Client};
+use async_trait::async_trait;
+use redis::{aio::ConnectionManager, AsyncCommands, Client};
 use serde_json;
+use std::collections::HashMap;
 use std::time::Duration;
-use tokio::time;
-use tracing::{error, info};
+use tokio::sync::mpsc;
+use tokio::time::{self, Instant};
+use tracing::{debug, error, info};
 
+use crate::config::Config;
 use crate::types::ArbitrageOpportunity;
 
-pub struct OpportunityPublisher {
-    redis_client: Client,
+/// Redis pub/sub channel opportunities are published to
+pub const OPPORTUNITIES_CHANNEL: &str = "opportunities";
+
+/// Destination for published opportunities
+#[async_trait]
+pub trait OpportunitySink: Send + Sync {
+    async fn publish_batch(&self, batch: &[ArbitrageOpportunity]) -> Result<()>;
+}
+
+/// Publishes to the "opportunities" channel and caches under `opportunity:{id}`
+pub struct RedisSink {
+    conn: ConnectionManager,
+    ttl_seconds: u64,
 }
 
-impl OpportunityPublisher {
-    pub async fn new(redis_url: &str) -> Result<Self> {
+impl RedisSink {
+    pub async fn new(redis_url: &str, ttl_seconds: u64) -> Result<Self> {
         let redis_client = Client::open(redis_url)?;
+        let mut conn = ConnectionManager::new(redis_client).await?;
 
         // Test connection
-        let mut conn = redis_client.get_multiplexed_async_connection().await?;
-        let _: () = conn.ping().await?;
+        let _: String = redis::cmd("PING").query_async(&mut conn).await?;
 
         info!("Redis publisher connected");
 
-        Ok(Self { redis_client })
+        Ok(Self { conn, ttl_seconds })
     }
 
-    /// Publish an opportunity to Redis pub/sub
-    pub async fn publish(&self, opportunity: &ArbitrageOpportunity) -> Result<()> {
-        let mut conn = self.redis_client.get_multiplexed_async_connection().await?;
+    /// Get recent opportunities from Redis cache
+    pub async fn get_recent_opportunities(&self) -> Result<Vec<ArbitrageOpportunity>> {
+        let mut conn = self.conn.clone();
 
-        let payload = serde_json::to_string(opportunity)?;
+        // Get all opportunity keys
+        let keys: Vec<String> = conn.keys("opportunity:*").await?;
 
-        // Publish to "opportunities" channel
-        let _: () = conn.publish("opportunities", &payload).await?;
+        let mut opportunities = Vec::new();
 
-        // Also store in Redis with TTL for caching
-        let key = format!("opportunity:{}", opportunity.id);
-        let _: () = conn
-            .set_ex(&key, &payload, 10) // 10 second TTL
-            .await?;
+        for key in keys {
+            if let Ok(payload) = conn.get::<_, String>(&key).await {
+                if let Ok(opportunity) = serde_json::from_str::<ArbitrageOpportunity>(&payload) {
+                    opportunities.push(opportunity);
+                }
+            }
+        }
+
+        Ok(opportunities)
+    }
+}
 
-        info!(
-            "Published opportunity {} to Redis ({} bps profit)",
-            opportunity.id, opportunity.profit_bps
-        );
+#[async_trait]
+impl OpportunitySink for RedisSink {
+    async fn publish_batch(&self, batch: &[ArbitrageOpportunity]) -> Result<()> {
+        let mut pipe = redis::pipe();
+
+        for opportunity in batch {
+            let payload = serde_json::to_string(opportunity)?;
+            pipe.publish(OPPORTUNITIES_CHANNEL, &payload)
+                .ignore()
+                .set_ex(format!("opportunity:{}", opportunity.id), &payload, self.ttl_seconds)
+                .ignore();
+        }
+
+        let mut conn = self.conn.clone();
+        let _: () = pipe.query_async(&mut conn).await?;
+
+        info!("Published {} opportunities to Redis", batch.len());
 
         Ok(())
     }
+}
 
-    /// Start publishing loop (placeholder for now)
-    pub async fn start_publishing(&self) {
-        info!("Opportunity publisher started");
+pub struct OpportunityPublisher<S: OpportunitySink> {
+    sink: S,
+    batch_size: usize,
+    batch_window: Duration,
+    dedup_window_seconds: i64,
+    /// Last published (profit_bps, timestamp) per route
+    recent_routes: HashMap<String, (u16, i64)>,
+}
 
-        loop {
-            time::sleep(Duration::from_secs(1)).await;
-            // Opportunities are published immediately when detected
-            // This loop is for future background tasks
+impl<S: OpportunitySink> OpportunityPublisher<S> {
+    pub fn new(sink: S, config: &Config) -> Self {
+        Self {
+            sink,
+            batch_size: config.publish_batch_size.max(1),
+            batch_window: Duration::from_millis(config.publish_batch_window_ms),
+            dedup_window_seconds: config.opportunity_ttl_seconds as i64,
+            recent_routes: HashMap::new(),
         }
     }
 
-    /// Get recent opportunities from Redis cache
-    pub async fn get_recent_opportunities(&self) -> Result<Vec<ArbitrageOpportunity>> {
-        let mut conn = self.redis_client.get_multiplexed_async_connection().await?;
+    /// Publish opportunities from the detector until the channel closes
+    pub async fn start_publishing(mut self, mut receiver: mpsc::Receiver<ArbitrageOpportunity>) {
+        info!("Opportunity publisher started");
 
-        // Get all opportunity keys
-        let keys: Vec<String> = conn.keys("opportunity:*").await?;
+        while let Some(first) = receiver.recv().await {
+            let mut batch = vec![first];
 
-        let mut opportunities = Vec::new();
+            // Fill the batch until it is full or the window elapses
+            let deadline = Instant::now() + self.batch_window;
+            while batch.len() < self.batch_size {
+                match time::timeout_at(deadline, receiver.recv()).await {
+                    Ok(Some(opportunity)) => batch.push(opportunity),
+                    Ok(None) | Err(_) => break,
+                }
+            }
 
-        for key in keys {
-            if let Ok(payload) = conn.get::<_, String>(&key).await {
-                if let Ok(opportunity) = serde_json::from_str::<ArbitrageOpportunity>(&payload) {
-                    opportunities.push(opportunity);
+            let batch = self.dedup(batch);
+            if batch.is_empty() {
+                continue;
+            }
+
+            if let Err(e) = self.sink.publish_batch(&batch).await {
+                error!("Error publishing {} opportunities: {}", batch.len(), e);
+            }
+        }
+
+        info!("Opportunity channel closed, publisher stopped");
+    }
+
+    /// Keep the most profitable opportunity per route, skipping routes already
+    /// published within the dedup window unless their profit improved
+    fn dedup(&mut self, batch: Vec<ArbitrageOpportunity>) -> Vec<ArbitrageOpportunity> {
+        let mut best: HashMap<String, ArbitrageOpportunity> = HashMap::new();
+        for opportunity in batch {
+            let route = opportunity.route_key();
+            match best.get(&route) {
+                Some(current) if current.profit_bps >= opportunity.profit_bps => {}
+                _ => {
+                    best.insert(route, opportunity);
                 }
             }
         }
 
-        Ok(opportunities)
+        let mut unique: Vec<ArbitrageOpportunity> = best
+            .into_iter()
+            .filter_map(|(route, opportunity)| {
+                if let Some(&(profit_bps, timestamp)) = self.recent_routes.get(&route) {
+                    let fresh = opportunity.timestamp - timestamp < self.dedup_window_seconds;
+                    if fresh && opportunity.profit_bps <= profit_bps {
+                        debug!("Skipping duplicate route {}", route);
+                        return None;
+                    }
+                }
+                self.recent_routes
+                    .insert(route, (opportunity.profit_bps, opportunity.timestamp));
+                Some(opportunity)
+            })
+            .collect();
+
+        // Forget routes whose dedup window has passed
+        if let Some(latest) = unique.iter().map(|opp| opp.timestamp).max() {
+            let window = self.dedup_window_seconds;
+            self.recent_routes
+                .retain(|_, (_, timestamp)| latest - *timestamp < window);
+        }
+
+        unique.sort_by(|a, b| b.profit_bps.cmp(&a.profit_bps));
+        unique
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::DexType;
+    use std::sync::{Arc, Mutex};
+
+    /// Records batches instead of talking to Redis
+    #[derive(Clone, Default)]
+    struct MockSink {
+        batches: Arc<Mutex<Vec<Vec<ArbitrageOpportunity>>>>,
+    }
+
+    #[async_trait]
+    impl OpportunitySink for MockSink {
+        async fn publish_batch(&self, batch: &[ArbitrageOpportunity]) -> Result<()> {
+            self.batches.lock().unwrap().push(batch.to_vec());
+            Ok(())
+        }
+    }
+
+    fn test_config(batch_size: usize) -> Config {
+        Config {
+            publish_batch_size: batch_size,
+            publish_batch_window_ms: 20,
+            ..Config::for_tests()
+        }
+    }
+
+    fn opportunity(id: &str, sell_pool: &str, profit_bps: u16, timestamp: i64) -> ArbitrageOpportunity {
+        ArbitrageOpportunity {
+            id: id.to_string(),
+            buy_dex: DexType::Raydium,
+            buy_pool: "buy".to_string(),
+            sell_dex: DexType::Orca,
+            sell_pool: sell_pool.to_string(),
+            token_a: "SOL".to_string(),
+            token_b: "USDC".to_string(),
+            buy_price: 100.0,
+            sell_price: 101.0,
+            profit_bps,
+            expected_profit: 0,
+            suggested_amount: 0,
+            timestamp,
+            hops: Vec::new(),
+            strategies: Vec::new(),
+        }
+    }
+
+    async fn publish_all(batch_size: usize, opportunities: Vec<ArbitrageOpportunity>) -> Vec<Vec<ArbitrageOpportunity>> {
+        let sink = MockSink::default();
+        let (sender, receiver) = mpsc::channel(16);
+        let publisher = OpportunityPublisher::new(sink.clone(), &test_config(batch_size));
+        let handle = tokio::spawn(publisher.start_publishing(receiver));
+
+        for opportunity in opportunities {
+            sender.send(opportunity).await.unwrap();
+        }
+        drop(sender);
+        handle.await.unwrap();
+
+        let batches = sink.batches.lock().unwrap().clone();
+        batches
+    }
+
+    #[tokio::test]
+    async fn test_every_opportunity_reaches_sink() {
+        let batches = publish_all(
+            32,
+            vec![
+                opportunity("a", "pool-1", 60, 0),
+                opportunity("b", "pool-2", 70, 0),
+                opportunity("c", "pool-3", 80, 0),
+            ],
+        )
+        .await;
+
+        let mut ids: Vec<String> = batches.concat().into_iter().map(|opp| opp.id).collect();
+        ids.sort();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn test_batches_are_capped() {
+        let opportunities = (0..5)
+            .map(|i| opportunity(&i.to_string(), &format!("pool-{}", i), 60, 0))
+            .collect();
+
+        let batches = publish_all(2, opportunities).await;
+
+        assert!(batches.iter().all(|batch| batch.len() <= 2));
+        assert_eq!(batches.concat().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_routes_keep_best_profit() {
+        let batches = publish_all(
+            32,
+            vec![
+                opportunity("low", "pool-1", 60, 0),
+                opportunity("high", "pool-1", 90, 0),
+                opportunity("mid", "pool-1", 75, 0),
+            ],
+        )
+        .await;
+
+        let published = batches.concat();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].id, "high");
+    }
+
+    #[test]
+    fn test_route_is_republished_after_window_or_on_better_profit() {
+        let mut publisher = OpportunityPublisher::new(MockSink::default(), &test_config(32));
+
+        assert_eq!(publisher.dedup(vec![opportunity("a", "pool-1", 60, 0)]).len(), 1);
+        // Same route, same profit, inside the window
+        assert!(publisher.dedup(vec![opportunity("b", "pool-1", 60, 5)]).is_empty());
+        // Profit improved
+        assert_eq!(publisher.dedup(vec![opportunity("c", "pool-1", 65, 6)]).len(), 1);
+        // Window passed
+        assert_eq!(publisher.dedup(vec![opportunity("d", "pool-1", 65, 20)]).len(), 1);
     }
 }
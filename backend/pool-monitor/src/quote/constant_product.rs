@@ -0,0 +1,91 @@
+/*!
+ * Constant product (x * y = k) quoting for Raydium AMM v4
+ */
+
+use crate::decoders::raydium::AmmInfo;
+
+/// Raydium AMM v4 default swap fee (0.25%)
+pub const RAYDIUM_FEE_NUMERATOR: u64 = 25;
+pub const RAYDIUM_FEE_DENOMINATOR: u64 = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstantProductModel {
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+    pub fee_numerator: u64,
+    pub fee_denominator: u64,
+}
+
+impl ConstantProductModel {
+    /// Model from the AMM account and its vault balances
+    pub fn from_amm(amm: &AmmInfo, coin_vault_amount: u64, pc_vault_amount: u64) -> Self {
+        let (reserve_a, reserve_b) = amm.reserves(coin_vault_amount, pc_vault_amount);
+        Self {
+            reserve_a,
+            reserve_b,
+            fee_numerator: amm.fees.swap_fee_numerator,
+            fee_denominator: amm.fees.swap_fee_denominator,
+        }
+    }
+
+    /// Output for `amount_in`; the fee is taken from the input, rounded up
+    pub fn quote(&self, amount_in: u64, a_to_b: bool) -> Option<u64> {
+        let (reserve_in, reserve_out) = if a_to_b {
+            (self.reserve_a, self.reserve_b)
+        } else {
+            (self.reserve_b, self.reserve_a)
+        };
+        if reserve_in == 0 || reserve_out == 0 || self.fee_denominator == 0 {
+            return None;
+        }
+
+        let fee = (amount_in as u128 * self.fee_numerator as u128)
+            .div_ceil(self.fee_denominator as u128);
+        let amount_in = (amount_in as u128).checked_sub(fee)?;
+
+        let amount_out = amount_in * reserve_out as u128 / (reserve_in as u128 + amount_in);
+        u64::try_from(amount_out).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(reserve_a: u64, reserve_b: u64) -> ConstantProductModel {
+        ConstantProductModel {
+            reserve_a,
+            reserve_b,
+            fee_numerator: RAYDIUM_FEE_NUMERATOR,
+            fee_denominator: RAYDIUM_FEE_DENOMINATOR,
+        }
+    }
+
+    #[test]
+    fn test_quote_applies_fee_and_price_impact() {
+        let pool = pool(1_000_000, 2_000_000);
+
+        // 1000 in, 3 fee: 997 * 2_000_000 / 1_000_997
+        assert_eq!(pool.quote(1_000, true), Some(1_992));
+        // 997 * 1_000_000 / 2_000_997
+        assert_eq!(pool.quote(1_000, false), Some(498));
+    }
+
+    #[test]
+    fn test_output_never_reaches_reserve() {
+        let pool = pool(1_000, 1_000);
+        assert_eq!(pool.quote(u64::MAX, true), Some(999));
+        assert_eq!(pool.quote(0, true), Some(0));
+    }
+
+    #[test]
+    fn test_from_amm_fixture_excludes_pnl() {
+        let amm = AmmInfo::decode(include_bytes!("../../fixtures/accounts/raydium_amm_v4.bin"))
+            .unwrap();
+        let model = ConstantProductModel::from_amm(&amm, 10_001_500_000, 1_500_250_000);
+
+        assert_eq!(model.reserve_a, 10_000_000_000);
+        assert_eq!(model.reserve_b, 1_500_000_000);
+        assert_eq!((model.fee_numerator, model.fee_denominator), (25, 10_000));
+    }
+}
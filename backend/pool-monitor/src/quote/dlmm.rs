@@ -0,0 +1,190 @@
+/*!
+ * Bin liquidity quoting for Meteora DLMM
+ *
+ * Each bin trades at a fixed price. A swap drains the active bin and moves
+ * to the next one (down for X -> Y, up for Y -> X) until the input is spent.
+ */
+
+use crate::decoders::meteora::{BinArray, LbPair};
+use crate::math::{mul_div_ceil, mul_div_floor, Q64};
+use std::collections::BTreeMap;
+
+/// DLMM fee precision (1e9 = 100%)
+pub const FEE_PRECISION: u128 = 1_000_000_000;
+/// Total fee cap enforced by the program (10%)
+pub const MAX_FEE_RATE: u128 = 100_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinLiquidity {
+    pub amount_x: u64,
+    pub amount_y: u64,
+    /// Q64.64 price of X in Y (raw units)
+    pub price: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DlmmModel {
+    pub active_id: i32,
+    pub bin_step: u16,
+    /// Base plus variable fee, in FEE_PRECISION units
+    pub fee_rate: u128,
+    /// Bins from the loaded bin arrays; the swap cannot leave them
+    pub bins: BTreeMap<i32, BinLiquidity>,
+}
+
+impl DlmmModel {
+    pub fn from_accounts(pair: &LbPair, bin_arrays: &[&BinArray]) -> Self {
+        let mut bins = BTreeMap::new();
+        for array in bin_arrays {
+            let first_id = array.index * array.bins.len() as i64;
+            for (offset, bin) in array.bins.iter().enumerate() {
+                let id = (first_id + offset as i64) as i32;
+                let price = if bin.price != 0 {
+                    bin.price
+                } else {
+                    bin_price_x64(id, pair.bin_step)
+                };
+                bins.insert(
+                    id,
+                    BinLiquidity {
+                        amount_x: bin.amount_x,
+                        amount_y: bin.amount_y,
+                        price,
+                    },
+                );
+            }
+        }
+
+        Self {
+            active_id: pair.active_id,
+            bin_step: pair.bin_step,
+            fee_rate: total_fee_rate(pair),
+            bins,
+        }
+    }
+
+    /// Output for `amount_in`, or None if the loaded bins cannot fill it
+    pub fn quote(&self, amount_in: u64, x_to_y: bool) -> Option<u64> {
+        let fee = (amount_in as u128 * self.fee_rate).div_ceil(FEE_PRECISION);
+        let mut remaining = amount_in as u128 - fee;
+        let mut amount_out = 0u128;
+        let mut bin_id = self.active_id;
+
+        while remaining > 0 {
+            let bin = self.bins.get(&bin_id)?;
+
+            if x_to_y {
+                // Input X needed to take all of the bin's Y
+                let drain_in = mul_div_ceil(bin.amount_y as u128, Q64, bin.price)?;
+                if remaining < drain_in {
+                    amount_out += mul_div_floor(remaining, bin.price, Q64)?;
+                    break;
+                }
+                amount_out += bin.amount_y as u128;
+                remaining -= drain_in;
+                bin_id -= 1;
+            } else {
+                // Input Y needed to take all of the bin's X
+                let drain_in = mul_div_ceil(bin.amount_x as u128, bin.price, Q64)?;
+                if remaining < drain_in {
+                    amount_out += mul_div_floor(remaining, Q64, bin.price)?;
+                    break;
+                }
+                amount_out += bin.amount_x as u128;
+                remaining -= drain_in;
+                bin_id += 1;
+            }
+        }
+
+        u64::try_from(amount_out).ok()
+    }
+}
+
+/// Base fee plus the volatility-driven variable fee, capped at MAX_FEE_RATE
+pub fn total_fee_rate(pair: &LbPair) -> u128 {
+    let parameters = &pair.parameters;
+    let base_fee = parameters.base_factor as u128
+        * pair.bin_step as u128
+        * 10
+        * 10u128.pow(parameters.base_fee_power_factor as u32);
+
+    let volatility = pair.v_parameters.volatility_accumulator as u128 * pair.bin_step as u128;
+    let variable_fee =
+        (parameters.variable_fee_control as u128 * volatility * volatility).div_ceil(100_000_000_000);
+
+    (base_fee + variable_fee).min(MAX_FEE_RATE)
+}
+
+/// Q64.64 price of bin `id`: (1 + bin_step / 10_000)^id
+pub fn bin_price_x64(id: i32, bin_step: u16) -> u128 {
+    let price = (1.0 + bin_step as f64 / 10_000.0).powi(id);
+    (price * Q64 as f64) as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> DlmmModel {
+        // Price 2.0 in bin 0, 1.0 in bin -1, 4.0 in bin 1
+        let bins = BTreeMap::from([
+            (-1, BinLiquidity { amount_x: 0, amount_y: 1_000, price: Q64 }),
+            (0, BinLiquidity { amount_x: 500, amount_y: 1_000, price: 2 * Q64 }),
+            (1, BinLiquidity { amount_x: 300, amount_y: 0, price: 4 * Q64 }),
+        ]);
+        DlmmModel {
+            active_id: 0,
+            bin_step: 10_000,
+            fee_rate: 0,
+            bins,
+        }
+    }
+
+    #[test]
+    fn test_swap_within_active_bin() {
+        let model = model();
+        assert_eq!(model.quote(100, true), Some(200));
+        assert_eq!(model.quote(200, false), Some(100));
+    }
+
+    #[test]
+    fn test_swap_traverses_bins() {
+        let model = model();
+
+        // 500 X drains bin 0's 1000 Y, then 100 X buys 100 Y at price 1.0
+        assert_eq!(model.quote(600, true), Some(1_100));
+        // 1000 Y drains bin 0's 500 X, then 400 Y buys 100 X at price 4.0
+        assert_eq!(model.quote(1_400, false), Some(600));
+        // Past the loaded bins
+        assert!(model.quote(2_000, true).is_none());
+    }
+
+    #[test]
+    fn test_fee_is_taken_from_input() {
+        let mut model = model();
+        model.fee_rate = FEE_PRECISION / 10;
+        assert_eq!(model.quote(100, true), Some(180));
+    }
+
+    #[test]
+    fn test_model_from_fixtures() {
+        let pair = LbPair::decode(include_bytes!("../../fixtures/accounts/meteora_lb_pair.bin"))
+            .unwrap();
+        let array =
+            BinArray::decode(include_bytes!("../../fixtures/accounts/meteora_bin_array.bin"))
+                .unwrap();
+
+        let model = DlmmModel::from_accounts(&pair, &[&array]);
+
+        // base 10000 * 25 * 10 = 0.25%; variable (1234 * 25)^2 * 40000 / 1e11 rounded up
+        assert_eq!(model.fee_rate, 2_500_000 + 381);
+        assert_eq!(model.bins.len(), 70);
+        assert_eq!(model.bins[&-1800].amount_x, 2_000_000_000);
+        // Empty bins fall back to the bin-id price
+        assert_eq!(model.bins[&-1820].price, bin_price_x64(-1820, 25));
+
+        // Y -> X: active bin holds 2 SOL at ~0.6326 raw
+        let out = model.quote(100_000_000, false).unwrap();
+        assert!(out > 150_000_000 && out < 160_000_000, "out={}", out);
+    }
+}
@@ -0,0 +1,194 @@
+/*!
+ * Swap quoting and trade sizing
+ *
+ * Each pool is modelled from its decoded on-chain state so a route can be
+ * simulated exactly, including fees and price impact. The trade size is
+ * then chosen to maximise profit net of the flash loan fee.
+ */
+
+pub mod constant_product;
+pub mod dlmm;
+pub mod whirlpool;
+
+use constant_product::ConstantProductModel;
+use dlmm::DlmmModel;
+use whirlpool::WhirlpoolModel;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PoolModel {
+    ConstantProduct(ConstantProductModel),
+    Whirlpool(WhirlpoolModel),
+    Dlmm(DlmmModel),
+}
+
+impl PoolModel {
+    /// Output of swapping `amount_in` of token A (a_to_b) or token B
+    pub fn quote(&self, amount_in: u64, a_to_b: bool) -> Option<u64> {
+        match self {
+            PoolModel::ConstantProduct(model) => model.quote(amount_in, a_to_b),
+            PoolModel::Whirlpool(model) => model.quote(amount_in, a_to_b),
+            PoolModel::Dlmm(model) => model.quote(amount_in, a_to_b),
+        }
+    }
+
+    /// Swap fee as a fraction of the input
+    pub fn fee_rate(&self) -> f64 {
+        match self {
+            PoolModel::ConstantProduct(model) => {
+                model.fee_numerator as f64 / model.fee_denominator as f64
+            }
+            PoolModel::Whirlpool(model) => {
+                model.fee_rate as f64 / whirlpool::FEE_RATE_DENOMINATOR as f64
+            }
+            PoolModel::Dlmm(model) => model.fee_rate as f64 / dlmm::FEE_PRECISION as f64,
+        }
+    }
+}
+
+/// One swap of a route
+#[derive(Debug, Clone, Copy)]
+pub struct Leg<'a> {
+    pub pool: &'a PoolModel,
+    pub a_to_b: bool,
+}
+
+/// Output of running `amount_in` through every leg in order
+pub fn quote_route(legs: &[Leg], amount_in: u64) -> Option<u64> {
+    legs.iter()
+        .try_fold(amount_in, |amount, leg| leg.pool.quote(amount, leg.a_to_b))
+}
+
+/// Flash loan fee, rounded down like the flash-loan program
+pub fn flash_loan_fee(amount: u64, fee_bps: u16) -> u64 {
+    (amount as u128 * fee_bps as u128 / 10_000) as u64
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradeSize {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub flash_loan_fee: u64,
+    /// amount_out - amount_in - flash_loan_fee
+    pub profit: u64,
+}
+
+/// Net profit of borrowing `amount_in` and running the route
+fn net_profit(legs: &[Leg], amount_in: u64, fee_bps: u16) -> Option<i128> {
+    let amount_out = quote_route(legs, amount_in)?;
+    Some(amount_out as i128 - amount_in as i128 - flash_loan_fee(amount_in, fee_bps) as i128)
+}
+
+/// Most profitable borrow amount in [1, max_amount], or None if nothing is profitable
+///
+/// Profit is concave in the input (each unit buys at a worse price than the
+/// last), so a ternary search finds the peak; amounts the pools cannot fill
+/// count as unprofitable.
+pub fn optimal_trade_size(legs: &[Leg], fee_bps: u16, max_amount: u64) -> Option<TradeSize> {
+    let profit = |amount: u64| net_profit(legs, amount, fee_bps).unwrap_or(i128::MIN);
+
+    let mut low = 1u64;
+    let mut high = max_amount;
+    while high - low > 2 {
+        let third = (high - low) / 3;
+        let m1 = low + third;
+        let m2 = high - third;
+        if profit(m1) < profit(m2) {
+            low = m1 + 1;
+        } else {
+            high = m2;
+        }
+    }
+
+    let amount_in = (low..=high).max_by_key(|&amount| profit(amount))?;
+    let amount_out = quote_route(legs, amount_in)?;
+    let fee = flash_loan_fee(amount_in, fee_bps);
+    let profit = amount_out.checked_sub(amount_in)?.checked_sub(fee)?;
+
+    (profit > 0).then_some(TradeSize {
+        amount_in,
+        amount_out,
+        flash_loan_fee: fee,
+        profit,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use constant_product::{RAYDIUM_FEE_DENOMINATOR, RAYDIUM_FEE_NUMERATOR};
+
+    fn pool(reserve_a: u64, reserve_b: u64) -> PoolModel {
+        PoolModel::ConstantProduct(ConstantProductModel {
+            reserve_a,
+            reserve_b,
+            fee_numerator: RAYDIUM_FEE_NUMERATOR,
+            fee_denominator: RAYDIUM_FEE_DENOMINATOR,
+        })
+    }
+
+    #[test]
+    fn test_route_chains_legs() {
+        let cheap = pool(1_000_000_000, 100_000_000_000);
+        let rich = pool(1_000_000_000, 110_000_000_000);
+        let legs = [
+            Leg { pool: &cheap, a_to_b: false },
+            Leg { pool: &rich, a_to_b: true },
+        ];
+
+        let first = cheap.quote(1_000_000, false).unwrap();
+        let expected = rich.quote(first, true).unwrap();
+        assert_eq!(quote_route(&legs, 1_000_000), Some(expected));
+    }
+
+    #[test]
+    fn test_optimal_size_beats_neighbours() {
+        // 10% price gap between two constant product pools
+        let cheap = pool(1_000_000_000, 100_000_000_000);
+        let rich = pool(1_000_000_000, 110_000_000_000);
+        let legs = [
+            Leg { pool: &cheap, a_to_b: false },
+            Leg { pool: &rich, a_to_b: true },
+        ];
+
+        let trade = optimal_trade_size(&legs, 9, 100_000_000_000).unwrap();
+
+        assert_eq!(
+            trade.profit,
+            trade.amount_out - trade.amount_in - trade.flash_loan_fee
+        );
+        for amount in [trade.amount_in / 2, trade.amount_in * 2, trade.amount_in - 1_000, trade.amount_in + 1_000] {
+            let profit = net_profit(&legs, amount, 9).unwrap();
+            assert!(profit <= trade.profit as i128, "amount {} beats optimum", amount);
+        }
+
+        // Continuous optimum for this pair is ~2.3% of the B reserve
+        assert!(trade.amount_in > 1_500_000_000 && trade.amount_in < 3_500_000_000);
+    }
+
+    #[test]
+    fn test_fees_can_erase_small_gaps() {
+        // 0.4% gap is less than two 0.25% swap fees
+        let cheap = pool(1_000_000_000, 100_000_000_000);
+        let rich = pool(1_000_000_000, 100_400_000_000);
+        let legs = [
+            Leg { pool: &cheap, a_to_b: false },
+            Leg { pool: &rich, a_to_b: true },
+        ];
+
+        assert!(optimal_trade_size(&legs, 9, 100_000_000_000).is_none());
+    }
+
+    #[test]
+    fn test_max_amount_caps_trade() {
+        let cheap = pool(1_000_000_000, 100_000_000_000);
+        let rich = pool(1_000_000_000, 110_000_000_000);
+        let legs = [
+            Leg { pool: &cheap, a_to_b: false },
+            Leg { pool: &rich, a_to_b: true },
+        ];
+
+        let trade = optimal_trade_size(&legs, 9, 1_000_000).unwrap();
+        assert!(trade.amount_in <= 1_000_000);
+        assert!(trade.amount_in > 990_000);
+    }
+}
@@ -0,0 +1,222 @@
+/*!
+ * Concentrated liquidity quoting for Orca Whirlpools
+ *
+ * Walks the swap one tick range at a time, crossing initialized ticks from
+ * the loaded tick arrays and updating the active liquidity as it goes.
+ */
+
+use crate::decoders::orca::{TickArray, Whirlpool, TICK_ARRAY_SIZE};
+use crate::math::whirlpool::{
+    amount_a_delta, amount_b_delta, next_sqrt_price_from_a_input, next_sqrt_price_from_b_input,
+    tick_index_to_sqrt_price, MAX_TICK_INDEX, MIN_TICK_INDEX,
+};
+
+/// Whirlpool fee_rate denominator (hundredths of a basis point)
+pub const FEE_RATE_DENOMINATOR: u128 = 1_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhirlpoolModel {
+    pub sqrt_price: u128,
+    pub liquidity: u128,
+    pub tick_current_index: i32,
+    pub fee_rate: u16,
+    /// Initialized ticks as (tick_index, liquidity_net), ascending
+    pub ticks: Vec<(i32, i128)>,
+    /// Tick range covered by the loaded tick arrays; the swap cannot leave it
+    pub min_tick: i32,
+    pub max_tick: i32,
+}
+
+impl WhirlpoolModel {
+    pub fn from_accounts(whirlpool: &Whirlpool, tick_arrays: &[&TickArray]) -> Self {
+        let spacing = whirlpool.tick_spacing as i32;
+        let array_width = spacing * TICK_ARRAY_SIZE as i32;
+
+        let mut ticks: Vec<(i32, i128)> = tick_arrays
+            .iter()
+            .flat_map(|array| array.initialized_ticks(whirlpool.tick_spacing))
+            .map(|(index, tick)| (index, tick.liquidity_net))
+            .collect();
+        ticks.sort_unstable_by_key(|(index, _)| *index);
+        ticks.dedup_by_key(|(index, _)| *index);
+
+        // Without tick arrays only the current tick range is known
+        let current_start = whirlpool.tick_current_index.div_euclid(spacing) * spacing;
+        let min_tick = tick_arrays
+            .iter()
+            .map(|array| array.start_tick_index)
+            .min()
+            .unwrap_or(current_start)
+            .max(MIN_TICK_INDEX);
+        let max_tick = tick_arrays
+            .iter()
+            .map(|array| array.start_tick_index + array_width)
+            .max()
+            .unwrap_or(current_start + spacing)
+            .min(MAX_TICK_INDEX);
+
+        Self {
+            sqrt_price: whirlpool.sqrt_price,
+            liquidity: whirlpool.liquidity,
+            tick_current_index: whirlpool.tick_current_index,
+            fee_rate: whirlpool.fee_rate,
+            ticks,
+            min_tick,
+            max_tick,
+        }
+    }
+
+    /// Output for `amount_in`, or None if the loaded ticks cannot fill it
+    pub fn quote(&self, amount_in: u64, a_to_b: bool) -> Option<u64> {
+        let fee = (amount_in as u128 * self.fee_rate as u128).div_ceil(FEE_RATE_DENOMINATOR);
+        let mut remaining = amount_in as u128 - fee;
+        let mut amount_out = 0u128;
+
+        let mut sqrt_price = self.sqrt_price;
+        let mut liquidity = self.liquidity;
+        let mut tick_current = self.tick_current_index;
+
+        while remaining > 0 {
+            // Next initialized tick in the swap direction, or the edge of the loaded range
+            let next_tick = if a_to_b {
+                self.ticks
+                    .iter()
+                    .rev()
+                    .find(|(index, _)| *index <= tick_current && *index >= self.min_tick)
+                    .copied()
+            } else {
+                self.ticks
+                    .iter()
+                    .find(|(index, _)| *index > tick_current && *index <= self.max_tick)
+                    .copied()
+            };
+            let target_tick = match next_tick {
+                Some((index, _)) => index,
+                None if a_to_b => self.min_tick,
+                None => self.max_tick,
+            };
+            let target_sqrt_price = tick_index_to_sqrt_price(target_tick)?;
+
+            if a_to_b {
+                let max_in = amount_a_delta(liquidity, target_sqrt_price, sqrt_price, true)?;
+                if remaining < max_in {
+                    let next = next_sqrt_price_from_a_input(sqrt_price, liquidity, remaining)?;
+                    amount_out += amount_b_delta(liquidity, next, sqrt_price, false)?;
+                    remaining = 0;
+                    break;
+                }
+                amount_out += amount_b_delta(liquidity, target_sqrt_price, sqrt_price, false)?;
+                remaining -= max_in;
+            } else {
+                let max_in = amount_b_delta(liquidity, sqrt_price, target_sqrt_price, true)?;
+                if remaining < max_in {
+                    let next = next_sqrt_price_from_b_input(sqrt_price, liquidity, remaining)?;
+                    amount_out += amount_a_delta(liquidity, sqrt_price, next, false)?;
+                    remaining = 0;
+                    break;
+                }
+                amount_out += amount_a_delta(liquidity, sqrt_price, target_sqrt_price, false)?;
+                remaining -= max_in;
+            }
+
+            // Cross the tick
+            sqrt_price = target_sqrt_price;
+            match next_tick {
+                Some((index, liquidity_net)) if a_to_b => {
+                    liquidity = liquidity.checked_add_signed(-liquidity_net)?;
+                    tick_current = index - 1;
+                }
+                Some((index, liquidity_net)) => {
+                    liquidity = liquidity.checked_add_signed(liquidity_net)?;
+                    tick_current = index;
+                }
+                // Ran off the loaded tick arrays
+                None => return None,
+            }
+        }
+
+        debug_assert_eq!(remaining, 0);
+        u64::try_from(amount_out).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::Q64;
+
+    /// Pool at price 1.0 (tick 0) with L = 1e12 in [-640, 640) and
+    /// L = 2e12 in [-1280, 1280)
+    fn model() -> WhirlpoolModel {
+        WhirlpoolModel {
+            sqrt_price: Q64,
+            liquidity: 3_000_000_000_000,
+            tick_current_index: 0,
+            fee_rate: 3000,
+            ticks: vec![
+                (-1280, 2_000_000_000_000),
+                (-640, 1_000_000_000_000),
+                (640, -1_000_000_000_000),
+                (1280, -2_000_000_000_000),
+            ],
+            min_tick: -5632,
+            max_tick: 5632,
+        }
+    }
+
+    #[test]
+    fn test_small_swap_stays_in_range() {
+        let model = model();
+
+        // 0.3% fee, then roughly 1:1 with negligible impact at L = 3e12
+        let out = model.quote(1_000_000, true).unwrap();
+        assert!(out <= 997_000 && out > 996_000, "out={}", out);
+
+        let out = model.quote(1_000_000, false).unwrap();
+        assert!(out <= 997_000 && out > 996_000, "out={}", out);
+    }
+
+    #[test]
+    fn test_swap_crosses_ticks_and_loses_liquidity() {
+        let model = model();
+
+        // Amount of A that takes the price exactly to tick -640
+        let sqrt_640 = tick_index_to_sqrt_price(-640).unwrap();
+        let to_first_tick = amount_a_delta(3_000_000_000_000, sqrt_640, Q64, true).unwrap() as u64;
+        let out_first = amount_b_delta(3_000_000_000_000, sqrt_640, Q64, false).unwrap() as u64;
+
+        // Swapping 1.5x that crosses -640 and continues with only L = 2e12 at a
+        // lower price, so the extra half buys less than half as much again
+        let gross = (to_first_tick as u128 * 3 / 2 * FEE_RATE_DENOMINATOR)
+            .div_ceil(FEE_RATE_DENOMINATOR - 3000) as u64;
+        let out = model.quote(gross, true).unwrap();
+        assert!(out > out_first);
+        assert!(out - out_first < out_first / 2);
+    }
+
+    #[test]
+    fn test_swap_beyond_loaded_ticks_fails() {
+        let model = model();
+
+        // All in-range liquidity is gone below -1280
+        assert!(model.quote(u64::MAX, true).is_none());
+        assert!(model.quote(u64::MAX, false).is_none());
+    }
+
+    #[test]
+    fn test_model_from_fixtures() {
+        let whirlpool = Whirlpool::decode(include_bytes!("../../fixtures/accounts/orca_whirlpool.bin"))
+            .unwrap();
+        let array =
+            TickArray::decode(include_bytes!("../../fixtures/accounts/orca_tick_array.bin")).unwrap();
+
+        let model = WhirlpoolModel::from_accounts(&whirlpool, &[&array]);
+
+        assert_eq!(model.ticks, vec![(-22528, 5_000_000), (-19712, -2_000_000), (-16960, -3_000_000)]);
+        assert_eq!((model.min_tick, model.max_tick), (-22528, -16896));
+
+        // Selling SOL (A) for USDC (B) at ~0.15 raw, 0.3% fee
+        let out = model.quote(1_000_000, true).unwrap();
+        assert!(out > 148_000 && out < 150_000, "out={}", out);
+    }
+}
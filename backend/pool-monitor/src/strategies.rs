@@ -0,0 +1,481 @@
+/*!
+ * Approved strategy matching
+ *
+ * Opportunities are only worth publishing if an approved strategy can
+ * execute them: the route must borrow and repay WSOL (the only mint the
+ * flash loan lends), every swap of the route must be on one of the strategy's
+ * DEXs and trade one of its token pairs, and the expected profit must clear
+ * the strategy's `profit_threshold` (basis points of the borrowed amount,
+ * checked the same way by the execution engine). The trade is sized down to
+ * the strategy's `max_borrow` first, as the executor bot will do.
+ *
+ * Strategies are loaded from a JSON snapshot or by scanning the
+ * strategy-registry program accounts, and reloaded periodically.
+ */
+
+use crate::config::Config;
+use crate::decoders::anchor_discriminator;
+use crate::decoders::strategy::{StrategyAccount, StrategyStatus};
+use crate::graph::SOL_MINT;
+use crate::types::{ArbitrageOpportunity, DexType};
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::time;
+use tracing::{debug, info, warn};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct StrategyPair {
+    pub token_a: String,
+    pub token_b: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ApprovedStrategy {
+    pub pubkey: String,
+    pub dexs: Vec<DexType>,
+    pub token_pairs: Vec<StrategyPair>,
+    /// Minimum profit in basis points of the borrowed amount
+    pub profit_threshold: u16,
+    /// Largest flash loan a single execution may take (lamports)
+    pub max_borrow: u64,
+}
+
+impl ApprovedStrategy {
+    /// Strategy for a registry account, if it is approved, not paused and may borrow
+    pub fn from_account(pubkey: &str, account: &StrategyAccount) -> Option<Self> {
+        if account.status != StrategyStatus::Approved
+            || account.emergency_paused
+            || account.max_borrow == 0
+        {
+            return None;
+        }
+
+        Some(Self {
+            pubkey: pubkey.to_string(),
+            dexs: account.dexs.clone(),
+            token_pairs: account
+                .token_pairs
+                .iter()
+                .map(|pair| StrategyPair {
+                    token_a: pair.token_a.to_string(),
+                    token_b: pair.token_b.to_string(),
+                })
+                .collect(),
+            profit_threshold: account.profit_threshold,
+            max_borrow: account.max_borrow,
+        })
+    }
+
+    fn has_pair(&self, token_a: &str, token_b: &str) -> bool {
+        self.token_pairs.iter().any(|pair| {
+            (pair.token_a == token_a && pair.token_b == token_b)
+                || (pair.token_a == token_b && pair.token_b == token_a)
+        })
+    }
+
+    /// Check if every swap of the route uses this strategy's DEXs and pairs
+    pub fn covers(&self, opportunity: &ArbitrageOpportunity) -> bool {
+        if opportunity.hops.is_empty() {
+            return self.dexs.contains(&opportunity.buy_dex)
+                && self.dexs.contains(&opportunity.sell_dex)
+                && self.has_pair(&opportunity.token_a, &opportunity.token_b);
+        }
+
+        opportunity
+            .hops
+            .iter()
+            .all(|hop| self.dexs.contains(&hop.dex) && self.has_pair(&hop.token_in, &hop.token_out))
+    }
+
+    /// Minimum profit for a borrowed amount (same rounding as the program)
+    pub fn min_profit_for(&self, amount: u64) -> u64 {
+        (amount as u128 * self.profit_threshold as u128 / 10_000) as u64
+    }
+
+    /// Check if the strategy can execute the opportunity at the size it may borrow
+    ///
+    /// The trade is capped at `max_borrow` and its profit scaled down linearly,
+    /// the same sizing the executor bot applies before submitting.
+    pub fn accepts(&self, opportunity: &ArbitrageOpportunity) -> bool {
+        if !borrows_sol(opportunity) {
+            return false;
+        }
+
+        let borrow_amount = opportunity.suggested_amount.min(self.max_borrow);
+        if borrow_amount == 0 {
+            return false;
+        }
+
+        let expected_profit = (opportunity.expected_profit as u128 * borrow_amount as u128
+            / opportunity.suggested_amount as u128) as u64;
+        self.covers(opportunity) && expected_profit >= self.min_profit_for(borrow_amount)
+    }
+}
+
+/// Flash loans are WSOL only, so the route must start and end at WSOL
+/// (the executor bot refuses anything else)
+fn borrows_sol(opportunity: &ArbitrageOpportunity) -> bool {
+    matches!(
+        (opportunity.hops.first(), opportunity.hops.last()),
+        (Some(first), Some(last)) if first.token_in == SOL_MINT && last.token_out == SOL_MINT
+    )
+}
+
+/// Current set of approved strategies, shared with the detector
+#[derive(Debug, Default)]
+pub struct StrategyBook {
+    strategies: RwLock<Vec<ApprovedStrategy>>,
+}
+
+impl StrategyBook {
+    pub fn new(strategies: Vec<ApprovedStrategy>) -> Self {
+        Self {
+            strategies: RwLock::new(strategies),
+        }
+    }
+
+    pub fn replace(&self, strategies: Vec<ApprovedStrategy>) {
+        *self.strategies.write().unwrap() = strategies;
+    }
+
+    /// Fill in the strategies that accept the opportunity, or drop it if none does
+    pub fn annotate(&self, mut opportunity: ArbitrageOpportunity) -> Option<ArbitrageOpportunity> {
+        opportunity.strategies = self
+            .strategies
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|strategy| strategy.accepts(&opportunity))
+            .map(|strategy| strategy.pubkey.clone())
+            .collect();
+
+        if opportunity.strategies.is_empty() {
+            None
+        } else {
+            Some(opportunity)
+        }
+    }
+}
+
+/// Where approved strategies are loaded from
+#[derive(Debug, Clone)]
+pub enum StrategySource {
+    /// JSON array of `ApprovedStrategy`
+    Snapshot(String),
+    /// `getProgramAccounts` scan of the strategy-registry program
+    Registry { rpc_url: String, program_id: String },
+}
+
+impl StrategySource {
+    /// Source configured in the environment; the snapshot takes precedence
+    pub fn from_config(config: &Config) -> Option<Self> {
+        if let Some(path) = &config.strategy_snapshot_file {
+            return Some(StrategySource::Snapshot(path.clone()));
+        }
+
+        config
+            .strategy_registry_program_id
+            .as_ref()
+            .map(|program_id| StrategySource::Registry {
+                rpc_url: config.helius_rpc.clone(),
+                program_id: program_id.clone(),
+            })
+    }
+
+    pub async fn load(&self, http_client: &Client) -> Result<Vec<ApprovedStrategy>> {
+        match self {
+            StrategySource::Snapshot(path) => {
+                let contents = std::fs::read_to_string(path)?;
+                Ok(serde_json::from_str(&contents)?)
+            }
+            StrategySource::Registry { rpc_url, program_id } => {
+                let request = json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "method": "getProgramAccounts",
+                    "params": [program_id, {
+                        "encoding": "base64",
+                        "commitment": "confirmed",
+                        "filters": [{
+                            "memcmp": {
+                                "offset": 0,
+                                "bytes": BASE64.encode(anchor_discriminator("StrategyAccount")),
+                                "encoding": "base64",
+                            }
+                        }],
+                    }],
+                });
+
+                let response: Value = http_client
+                    .post(rpc_url)
+                    .json(&request)
+                    .send()
+                    .await?
+                    .json()
+                    .await?;
+
+                parse_program_accounts(&response)
+            }
+        }
+    }
+}
+
+/// Approved strategies from a `getProgramAccounts` response
+fn parse_program_accounts(response: &Value) -> Result<Vec<ApprovedStrategy>> {
+    if let Some(error) = response.get("error") {
+        return Err(anyhow!("getProgramAccounts failed: {}", error));
+    }
+
+    let accounts = response["result"]
+        .as_array()
+        .ok_or_else(|| anyhow!("getProgramAccounts returned no result"))?;
+
+    let mut strategies = Vec::new();
+    for account in accounts {
+        let pubkey = account["pubkey"].as_str().unwrap_or_default();
+
+        // Data is encoded as [payload, "base64"]
+        let data = account["account"]["data"][0]
+            .as_str()
+            .and_then(|encoded| BASE64.decode(encoded).ok());
+        let Some(data) = data else {
+            warn!("Skipping strategy {} with unreadable data", pubkey);
+            continue;
+        };
+
+        match StrategyAccount::decode(&data) {
+            Ok(decoded) => strategies.extend(ApprovedStrategy::from_account(pubkey, &decoded)),
+            Err(e) => warn!("Skipping strategy {}: {}", pubkey, e),
+        }
+    }
+
+    Ok(strategies)
+}
+
+/// Reload strategies every `interval`, keeping the current set on failure
+pub async fn refresh_strategies(book: Arc<StrategyBook>, source: StrategySource, interval: Duration) {
+    let http_client = Client::new();
+    let mut ticker = time::interval(interval);
+    ticker.tick().await;
+
+    loop {
+        ticker.tick().await;
+
+        match source.load(&http_client).await {
+            Ok(strategies) => {
+                debug!("Reloaded {} approved strategies", strategies.len());
+                book.replace(strategies);
+            }
+            Err(e) => warn!("Failed to reload strategies, keeping current set: {}", e),
+        }
+    }
+}
+
+/// Initial strategy load; the monitor should not publish before this succeeds
+pub async fn load_strategies(source: &StrategySource) -> Result<Arc<StrategyBook>> {
+    let strategies = source.load(&Client::new()).await?;
+    info!("Loaded {} approved strategies", strategies.len());
+    Ok(Arc::new(StrategyBook::new(strategies)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoders::strategy::tests::encode;
+    use crate::types::Hop;
+    use chrono::Utc;
+    use solana_sdk::pubkey::Pubkey;
+
+    const SOL: &str = SOL_MINT;
+
+    fn strategy(dexs: Vec<DexType>, pairs: &[(&str, &str)], profit_threshold: u16) -> ApprovedStrategy {
+        ApprovedStrategy {
+            pubkey: "strategy".to_string(),
+            dexs,
+            token_pairs: pairs
+                .iter()
+                .map(|(token_a, token_b)| StrategyPair {
+                    token_a: token_a.to_string(),
+                    token_b: token_b.to_string(),
+                })
+                .collect(),
+            profit_threshold,
+            max_borrow: u64::MAX,
+        }
+    }
+
+    fn hop(dex: DexType, token_in: &str, token_out: &str) -> Hop {
+        Hop {
+            dex,
+            pool: format!("{}-{}", token_in, token_out),
+            token_in: token_in.to_string(),
+            token_out: token_out.to_string(),
+        }
+    }
+
+    fn opportunity(hops: Vec<Hop>, suggested_amount: u64, expected_profit: u64) -> ArbitrageOpportunity {
+        ArbitrageOpportunity {
+            id: "id".to_string(),
+            token_a: SOL.to_string(),
+            token_b: "USDC".to_string(),
+            buy_dex: hops[0].dex.clone(),
+            sell_dex: hops[hops.len() - 1].dex.clone(),
+            buy_pool: hops[0].pool.clone(),
+            sell_pool: hops[hops.len() - 1].pool.clone(),
+            buy_price: 1.0,
+            sell_price: 1.01,
+            profit_bps: 100,
+            suggested_amount,
+            expected_profit,
+            hops,
+            strategies: Vec::new(),
+            timestamp: Utc::now().timestamp(),
+        }
+    }
+
+    #[test]
+    fn test_route_must_use_strategy_dexs_and_pairs() {
+        let strategy = strategy(vec![DexType::Raydium, DexType::Orca], &[(SOL, "USDC"), ("BONK", "USDC")], 50);
+
+        let pairwise = opportunity(
+            vec![hop(DexType::Raydium, SOL, "USDC"), hop(DexType::Orca, "USDC", SOL)],
+            1_000,
+            10,
+        );
+        assert!(strategy.covers(&pairwise));
+
+        let other_dex = opportunity(
+            vec![hop(DexType::Raydium, SOL, "USDC"), hop(DexType::Meteora, "USDC", SOL)],
+            1_000,
+            10,
+        );
+        assert!(!strategy.covers(&other_dex));
+
+        // SOL/BONK is not one of the strategy's pairs
+        let triangle = opportunity(
+            vec![
+                hop(DexType::Raydium, SOL, "USDC"),
+                hop(DexType::Orca, "USDC", "BONK"),
+                hop(DexType::Orca, "BONK", SOL),
+            ],
+            1_000,
+            10,
+        );
+        assert!(!strategy.covers(&triangle));
+    }
+
+    #[test]
+    fn test_profit_threshold_is_checked_against_borrow_amount() {
+        let strategy = strategy(vec![DexType::Raydium, DexType::Orca], &[(SOL, "USDC")], 50);
+        let hops = vec![hop(DexType::Raydium, SOL, "USDC"), hop(DexType::Orca, "USDC", SOL)];
+
+        assert_eq!(strategy.min_profit_for(1_000_000), 5_000);
+        assert!(strategy.accepts(&opportunity(hops.clone(), 1_000_000, 5_000)));
+        assert!(!strategy.accepts(&opportunity(hops, 1_000_000, 4_999)));
+    }
+
+    #[test]
+    fn test_route_must_borrow_and_repay_wsol() {
+        let strategy = strategy(vec![DexType::Raydium, DexType::Orca], &[(SOL, "USDC")], 50);
+
+        let from_sol = opportunity(
+            vec![hop(DexType::Raydium, SOL, "USDC"), hop(DexType::Orca, "USDC", SOL)],
+            1_000_000,
+            10_000,
+        );
+        assert!(strategy.accepts(&from_sol));
+
+        // Same pools and pairs, but the flash loan would have to lend USDC
+        let from_usdc = opportunity(
+            vec![hop(DexType::Raydium, "USDC", SOL), hop(DexType::Orca, SOL, "USDC")],
+            1_000_000,
+            10_000,
+        );
+        assert!(strategy.covers(&from_usdc));
+        assert!(!strategy.accepts(&from_usdc));
+
+        // Routes without hops cannot be executed either
+        let mut legacy = from_sol;
+        legacy.hops.clear();
+        assert!(!strategy.accepts(&legacy));
+    }
+
+    #[test]
+    fn test_profit_threshold_is_checked_at_the_capped_borrow() {
+        let mut strategy = strategy(vec![DexType::Raydium, DexType::Orca], &[(SOL, "USDC")], 50);
+        let hops = vec![hop(DexType::Raydium, SOL, "USDC"), hop(DexType::Orca, "USDC", SOL)];
+
+        // 0.5% at the suggested size is 0.5% of whatever the cap allows
+        strategy.max_borrow = 400_000;
+        assert!(strategy.accepts(&opportunity(hops.clone(), 1_000_000, 5_000)));
+
+        // 9_999 clears the threshold at 1_999_999 but scales to 4_999 < 5_000 at the cap
+        let marginal = opportunity(hops.clone(), 1_999_999, 9_999);
+        strategy.max_borrow = u64::MAX;
+        assert!(strategy.accepts(&marginal));
+        strategy.max_borrow = 1_000_000;
+        assert!(!strategy.accepts(&marginal));
+
+        // A strategy that may not borrow can never execute
+        strategy.max_borrow = 0;
+        assert!(!strategy.accepts(&opportunity(hops, 1_000_000, 1_000_000)));
+    }
+
+    #[test]
+    fn test_book_annotates_or_drops() {
+        let mut cheap = strategy(vec![DexType::Raydium, DexType::Orca], &[(SOL, "USDC")], 10);
+        cheap.pubkey = "cheap".to_string();
+        let mut strict = strategy(vec![DexType::Raydium, DexType::Orca], &[(SOL, "USDC")], 500);
+        strict.pubkey = "strict".to_string();
+        let book = StrategyBook::new(vec![cheap, strict]);
+
+        let hops = vec![hop(DexType::Raydium, SOL, "USDC"), hop(DexType::Orca, "USDC", SOL)];
+        let annotated = book.annotate(opportunity(hops.clone(), 1_000_000, 10_000)).unwrap();
+        assert_eq!(annotated.strategies, vec!["cheap".to_string()]);
+
+        assert!(book.annotate(opportunity(hops, 1_000_000, 100)).is_none());
+    }
+
+    #[test]
+    fn test_only_approved_registry_accounts_are_loaded() {
+        let sol = Pubkey::new_unique();
+        let usdc = Pubkey::new_unique();
+        let approved = encode(&[0, 2], &[(sol, usdc)], 50, 1, 5_000_000_000);
+        let pending = encode(&[0], &[(sol, usdc)], 50, 0, 5_000_000_000);
+        let uncapped = encode(&[0], &[(sol, usdc)], 50, 1, 0);
+
+        let response = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": [
+                { "pubkey": "approved", "account": { "data": [BASE64.encode(&approved), "base64"] } },
+                { "pubkey": "pending", "account": { "data": [BASE64.encode(&pending), "base64"] } },
+                { "pubkey": "uncapped", "account": { "data": [BASE64.encode(&uncapped), "base64"] } },
+                { "pubkey": "garbage", "account": { "data": [BASE64.encode([1, 2, 3]), "base64"] } },
+            ],
+        });
+
+        let strategies = parse_program_accounts(&response).unwrap();
+        assert_eq!(
+            strategies,
+            vec![ApprovedStrategy {
+                pubkey: "approved".to_string(),
+                dexs: vec![DexType::Raydium, DexType::Meteora],
+                token_pairs: vec![StrategyPair {
+                    token_a: sol.to_string(),
+                    token_b: usdc.to_string(),
+                }],
+                profit_threshold: 50,
+                max_borrow: 5_000_000_000,
+            }]
+        );
+
+        let error = json!({ "jsonrpc": "2.0", "id": 1, "error": { "code": -32600, "message": "bad" } });
+        assert!(parse_program_accounts(&error).is_err());
+    }
+}
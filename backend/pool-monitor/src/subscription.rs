@@ -0,0 +1,376 @@
+/*!
+ * Solana websocket account subscriptions
+ *
+ * Speaks the `accountSubscribe` / `programSubscribe` JSON-RPC pubsub protocol
+ * directly over tokio-tungstenite. When the connection drops, the subscriber
+ * waits with exponential backoff, reconnects and resubscribes every request.
+ */
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use futures::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{debug, error, info, warn};
+
+/// Account or program to subscribe to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionRequest {
+    /// Single account (pool state or vault token account)
+    Account(String),
+    /// Every account owned by a program
+    Program(String),
+}
+
+impl SubscriptionRequest {
+    fn to_rpc(&self, id: u64, commitment: &str) -> Value {
+        let (method, key) = match self {
+            SubscriptionRequest::Account(pubkey) => ("accountSubscribe", pubkey),
+            SubscriptionRequest::Program(program_id) => ("programSubscribe", program_id),
+        };
+
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": [key, { "encoding": "base64", "commitment": commitment }],
+        })
+    }
+}
+
+/// Decoded account notification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountUpdate {
+    pub pubkey: String,
+    pub slot: u64,
+    pub data: Vec<u8>,
+}
+
+/// Receives account updates from the subscriber
+#[async_trait]
+pub trait AccountHandler: Send + Sync {
+    async fn on_account_update(&self, update: AccountUpdate);
+}
+
+/// Convert an HTTP RPC endpoint into its websocket equivalent
+pub fn websocket_url(rpc_url: &str) -> String {
+    if let Some(rest) = rpc_url.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else {
+        rpc_url.to_string()
+    }
+}
+
+pub struct AccountSubscriber {
+    ws_url: String,
+    requests: Vec<SubscriptionRequest>,
+    commitment: String,
+    min_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl AccountSubscriber {
+    pub fn new(ws_url: String, requests: Vec<SubscriptionRequest>) -> Self {
+        Self {
+            ws_url,
+            requests,
+            commitment: "confirmed".to_string(),
+            min_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+
+    /// Stream updates to the handler forever, reconnecting on failure
+    pub async fn run<H: AccountHandler>(&self, handler: &H) {
+        let mut backoff = self.min_backoff;
+
+        loop {
+            match self.run_connection(handler, &mut backoff).await {
+                Ok(()) => warn!("Websocket closed by server"),
+                Err(e) => error!("Websocket error: {}", e),
+            }
+
+            info!("Reconnecting to {} in {:?}", self.ws_url, backoff);
+            time::sleep(backoff).await;
+            backoff = (backoff * 2).min(self.max_backoff);
+        }
+    }
+
+    /// Connect, subscribe and forward notifications until the connection ends
+    async fn run_connection<H: AccountHandler>(
+        &self,
+        handler: &H,
+        backoff: &mut Duration,
+    ) -> Result<()> {
+        let (mut socket, _) = connect_async(self.ws_url.as_str()).await?;
+        info!("Connected to {}", self.ws_url);
+
+        for (id, request) in self.requests.iter().enumerate() {
+            let payload = request.to_rpc(id as u64, &self.commitment);
+            socket.send(Message::Text(payload.to_string())).await?;
+        }
+
+        // Request id -> request, then subscription id -> request once confirmed
+        let mut pending: HashMap<u64, &SubscriptionRequest> =
+            self.requests.iter().enumerate().map(|(id, r)| (id as u64, r)).collect();
+        let mut active: HashMap<u64, &SubscriptionRequest> = HashMap::new();
+
+        while let Some(message) = socket.next().await {
+            let text = match message? {
+                Message::Text(text) => text,
+                Message::Ping(payload) => {
+                    socket.send(Message::Pong(payload)).await?;
+                    continue;
+                }
+                Message::Close(_) => return Ok(()),
+                _ => continue,
+            };
+
+            let value: Value = match serde_json::from_str(&text) {
+                Ok(value) => value,
+                Err(e) => {
+                    warn!("Ignoring malformed websocket message: {}", e);
+                    continue;
+                }
+            };
+
+            // Subscription confirmation
+            if let Some(id) = value.get("id").and_then(Value::as_u64) {
+                let request = pending
+                    .remove(&id)
+                    .ok_or_else(|| anyhow!("Unexpected response id {}", id))?;
+
+                match value.get("result").and_then(Value::as_u64) {
+                    Some(subscription) => {
+                        debug!("Subscribed {:?} as {}", request, subscription);
+                        active.insert(subscription, request);
+                    }
+                    None => return Err(anyhow!("Subscription to {:?} failed: {}", request, value)),
+                }
+
+                // Fully resubscribed, so the next drop starts from the minimum backoff
+                if pending.is_empty() {
+                    *backoff = self.min_backoff;
+                }
+                continue;
+            }
+
+            let Some(params) = value.get("params") else {
+                continue;
+            };
+            let Some(request) = params
+                .get("subscription")
+                .and_then(Value::as_u64)
+                .and_then(|subscription| active.get(&subscription))
+            else {
+                continue;
+            };
+
+            match parse_notification(request, &params["result"]) {
+                Some(update) => handler.on_account_update(update).await,
+                None => warn!("Could not decode notification for {:?}", request),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Decode an accountNotification / programNotification result
+fn parse_notification(request: &SubscriptionRequest, result: &Value) -> Option<AccountUpdate> {
+    let slot = result["context"]["slot"].as_u64()?;
+
+    let (pubkey, account) = match request {
+        SubscriptionRequest::Account(pubkey) => (pubkey.clone(), &result["value"]),
+        SubscriptionRequest::Program(_) => (
+            result["value"]["pubkey"].as_str()?.to_string(),
+            &result["value"]["account"],
+        ),
+    };
+
+    // Data is encoded as [payload, "base64"]
+    let encoded = account["data"].get(0)?.as_str()?;
+    let data = BASE64.decode(encoded).ok()?;
+
+    Some(AccountUpdate { pubkey, slot, data })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+    use tokio::sync::mpsc;
+    use tokio_tungstenite::accept_async;
+
+    /// Forwards every update to the test
+    struct RecordingHandler {
+        updates: mpsc::UnboundedSender<AccountUpdate>,
+    }
+
+    #[async_trait]
+    impl AccountHandler for RecordingHandler {
+        async fn on_account_update(&self, update: AccountUpdate) {
+            let _ = self.updates.send(update);
+        }
+    }
+
+    fn notification(method: &str, subscription: u64, slot: u64, value: Value) -> Message {
+        Message::Text(
+            json!({
+                "jsonrpc": "2.0",
+                "method": method,
+                "params": {
+                    "result": { "context": { "slot": slot }, "value": value },
+                    "subscription": subscription,
+                },
+            })
+            .to_string(),
+        )
+    }
+
+    fn account_value(data: &[u8]) -> Value {
+        json!({
+            "data": [BASE64.encode(data), "base64"],
+            "executable": false,
+            "lamports": 1,
+            "owner": "11111111111111111111111111111111",
+            "rentEpoch": 0,
+        })
+    }
+
+    /// Local websocket stand-in: accepts `connections` clients, confirms every
+    /// subscription, sends one notification per subscription and then hangs up
+    async fn spawn_stand_in(connections: usize) -> (String, mpsc::UnboundedReceiver<Vec<Value>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (requests_tx, requests_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            for connection in 0..connections {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut socket = accept_async(stream).await.unwrap();
+                let mut received = Vec::new();
+
+                while let Some(Ok(Message::Text(text))) = socket.next().await {
+                    let request: Value = serde_json::from_str(&text).unwrap();
+                    let id = request["id"].as_u64().unwrap();
+                    let subscription = 100 + id;
+                    socket
+                        .send(Message::Text(
+                            json!({ "jsonrpc": "2.0", "result": subscription, "id": id })
+                                .to_string(),
+                        ))
+                        .await
+                        .unwrap();
+
+                    let slot = connection as u64;
+                    let message = match request["method"].as_str().unwrap() {
+                        "accountSubscribe" => notification(
+                            "accountNotification",
+                            subscription,
+                            slot,
+                            account_value(&[id as u8, 1, 2]),
+                        ),
+                        _ => notification(
+                            "programNotification",
+                            subscription,
+                            slot,
+                            json!({ "pubkey": "owned", "account": account_value(&[9]) }),
+                        ),
+                    };
+                    socket.send(message).await.unwrap();
+
+                    received.push(request);
+                    if received.len() == 2 {
+                        break;
+                    }
+                }
+
+                requests_tx.send(received).unwrap();
+                socket.close(None).await.unwrap();
+            }
+        });
+
+        (url, requests_rx)
+    }
+
+    #[test]
+    fn test_websocket_url_from_rpc() {
+        assert_eq!(
+            websocket_url("https://mainnet.helius-rpc.com/?api-key=abc"),
+            "wss://mainnet.helius-rpc.com/?api-key=abc"
+        );
+        assert_eq!(websocket_url("http://127.0.0.1:8899"), "ws://127.0.0.1:8899");
+    }
+
+    #[tokio::test]
+    async fn test_subscribes_and_decodes_notifications() {
+        let (url, mut requests) = spawn_stand_in(1).await;
+        let (updates_tx, mut updates) = mpsc::unbounded_channel();
+        let handler = RecordingHandler { updates: updates_tx };
+
+        let subscriber = AccountSubscriber::new(
+            url,
+            vec![
+                SubscriptionRequest::Account("pool".to_string()),
+                SubscriptionRequest::Program("program".to_string()),
+            ],
+        );
+        let task = tokio::spawn(async move { subscriber.run(&handler).await });
+
+        let sent = requests.recv().await.unwrap();
+        assert_eq!(sent[0]["method"], "accountSubscribe");
+        assert_eq!(sent[0]["params"][0], "pool");
+        assert_eq!(sent[0]["params"][1]["encoding"], "base64");
+        assert_eq!(sent[1]["method"], "programSubscribe");
+
+        let first = updates.recv().await.unwrap();
+        assert_eq!(first, AccountUpdate { pubkey: "pool".to_string(), slot: 0, data: vec![0, 1, 2] });
+        let second = updates.recv().await.unwrap();
+        assert_eq!(second, AccountUpdate { pubkey: "owned".to_string(), slot: 0, data: vec![9] });
+
+        task.abort();
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_and_resubscribes_after_disconnect() {
+        let (url, mut requests) = spawn_stand_in(2).await;
+        let (updates_tx, mut updates) = mpsc::unbounded_channel();
+        let handler = RecordingHandler { updates: updates_tx };
+
+        let mut subscriber = AccountSubscriber::new(
+            url,
+            vec![
+                SubscriptionRequest::Account("vault-a".to_string()),
+                SubscriptionRequest::Account("vault-b".to_string()),
+            ],
+        );
+        subscriber.min_backoff = Duration::from_millis(10);
+        subscriber.max_backoff = Duration::from_millis(50);
+        let task = tokio::spawn(async move { subscriber.run(&handler).await });
+
+        // Same subscriptions are sent on both connections
+        let first = requests.recv().await.unwrap();
+        let second = requests.recv().await.unwrap();
+        let keys = |sent: &Vec<Value>| -> Vec<String> {
+            sent.iter().map(|r| r["params"][0].as_str().unwrap().to_string()).collect()
+        };
+        assert_eq!(keys(&first), vec!["vault-a", "vault-b"]);
+        assert_eq!(keys(&second), keys(&first));
+
+        // Updates keep flowing after the reconnect (slot = connection index)
+        let mut slots = Vec::new();
+        for _ in 0..4 {
+            slots.push(updates.recv().await.unwrap().slot);
+        }
+        assert_eq!(slots, vec![0, 0, 1, 1]);
+
+        task.abort();
+    }
+}
@@ -5,7 +5,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DexType {
     Raydium,
     Orca,
@@ -38,6 +38,15 @@ pub struct PoolPrice {
     pub timestamp: i64,
 }
 
+/// One swap of an arbitrage route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hop {
+    pub dex: DexType,
+    pub pool: String,
+    pub token_in: String,
+    pub token_out: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArbitrageOpportunity {
     pub id: String,
@@ -53,9 +62,31 @@ pub struct ArbitrageOpportunity {
     pub expected_profit: u64,     // Expected profit in lamports
     pub suggested_amount: u64,    // Suggested trade amount in lamports
     pub timestamp: i64,
+    /// Swaps in execution order, starting and ending with the borrowed token
+    #[serde(default)]
+    pub hops: Vec<Hop>,
+    /// Approved strategy accounts that can execute this route
+    #[serde(default)]
+    pub strategies: Vec<String>,
 }
 
 impl ArbitrageOpportunity {
+    /// Identifies the trade route regardless of prices (used to dedup publishes)
+    pub fn route_key(&self) -> String {
+        if self.hops.is_empty() {
+            return format!(
+                "{}:{}->{}:{}",
+                self.buy_dex, self.buy_pool, self.sell_dex, self.sell_pool
+            );
+        }
+
+        self.hops
+            .iter()
+            .map(|hop| format!("{}:{}", hop.dex, hop.pool))
+            .collect::<Vec<_>>()
+            .join("->")
+    }
+
     pub fn calculate_profit_bps(buy_price: f64, sell_price: f64) -> u16 {
         let profit_ratio = (sell_price - buy_price) / buy_price;
         (profit_ratio * 10000.0) as u16
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+/// Longest window an admin may grant the guardian (24 hours)
+pub const MAX_GUARDIAN_PAUSE_SECONDS: i64 = 86_400;
+
+/// Circuit breaker for groups of program instructions (bit per group, each
+/// program defines its own PAUSE_* constants)
+///
+/// Admin pauses last until the admin lifts them. The guardian can only pause
+/// for guardian_pause_seconds, giving the admin time to take over. Who the
+/// admin is depends on the program (see its set_pause instruction).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq, Debug)]
+pub struct PauseFlags {
+    /// Key allowed to pause temporarily (Pubkey::default() when unset)
+    pub guardian: Pubkey,
+
+    /// How long a guardian pause lasts, set together with the guardian
+    pub guardian_pause_seconds: i64,
+
+    /// Groups paused by the admin (no expiry)
+    pub admin_paused: u8,
+
+    /// Groups paused by the guardian (until guardian_expires_at)
+    pub guardian_paused: u8,
+
+    /// When the guardian pause lapses (Unix timestamp)
+    pub guardian_expires_at: i64,
+}
+
+impl PauseFlags {
+    pub const LEN: usize = 32 + // guardian
+        8 + // guardian_pause_seconds
+        1 + // admin_paused
+        1 + // guardian_paused
+        8; // guardian_expires_at
+
+    /// Check if `pause_seconds` is a window an admin may grant the guardian
+    pub fn is_valid_guardian_window(pause_seconds: i64) -> bool {
+        pause_seconds > 0 && pause_seconds <= MAX_GUARDIAN_PAUSE_SECONDS
+    }
+
+    /// Check if key is the configured guardian
+    pub fn is_guardian(&self, key: &Pubkey) -> bool {
+        self.guardian != Pubkey::default() && *key == self.guardian
+    }
+
+    /// Check if any group in `groups` is paused at `now`
+    pub fn is_paused(&self, groups: u8, now: i64) -> bool {
+        self.admin_paused & groups != 0
+            || (self.guardian_paused & groups != 0 && now < self.guardian_expires_at)
+    }
+
+    /// Replace the guardian and its pause window (callers validate the window)
+    pub fn set_guardian(&mut self, guardian: Pubkey, pause_seconds: i64) {
+        self.guardian = guardian;
+        self.guardian_pause_seconds = pause_seconds;
+    }
+
+    /// Replace the admin pause (also lifts any guardian pause)
+    pub fn set_by_admin(&mut self, groups: u8) {
+        self.admin_paused = groups;
+        self.guardian_paused = 0;
+        self.guardian_expires_at = 0;
+    }
+
+    /// Replace the guardian pause, expiring guardian_pause_seconds from `now`
+    pub fn set_by_guardian(&mut self, groups: u8, now: i64) {
+        self.guardian_paused = groups;
+        self.guardian_expires_at = now.saturating_add(self.guardian_pause_seconds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guardian_pause_lapses_after_its_window() {
+        let guardian = Pubkey::new_unique();
+        let mut pause = PauseFlags::default();
+        pause.set_guardian(guardian, 60);
+
+        pause.set_by_guardian(0b01, 1_000);
+        assert!(pause.is_paused(0b01, 1_059));
+        assert!(!pause.is_paused(0b01, 1_060));
+        assert!(!pause.is_paused(0b10, 1_000));
+    }
+
+    #[test]
+    fn admin_pause_replaces_guardian_pause() {
+        let mut pause = PauseFlags::default();
+        pause.set_guardian(Pubkey::new_unique(), 60);
+        pause.set_by_guardian(0b01, 1_000);
+
+        pause.set_by_admin(0b10);
+        assert!(!pause.is_paused(0b01, 1_000));
+        assert!(pause.is_paused(0b10, i64::MAX));
+    }
+
+    #[test]
+    fn guardian_window_is_capped() {
+        assert!(!PauseFlags::is_valid_guardian_window(0));
+        assert!(PauseFlags::is_valid_guardian_window(MAX_GUARDIAN_PAUSE_SECONDS));
+        assert!(!PauseFlags::is_valid_guardian_window(MAX_GUARDIAN_PAUSE_SECONDS + 1));
+    }
+}
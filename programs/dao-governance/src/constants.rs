@@ -15,6 +15,23 @@ pub const DEFAULT_QUORUM_PERCENTAGE: u8 = 10;  // 10% of circulating supply
 pub const DEFAULT_VOTING_PERIOD: i64 = 3 * 24 * 60 * 60;  // 3 days in seconds
 pub const MIN_PROPOSAL_THRESHOLD: u64 = 1_000 * 1_000_000_000;  // 1,000 REBEL to propose
 
+/// Pause Groups (bitmask for set_pause)
+#[constant]
+pub const PAUSE_PROPOSE: u8 = 1 << 0;  // create_proposal
+#[constant]
+pub const PAUSE_VOTE: u8 = 1 << 1;     // cast_vote
+#[constant]
+pub const PAUSE_EXECUTE: u8 = 1 << 2;  // execute_proposal
+#[constant]
+pub const PAUSE_ALL: u8 = PAUSE_PROPOSE | PAUSE_VOTE | PAUSE_EXECUTE;
+
+/// Execution engine program, paused by PauseChange proposals through a plain
+/// CPI (the engine depends on this crate, so its client cannot be used here)
+pub const EXECUTION_ENGINE_PROGRAM_ID: Pubkey = pubkey!("REC2Aq9iAu4hu7efgJhtyFyWS1bSBDYgeoVXXQFtfpk");
+
+/// Every execution engine pause group (mirrors execution_engine::PAUSE_ALL)
+pub const EXECUTION_ENGINE_PAUSE_ALL: u8 = 0b11;
+
 /// PDA Seeds
 pub const GOVERNANCE_SEED: &[u8] = b"governance";
 pub const PROPOSAL_SEED: &[u8] = b"proposal";
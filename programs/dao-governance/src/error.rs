@@ -52,4 +52,16 @@ pub enum GovernanceError {
 
     #[msg("Treasury balance insufficient for withdrawal")]
     InsufficientTreasuryBalance,
+
+    #[msg("This instruction group is paused")]
+    ProgramPaused,
+
+    #[msg("Invalid pause groups")]
+    InvalidPauseGroups,
+
+    #[msg("Guardian pause window must be between 1 second and 24 hours")]
+    InvalidGuardianPause,
+
+    #[msg("Pause target accounts do not match the proposal")]
+    InvalidPauseTarget,
 }
@@ -65,6 +65,12 @@ pub fn handler(
     let vote_record = &mut ctx.accounts.vote_record;
     let clock = Clock::get()?;
 
+    require!(
+        !proposal.is_pausable()
+            || !ctx.accounts.governance_config.pause.is_paused(PAUSE_VOTE, clock.unix_timestamp),
+        GovernanceError::ProgramPaused
+    );
+
     // Validate voting period
     require!(
         clock.unix_timestamp >= proposal.voting_starts,
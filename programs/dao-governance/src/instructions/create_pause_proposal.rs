@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    instructions::create_proposal::open_proposal,
+    state::*,
+};
+
+/// Create a proposal to set the no-expiry pause of governance, the flash loan
+/// pool or the execution engine (guardians can only pause temporarily)
+/// Pause proposals can be created, voted on and executed while governance is paused
+#[derive(Accounts)]
+pub struct CreatePauseProposal<'info> {
+    /// Governance configuration
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// New proposal account
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [
+            PROPOSAL_SEED,
+            &governance_config.next_proposal_id.to_le_bytes()
+        ],
+        bump,
+        space = Proposal::LEN
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Proposer's REBEL token account (must have minimum threshold)
+    #[account(
+        constraint = proposer_token_account.mint == governance_config.rebel_mint,
+        constraint = proposer_token_account.owner == proposer.key()
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+
+    /// Proposer (signer)
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreatePauseProposal>,
+    description: String,
+    action: PauseAction,
+) -> Result<()> {
+    require!(action.is_valid(), GovernanceError::InvalidPauseGroups);
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_type = ProposalType::PauseChange;
+    proposal.strategy_to_approve = Pubkey::default();
+    proposal.strategy_version = 0;
+    proposal.registry_action = RegistryAction::None;
+    proposal.pause_action = action;
+
+    open_proposal(
+        &mut ctx.accounts.governance_config,
+        proposal,
+        ctx.accounts.proposer.key(),
+        &ctx.accounts.proposer_token_account,
+        description,
+        ctx.bumps.proposal,
+    )?;
+
+    msg!("Pause change proposed: {:?}", action);
+
+    Ok(())
+}
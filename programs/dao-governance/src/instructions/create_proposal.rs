@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::TokenAccount;
+use strategy_registry::StrategyAccount;
 use crate::{
     constants::*,
     error::GovernanceError,
@@ -7,7 +8,7 @@ use crate::{
 };
 
 /// Create a new governance proposal
-/// For hackathon: focuses on StrategyApproval proposals
+/// Supports StrategyApproval and StrategyRejection proposals
 #[derive(Accounts)]
 #[instruction(strategy_to_approve: Pubkey)]
 pub struct CreateProposal<'info> {
@@ -32,6 +33,10 @@ pub struct CreateProposal<'info> {
     )]
     pub proposal: Account<'info, Proposal>,
 
+    /// Target strategy, its current version is bound into the proposal
+    #[account(address = strategy_to_approve @ GovernanceError::InvalidStrategy)]
+    pub strategy: Account<'info, StrategyAccount>,
+
     /// Proposer's REBEL token account (must have minimum threshold)
     #[account(
         constraint = proposer_token_account.mint == governance_config.rebel_mint,
@@ -51,15 +56,62 @@ pub fn handler(
     ctx: Context<CreateProposal>,
     strategy_to_approve: Pubkey,
     description: String,
+    proposal_type: ProposalType,
 ) -> Result<()> {
-    let config = &mut ctx.accounts.governance_config;
+    // Only strategy decisions go through this instruction
+    // (registry changes use create_registry_proposal)
+    require!(
+        proposal_type == ProposalType::StrategyApproval
+            || proposal_type == ProposalType::StrategyRejection,
+        GovernanceError::InvalidProposalType
+    );
+
     let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_type = proposal_type;
+    proposal.strategy_to_approve = strategy_to_approve;
+    proposal.strategy_version = ctx.accounts.strategy.version;
+    proposal.registry_action = RegistryAction::None;
+    proposal.pause_action = PauseAction::default();
+
+    open_proposal(
+        &mut ctx.accounts.governance_config,
+        proposal,
+        ctx.accounts.proposer.key(),
+        &ctx.accounts.proposer_token_account,
+        description,
+        ctx.bumps.proposal,
+    )?;
+
+    msg!(
+        "Proposal type: {:?}, Strategy: {} (version {})",
+        proposal_type,
+        strategy_to_approve,
+        proposal.strategy_version
+    );
+
+    Ok(())
+}
+
+/// Checks shared by every proposal type, then opens voting on `proposal`
+/// Callers set the type-specific fields (type, strategy, registry or pause action)
+pub(crate) fn open_proposal(
+    config: &mut GovernanceConfig,
+    proposal: &mut Proposal,
+    proposer: Pubkey,
+    proposer_token_account: &TokenAccount,
+    description: String,
+    bump: u8,
+) -> Result<()> {
     let clock = Clock::get()?;
 
+    require!(
+        !proposal.is_pausable() || !config.pause.is_paused(PAUSE_PROPOSE, clock.unix_timestamp),
+        GovernanceError::ProgramPaused
+    );
+
     // Verify proposer has minimum REBEL tokens
-    let proposer_balance = ctx.accounts.proposer_token_account.amount;
     require!(
-        proposer_balance >= config.proposal_threshold,
+        proposer_token_account.amount >= config.proposal_threshold,
         GovernanceError::InsufficientTokens
     );
 
@@ -71,10 +123,8 @@ pub fn handler(
 
     // Initialize proposal
     proposal.proposal_id = config.next_proposal_id;
-    proposal.proposal_type = ProposalType::StrategyApproval;
-    proposal.proposer = ctx.accounts.proposer.key();
-    proposal.strategy_to_approve = strategy_to_approve;
-    proposal.description = description.clone();
+    proposal.proposer = proposer;
+    proposal.description = description;
     proposal.voting_starts = clock.unix_timestamp;
     proposal.voting_ends = clock.unix_timestamp + config.voting_period_seconds;
     proposal.votes_yes = 0;
@@ -82,7 +132,7 @@ pub fn handler(
     proposal.votes_abstain = 0;
     proposal.status = ProposalStatus::Active;
     proposal.executed = false;
-    proposal.bump = ctx.bumps.proposal;
+    proposal.bump = bump;
 
     // Increment proposal counters
     config.next_proposal_id = config
@@ -95,7 +145,6 @@ pub fn handler(
         .ok_or(GovernanceError::ArithmeticOverflow)?;
 
     msg!("Proposal {} created by {}", proposal.proposal_id, proposal.proposer);
-    msg!("Strategy to approve: {}", strategy_to_approve);
     msg!("Voting ends: {}", proposal.voting_ends);
 
     Ok(())
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    instructions::create_proposal::open_proposal,
+    state::*,
+};
+
+/// Create a proposal to change strategy-registry configuration
+/// Once the registry is in governance-only mode this is the only way to
+/// change its settings (including lifting a pause)
+#[derive(Accounts)]
+pub struct CreateRegistryProposal<'info> {
+    /// Governance configuration
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// New proposal account
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [
+            PROPOSAL_SEED,
+            &governance_config.next_proposal_id.to_le_bytes()
+        ],
+        bump,
+        space = Proposal::LEN
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Proposer's REBEL token account (must have minimum threshold)
+    #[account(
+        constraint = proposer_token_account.mint == governance_config.rebel_mint,
+        constraint = proposer_token_account.owner == proposer.key()
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+
+    /// Proposer (signer)
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateRegistryProposal>,
+    description: String,
+    action: RegistryAction,
+) -> Result<()> {
+    require!(
+        action != RegistryAction::None,
+        GovernanceError::InvalidProposalType
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_type = ProposalType::RegistryConfig;
+    proposal.strategy_to_approve = Pubkey::default();
+    proposal.strategy_version = 0;
+    proposal.registry_action = action;
+    proposal.pause_action = PauseAction::default();
+
+    open_proposal(
+        &mut ctx.accounts.governance_config,
+        proposal,
+        ctx.accounts.proposer.key(),
+        &ctx.accounts.proposer_token_account,
+        description,
+        ctx.bumps.proposal,
+    )?;
+
+    msg!("Registry change proposed: {:?}", action);
+
+    Ok(())
+}
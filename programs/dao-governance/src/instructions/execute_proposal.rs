@@ -1,8 +1,21 @@
 use anchor_lang::prelude::*;
+use anchor_lang::InstructionData;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use flash_loan::cpi::accounts::SetPause as FlashLoanSetPauseAccounts;
 use strategy_registry::{
     program::StrategyRegistry,
     cpi::accounts::ApproveStrategy as ApproveStrategyAccounts,
-    StrategyAccount,
+    cpi::accounts::EmergencyPauseStrategy as EmergencyPauseStrategyAccounts,
+    cpi::accounts::RejectStrategy as RejectStrategyAccounts,
+    cpi::accounts::ResumeStrategy as ResumeStrategyAccounts,
+    cpi::accounts::SetApprovalMode as SetApprovalModeAccounts,
+    cpi::accounts::SetCreationBond as SetCreationBondAccounts,
+    cpi::accounts::SetExecutionEngine as SetExecutionEngineAccounts,
+    cpi::accounts::SetGuardian as SetGuardianAccounts,
+    cpi::accounts::SetPause as SetPauseAccounts,
 };
 use crate::{
     constants::*,
@@ -12,7 +25,12 @@ use crate::{
 
 /// Execute a proposal after voting period ends
 /// If quorum reached and majority votes yes, execute the action
-/// For StrategyApproval: CPI to strategy-registry to approve strategy
+/// For StrategyApproval/StrategyRejection: CPI to strategy-registry
+/// (remaining accounts are forwarded so the strategy's pair indexes stay in sync)
+/// For RegistryConfig: CPI to the registry setter named by the proposal
+/// (strategy actions also forward the strategy account and its pair indexes)
+/// For PauseChange: sets the target's no-expiry pause (remaining accounts are
+/// the target's state account and program; none when governance is the target)
 #[derive(Accounts)]
 pub struct ExecuteProposal<'info> {
     /// Governance configuration (acts as signer via PDA)
@@ -34,13 +52,15 @@ pub struct ExecuteProposal<'info> {
     )]
     pub proposal: Account<'info, Proposal>,
 
-    /// Strategy to approve (if ProposalType::StrategyApproval)
+    /// Target strategy (if ProposalType::StrategyApproval or StrategyRejection,
+    /// or an emergency pause / resume RegistryAction)
     /// CHECK: Validated by strategy-registry program via CPI
     #[account(mut)]
     pub strategy_account: UncheckedAccount<'info>,
 
     /// Admin config from strategy-registry (needed for CPI)
     /// CHECK: Validated by strategy-registry program
+    #[account(mut)]
     pub admin_config: UncheckedAccount<'info>,
 
     /// Strategy Registry program for CPI
@@ -50,11 +70,16 @@ pub struct ExecuteProposal<'info> {
     pub executor: Signer<'info>,
 }
 
-pub fn handler(ctx: Context<ExecuteProposal>) -> Result<()> {
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>) -> Result<()> {
     let proposal = &mut ctx.accounts.proposal;
     let config = &ctx.accounts.governance_config;
     let clock = Clock::get()?;
 
+    require!(
+        !proposal.is_pausable() || !config.pause.is_paused(PAUSE_EXECUTE, clock.unix_timestamp),
+        GovernanceError::ProgramPaused
+    );
+
     // Validate voting has ended
     require!(
         clock.unix_timestamp > proposal.voting_ends,
@@ -83,6 +108,7 @@ pub fn handler(ctx: Context<ExecuteProposal>) -> Result<()> {
 
     // Update proposal status
     proposal.status = ProposalStatus::Succeeded;
+    let governance_bump = config.bump;
 
     // Execute action based on proposal type
     match proposal.proposal_type {
@@ -100,11 +126,68 @@ pub fn handler(ctx: Context<ExecuteProposal>) -> Result<()> {
                 ctx.accounts.strategy_account.to_account_info(),
                 ctx.accounts.admin_config.to_account_info(),
                 ctx.accounts.governance_config.to_account_info(),
-                config.bump,
+                governance_bump,
+                proposal.strategy_version,
+                ctx.remaining_accounts.to_vec(),
             )?;
 
             msg!("Strategy {} approved via DAO governance", proposal.strategy_to_approve);
         }
+        ProposalType::StrategyRejection => {
+            // Verify strategy account matches proposal
+            require!(
+                ctx.accounts.strategy_account.key() == proposal.strategy_to_approve,
+                GovernanceError::InvalidStrategy
+            );
+
+            // CPI to strategy-registry to reject strategy
+            execute_strategy_rejection(
+                ctx.accounts.strategy_registry_program.to_account_info(),
+                ctx.accounts.strategy_account.to_account_info(),
+                ctx.accounts.admin_config.to_account_info(),
+                ctx.accounts.governance_config.to_account_info(),
+                governance_bump,
+                ctx.remaining_accounts.to_vec(),
+            )?;
+
+            msg!("Strategy {} rejected via DAO governance", proposal.strategy_to_approve);
+        }
+        ProposalType::RegistryConfig => {
+            // CPI to the strategy-registry setter, governance PDA as authority
+            execute_registry_change(
+                ctx.accounts.strategy_registry_program.to_account_info(),
+                ctx.accounts.strategy_account.to_account_info(),
+                ctx.accounts.admin_config.to_account_info(),
+                ctx.accounts.governance_config.to_account_info(),
+                governance_bump,
+                proposal.registry_action,
+                ctx.remaining_accounts.to_vec(),
+            )?;
+
+            msg!("Registry change {:?} applied via DAO governance", proposal.registry_action);
+        }
+        ProposalType::PauseChange => {
+            let action = proposal.pause_action;
+
+            if action.target == PauseTarget::Governance {
+                ctx.accounts.governance_config.pause.set_by_admin(action.groups);
+            } else {
+                // The target program checks the state account and that the governance PDA signed
+                let [target_state, target_program] = ctx.remaining_accounts else {
+                    return err!(GovernanceError::InvalidPauseTarget);
+                };
+
+                execute_pause_change(
+                    target_program.clone(),
+                    target_state.clone(),
+                    ctx.accounts.governance_config.to_account_info(),
+                    governance_bump,
+                    action,
+                )?;
+            }
+
+            msg!("Pause change {:?} applied via DAO governance", action);
+        }
         _ => {
             // Other proposal types not implemented for hackathon
             return Err(GovernanceError::InvalidProposalType.into());
@@ -132,6 +215,8 @@ fn execute_strategy_approval<'info>(
     admin_config: AccountInfo<'info>,
     governance_config: AccountInfo<'info>,
     governance_bump: u8,
+    strategy_version: u32,
+    pair_indexes: Vec<AccountInfo<'info>>,
 ) -> Result<()> {
     // Prepare signer seeds for governance PDA
     let governance_seed = GOVERNANCE_SEED;
@@ -150,12 +235,168 @@ fn execute_strategy_approval<'info>(
         strategy_registry_program,
         cpi_accounts,
         signer_seeds,
-    );
+    )
+    .with_remaining_accounts(pair_indexes);
 
     // Call approve_strategy via CPI
-    strategy_registry::cpi::approve_strategy(cpi_ctx)?;
+    // The registry refuses if the strategy was edited after the proposal opened
+    strategy_registry::cpi::approve_strategy(cpi_ctx, strategy_version)?;
 
     msg!("Strategy approved via DAO governance CPI");
 
     Ok(())
 }
+
+/// Helper function to execute strategy rejection via CPI
+fn execute_strategy_rejection<'info>(
+    strategy_registry_program: AccountInfo<'info>,
+    strategy_account: AccountInfo<'info>,
+    admin_config: AccountInfo<'info>,
+    governance_config: AccountInfo<'info>,
+    governance_bump: u8,
+    pair_indexes: Vec<AccountInfo<'info>>,
+) -> Result<()> {
+    // Prepare signer seeds for governance PDA
+    let governance_seed = GOVERNANCE_SEED;
+    let bump = &[governance_bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[governance_seed, bump]];
+
+    // Build CPI accounts for strategy-registry reject_strategy
+    let cpi_accounts = RejectStrategyAccounts {
+        strategy: strategy_account,
+        admin_config,
+        admin: governance_config, // Governance PDA acts as rejecter
+    };
+
+    // Execute CPI with governance PDA as signer
+    let cpi_ctx = CpiContext::new_with_signer(
+        strategy_registry_program,
+        cpi_accounts,
+        signer_seeds,
+    )
+    .with_remaining_accounts(pair_indexes);
+
+    // Call reject_strategy via CPI
+    strategy_registry::cpi::reject_strategy(cpi_ctx)?;
+
+    msg!("Strategy rejected via DAO governance CPI");
+
+    Ok(())
+}
+
+/// Helper function to apply a registry configuration change via CPI
+fn execute_registry_change<'info>(
+    strategy_registry_program: AccountInfo<'info>,
+    strategy_account: AccountInfo<'info>,
+    admin_config: AccountInfo<'info>,
+    governance_config: AccountInfo<'info>,
+    governance_bump: u8,
+    action: RegistryAction,
+    pair_indexes: Vec<AccountInfo<'info>>,
+) -> Result<()> {
+    // Prepare signer seeds for governance PDA
+    let governance_seed = GOVERNANCE_SEED;
+    let bump = &[governance_bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[governance_seed, bump]];
+
+    // Every registry setter takes the admin config and the authority (governance PDA)
+    match action {
+        RegistryAction::SetApprovalMode { governance, governance_only } => {
+            let cpi_accounts = SetApprovalModeAccounts { admin_config, authority: governance_config };
+            let cpi_ctx = CpiContext::new_with_signer(strategy_registry_program, cpi_accounts, signer_seeds);
+            strategy_registry::cpi::set_approval_mode(cpi_ctx, governance, governance_only)?;
+        }
+        RegistryAction::SetExecutionEngine { execution_engine } => {
+            let cpi_accounts = SetExecutionEngineAccounts { admin_config, authority: governance_config };
+            let cpi_ctx = CpiContext::new_with_signer(strategy_registry_program, cpi_accounts, signer_seeds);
+            strategy_registry::cpi::set_execution_engine(cpi_ctx, execution_engine)?;
+        }
+        RegistryAction::SetCreationBond { creation_bond, treasury } => {
+            let cpi_accounts = SetCreationBondAccounts { admin_config, authority: governance_config };
+            let cpi_ctx = CpiContext::new_with_signer(strategy_registry_program, cpi_accounts, signer_seeds);
+            strategy_registry::cpi::set_creation_bond(cpi_ctx, creation_bond, treasury)?;
+        }
+        RegistryAction::SetGuardian { guardian, pause_seconds } => {
+            let cpi_accounts = SetGuardianAccounts { admin_config, authority: governance_config };
+            let cpi_ctx = CpiContext::new_with_signer(strategy_registry_program, cpi_accounts, signer_seeds);
+            strategy_registry::cpi::set_guardian(cpi_ctx, guardian, pause_seconds)?;
+        }
+        RegistryAction::SetPause { groups } => {
+            let cpi_accounts = SetPauseAccounts { admin_config, authority: governance_config };
+            let cpi_ctx = CpiContext::new_with_signer(strategy_registry_program, cpi_accounts, signer_seeds);
+            strategy_registry::cpi::set_pause(cpi_ctx, groups)?;
+        }
+        RegistryAction::EmergencyPauseStrategy { strategy } => {
+            require_keys_eq!(strategy_account.key(), strategy, GovernanceError::InvalidStrategy);
+            let cpi_accounts = EmergencyPauseStrategyAccounts {
+                strategy: strategy_account,
+                admin_config,
+                admin: governance_config,
+            };
+            let cpi_ctx = CpiContext::new_with_signer(strategy_registry_program, cpi_accounts, signer_seeds)
+                .with_remaining_accounts(pair_indexes);
+            strategy_registry::cpi::emergency_pause_strategy(cpi_ctx)?;
+        }
+        RegistryAction::ResumeStrategy { strategy } => {
+            require_keys_eq!(strategy_account.key(), strategy, GovernanceError::InvalidStrategy);
+            let cpi_accounts = ResumeStrategyAccounts {
+                strategy: strategy_account,
+                admin_config,
+                authority: governance_config,
+            };
+            let cpi_ctx = CpiContext::new_with_signer(strategy_registry_program, cpi_accounts, signer_seeds)
+                .with_remaining_accounts(pair_indexes);
+            strategy_registry::cpi::resume_strategy(cpi_ctx)?;
+        }
+        RegistryAction::None => return err!(GovernanceError::InvalidProposalType),
+    }
+
+    msg!("Registry configuration updated via DAO governance CPI");
+
+    Ok(())
+}
+
+/// Helper function to set a program's no-expiry pause, governance PDA as authority
+fn execute_pause_change<'info>(
+    target_program: AccountInfo<'info>,
+    target_state: AccountInfo<'info>,
+    governance_config: AccountInfo<'info>,
+    governance_bump: u8,
+    action: PauseAction,
+) -> Result<()> {
+    // Prepare signer seeds for governance PDA
+    let governance_seed = GOVERNANCE_SEED;
+    let bump = &[governance_bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[governance_seed, bump]];
+
+    match action.target {
+        PauseTarget::FlashLoan => {
+            require_keys_eq!(target_program.key(), flash_loan::ID, GovernanceError::InvalidPauseTarget);
+
+            let cpi_accounts = FlashLoanSetPauseAccounts { pool: target_state, authority: governance_config };
+            let cpi_ctx = CpiContext::new_with_signer(target_program, cpi_accounts, signer_seeds);
+            flash_loan::cpi::set_pause(cpi_ctx, action.groups)?;
+        }
+        PauseTarget::ExecutionEngine => {
+            require_keys_eq!(target_program.key(), EXECUTION_ENGINE_PROGRAM_ID, GovernanceError::InvalidPauseTarget);
+
+            // Engine set_pause(groups) with accounts [vault, authority]; Anchor instruction
+            // data only depends on the name and arguments, which flash-loan's set_pause shares
+            let data = flash_loan::instruction::SetPause { groups: action.groups }.data();
+            let instruction = Instruction {
+                program_id: EXECUTION_ENGINE_PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(target_state.key(), false),
+                    AccountMeta::new_readonly(governance_config.key(), true),
+                ],
+                data,
+            };
+            invoke_signed(&instruction, &[target_state, governance_config, target_program], signer_seeds)?;
+        }
+        PauseTarget::Governance => return err!(GovernanceError::InvalidPauseTarget),
+    }
+
+    msg!("Program pause updated via DAO governance CPI");
+
+    Ok(())
+}
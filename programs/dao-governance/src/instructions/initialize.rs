@@ -115,6 +115,8 @@ pub fn handler(ctx: Context<Initialize>) -> Result<()> {
     config.next_proposal_id = 0;
     config.total_proposals = 0;
     config.distribution_completed = false;
+    config.admin = ctx.accounts.authority.key();
+    config.pause = PauseFlags::default();
     config.bump = ctx.bumps.governance_config;
 
     // Initialize treasury
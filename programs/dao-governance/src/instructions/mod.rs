@@ -1,11 +1,21 @@
 pub mod initialize;
 pub mod create_proposal;
+pub mod create_registry_proposal;
+pub mod create_pause_proposal;
 pub mod cast_vote;
 pub mod execute_proposal;
 pub mod deposit_treasury;
+pub mod set_guardian;
+pub mod set_pause;
 
+// Every module defines its own `handler`, called through its module path
+#[allow(ambiguous_glob_reexports)]
 pub use initialize::*;
 pub use create_proposal::*;
+pub use create_registry_proposal::*;
+pub use create_pause_proposal::*;
 pub use cast_vote::*;
 pub use execute_proposal::*;
 pub use deposit_treasury::*;
+pub use set_guardian::*;
+pub use set_pause::*;
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    state::*,
+};
+
+/// Set the guardian allowed to pause governance temporarily
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    /// Governance configuration
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED],
+        bump = governance_config.bump,
+        has_one = admin @ GovernanceError::UnauthorizedGovernance
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Governance admin
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGuardian>, guardian: Pubkey, pause_seconds: i64) -> Result<()> {
+    require!(
+        PauseFlags::is_valid_guardian_window(pause_seconds),
+        GovernanceError::InvalidGuardianPause
+    );
+
+    ctx.accounts.governance_config.pause.set_guardian(guardian, pause_seconds);
+
+    msg!("Governance guardian set to {} ({}s pauses)", guardian, pause_seconds);
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::GovernanceError,
+    state::*,
+};
+
+/// Guardian pause or unpause of governance instruction groups
+/// (the no-expiry pause is set by executing a PauseChange proposal)
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    /// Governance configuration
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED],
+        bump = governance_config.bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    /// Guardian (pause expires after its pause window)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPause>, groups: u8) -> Result<()> {
+    let config = &mut ctx.accounts.governance_config;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    require!(groups & !PAUSE_ALL == 0, GovernanceError::InvalidPauseGroups);
+
+    require!(
+        config.pause.is_guardian(&authority),
+        GovernanceError::UnauthorizedGovernance
+    );
+
+    config.pause.set_by_guardian(groups, clock.unix_timestamp);
+
+    msg!(
+        "Governance pause updated: admin={:#05b}, guardian={:#05b} until {}",
+        config.pause.admin_paused,
+        config.pause.guardian_paused,
+        config.pause.guardian_expires_at
+    );
+
+    Ok(())
+}
@@ -7,6 +7,8 @@ use anchor_lang::prelude::*;
 
 pub use constants::*;
 pub use error::*;
+// Some instruction modules export handlers named after their #[program] entry point
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::*;
 pub use state::*;
 
@@ -29,13 +31,35 @@ pub mod dao_governance {
     }
 
     /// Create a new governance proposal
-    /// Currently supports StrategyApproval type
+    /// Currently supports StrategyApproval and StrategyRejection types
     pub fn create_proposal(
         ctx: Context<CreateProposal>,
         strategy_to_approve: Pubkey,
         description: String,
+        proposal_type: ProposalType,
     ) -> Result<()> {
-        instructions::create_proposal::handler(ctx, strategy_to_approve, description)
+        instructions::create_proposal::handler(ctx, strategy_to_approve, description, proposal_type)
+    }
+
+    /// Create a proposal to change strategy-registry configuration
+    /// Executed through the matching registry setter, signed by the governance PDA
+    pub fn create_registry_proposal(
+        ctx: Context<CreateRegistryProposal>,
+        description: String,
+        action: RegistryAction,
+    ) -> Result<()> {
+        instructions::create_registry_proposal::handler(ctx, description, action)
+    }
+
+    /// Create a proposal to set the no-expiry pause of governance, the flash
+    /// loan pool or the execution engine, applied by the governance PDA
+    /// (stays available while governance itself is paused)
+    pub fn create_pause_proposal(
+        ctx: Context<CreatePauseProposal>,
+        description: String,
+        action: PauseAction,
+    ) -> Result<()> {
+        instructions::create_pause_proposal::handler(ctx, description, action)
     }
 
     /// Cast a vote on an active proposal
@@ -49,8 +73,10 @@ pub mod dao_governance {
 
     /// Execute a proposal after voting ends
     /// Requires quorum and majority approval
-    /// For StrategyApproval: CPI to strategy-registry
-    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    /// For StrategyApproval/StrategyRejection/RegistryConfig: CPI to strategy-registry
+    /// For PauseChange: sets the target program's pause (remaining accounts:
+    /// target state account and program, none for governance itself)
+    pub fn execute_proposal<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>) -> Result<()> {
         instructions::execute_proposal::handler(ctx)
     }
 
@@ -59,4 +85,17 @@ pub mod dao_governance {
     pub fn deposit_treasury(ctx: Context<DepositTreasury>, amount: u64) -> Result<()> {
         instructions::deposit_treasury::handler(ctx, amount)
     }
+
+    /// Set the guardian allowed to pause governance temporarily, and for how
+    /// long each guardian pause lasts (admin only, at most 24 hours)
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey, pause_seconds: i64) -> Result<()> {
+        instructions::set_guardian::handler(ctx, guardian, pause_seconds)
+    }
+
+    /// Guardian pause of proposal creation, voting and/or execution (PAUSE_* bitmask,
+    /// 0 unpauses), lapsing after the guardian's window
+    /// No-expiry pauses are only set by PauseChange proposals
+    pub fn set_pause(ctx: Context<SetPause>, groups: u8) -> Result<()> {
+        instructions::set_pause::handler(ctx, groups)
+    }
 }
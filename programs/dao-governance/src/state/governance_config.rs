@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::PauseFlags;
 
 /// Main governance configuration account
 /// PDA seeds: [b"governance"]
@@ -37,6 +38,14 @@ pub struct GovernanceConfig {
 
     /// Bump seed for PDA
     pub bump: u8,
+
+    /// Initializer, may set the guardian
+    /// (this and the fields below were appended after bump in a later upgrade)
+    pub admin: Pubkey,
+
+    /// Circuit breaker for proposal, vote and execution groups
+    /// (no-expiry pause set by PauseChange proposals, temporary pause by the guardian)
+    pub pause: PauseFlags,
 }
 
 impl GovernanceConfig {
@@ -51,5 +60,7 @@ impl GovernanceConfig {
         8 +                     // next_proposal_id
         8 +                     // total_proposals
         1 +                     // distribution_completed
-        1;                      // bump
+        1 +                     // bump
+        32 +                    // admin
+        PauseFlags::LEN;        // pause
 }
@@ -7,3 +7,6 @@ pub use governance_config::*;
 pub use proposal::*;
 pub use treasury::*;
 pub use vote_record::*;
+
+// Circuit breaker shared with the other MEVrebels programs
+pub use pause_flags::PauseFlags;
@@ -11,6 +11,12 @@ pub enum ProposalType {
     TreasurySpend,
     /// Protocol upgrade proposal (future)
     ProtocolUpgrade,
+    /// Reject a pending strategy
+    StrategyRejection,
+    /// Change strategy-registry configuration (see RegistryAction)
+    RegistryConfig,
+    /// Set the no-expiry pause of a MEVrebels program (see PauseAction)
+    PauseChange,
 }
 
 impl Default for ProposalType {
@@ -19,6 +25,70 @@ impl Default for ProposalType {
     }
 }
 
+/// Strategy-registry setter executed by a RegistryConfig proposal
+/// (the governance PDA signs the CPI)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum RegistryAction {
+    /// No registry change (strategy proposals)
+    #[default]
+    None,
+    /// set_approval_mode
+    SetApprovalMode { governance: Pubkey, governance_only: bool },
+    /// set_execution_engine
+    SetExecutionEngine { execution_engine: Pubkey },
+    /// set_creation_bond
+    SetCreationBond { creation_bond: u64, treasury: Pubkey },
+    /// set_guardian (guardian and how long its pauses last)
+    SetGuardian { guardian: Pubkey, pause_seconds: i64 },
+    /// set_pause (PAUSE_* bitmask of the registry)
+    SetPause { groups: u8 },
+    /// emergency_pause_strategy (strategy passed as the strategy account)
+    EmergencyPauseStrategy { strategy: Pubkey },
+    /// resume_strategy after an emergency pause (strategy passed as the strategy account)
+    ResumeStrategy { strategy: Pubkey },
+}
+
+impl RegistryAction {
+    /// Variant tag plus the largest payload (SetCreationBond, SetGuardian)
+    pub const LEN: usize = 1 + 8 + 32;
+}
+
+/// Program whose no-expiry pause a PauseChange proposal sets
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PauseTarget {
+    /// This program (applied directly to GovernanceConfig)
+    #[default]
+    Governance,
+    /// flash-loan pool
+    FlashLoan,
+    /// execution-engine vault
+    ExecutionEngine,
+}
+
+/// No-expiry pause applied by a PauseChange proposal
+/// (the governance PDA is the only signer allowed to set it)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct PauseAction {
+    /// Program to pause
+    pub target: PauseTarget,
+    /// PAUSE_* bitmask of the target program (0 unpauses)
+    pub groups: u8,
+}
+
+impl PauseAction {
+    pub const LEN: usize = 1 + 1;
+
+    /// Check that `groups` only names pause groups of the target program
+    pub fn is_valid(&self) -> bool {
+        let all = match self.target {
+            PauseTarget::Governance => crate::constants::PAUSE_ALL,
+            PauseTarget::FlashLoan => flash_loan::PAUSE_ALL,
+            PauseTarget::ExecutionEngine => crate::constants::EXECUTION_ENGINE_PAUSE_ALL,
+        };
+        self.groups & !all == 0
+    }
+}
+
 /// Proposal status lifecycle
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ProposalStatus {
@@ -52,7 +122,7 @@ pub struct Proposal {
     /// Address of proposer
     pub proposer: Pubkey,
 
-    /// Strategy to approve (if ProposalType::StrategyApproval)
+    /// Target strategy (if ProposalType::StrategyApproval or StrategyRejection)
     pub strategy_to_approve: Pubkey,
 
     /// Proposal description (limited to 200 chars for hackathon)
@@ -81,6 +151,18 @@ pub struct Proposal {
 
     /// Bump seed for PDA
     pub bump: u8,
+
+    // Later additions go after bump so earlier proposals decode unchanged
+
+    /// Strategy version the proposal was opened against
+    /// (approval fails if the strategy changed since)
+    pub strategy_version: u32,
+
+    /// Registry change (if ProposalType::RegistryConfig)
+    pub registry_action: RegistryAction,
+
+    /// Pause change (if ProposalType::PauseChange)
+    pub pause_action: PauseAction,
 }
 
 impl Proposal {
@@ -99,7 +181,10 @@ impl Proposal {
         8 +                     // votes_abstain
         1 +                     // status (enum)
         1 +                     // executed (bool)
-        1;                      // bump
+        1 +                     // bump
+        4 +                     // strategy_version
+        RegistryAction::LEN +   // registry_action
+        PauseAction::LEN;       // pause_action
 
     /// Check if voting is active
     pub fn is_voting_active(&self, current_timestamp: i64) -> bool {
@@ -137,4 +222,10 @@ impl Proposal {
             ProposalStatus::Defeated
         }
     }
+
+    /// Check if the governance pause applies to this proposal
+    /// PauseChange proposals are exempt, so a paused DAO can still lift its own pause
+    pub fn is_pausable(&self) -> bool {
+        self.proposal_type != ProposalType::PauseChange
+    }
 }
@@ -5,6 +5,31 @@ use anchor_lang::prelude::*;
 pub const FLASHLOAN_FEE_BPS: u64 = 9;
 pub const BPS_DENOMINATOR: u64 = 10_000;
 
+/// Minimum slots between failed-attempt reports per (strategy, executor)
+/// ~1 minute at 400ms slots, prevents spamming a strategy's failure count
+pub const MIN_SLOTS_BETWEEN_FAILURE_REPORTS: u64 = 150;
+
+/// Maximum distinct strategies in an executor's per-strategy breakdown
+/// Executions beyond this are counted in ExecutorStats::untracked_executions
+pub const MAX_TRACKED_STRATEGIES_PER_EXECUTOR: usize = 10;
+
+/// Pause group: execute_strategy
+#[constant]
+pub const PAUSE_EXECUTE: u8 = 1 << 0;
+
+/// Pause group: report_failed_attempt
+#[constant]
+pub const PAUSE_REPORT: u8 = 1 << 1;
+
+/// Every execution engine pause group
+#[constant]
+pub const PAUSE_ALL: u8 = PAUSE_EXECUTE | PAUSE_REPORT;
+
+/// SOL reserve of each mock pool, in lamports (the USDC side holds the same value)
+/// Fills lose input / (reserve + input) of the quote to price impact, so a
+/// hop slips ~0.1% per 10 SOL traded and max_slippage bounds the trade size
+pub const MOCK_POOL_SOL_RESERVE: u64 = 10_000 * 1_000_000_000;
+
 /// Profit distribution percentages (must sum to 100)
 pub const CREATOR_SHARE_PERCENT: u64 = 40;
 pub const EXECUTOR_SHARE_PERCENT: u64 = 40;
@@ -23,13 +48,13 @@ pub const TREASURY_SHARE_PERCENT: u64 = 20;
 /// - 1 SOL = 98 USDC
 /// - 100 USDC = 1.08 SOL (8% profit margin)
 ///
-/// Example execution with 10 SOL:
+/// Example execution with 10 SOL (price impact against MOCK_POOL_SOL_RESERVE):
 /// 1. Borrow 10 SOL from flashloan vault
-/// 2. Swap 10 SOL → 1000 USDC on DEX A (rate: 100 USDC/SOL)
-/// 3. Swap 1000 USDC → 10.8 SOL on DEX B (rate: 1.08 SOL/100 USDC)
+/// 2. Swap 10 SOL → ~999 USDC on DEX A (quote: 1000 USDC at 100 USDC/SOL)
+/// 3. Swap ~999 USDC → ~10.778 SOL on DEX B (quote: ~10.789 SOL at 1.08 SOL/100 USDC)
 /// 4. Repay 10 SOL + 0.009 SOL fee (0.09%)
-/// 5. Net profit: 10.8 - 10.009 = 0.791 SOL
-/// 6. Distribute: 0.3164 SOL (creator), 0.3164 SOL (executor), 0.1582 SOL (treasury)
+/// 5. Net profit: 10.778 - 10.009 = ~0.769 SOL
+/// 6. Distribute: ~0.3078 SOL (creator), ~0.3078 SOL (executor), ~0.1539 SOL (treasury)
 pub struct MockExchangeRate {
     pub from_token: &'static str,
     pub to_token: &'static str,
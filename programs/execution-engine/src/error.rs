@@ -20,6 +20,9 @@ pub enum ExecutionError {
     #[msg("Slippage tolerance exceeded")]
     SlippageExceeded,
 
+    #[msg("Net profit is below the strategy's profit threshold")]
+    ProfitBelowThreshold,
+
     #[msg("Invalid exchange rate configuration")]
     InvalidExchangeRate,
 
@@ -29,6 +32,9 @@ pub enum ExecutionError {
     #[msg("Strategy is not approved for execution")]
     StrategyNotApproved,
 
+    #[msg("Strategy is paused")]
+    StrategyPaused,
+
     #[msg("Vault is not initialized")]
     VaultNotInitialized,
 
@@ -37,4 +43,31 @@ pub enum ExecutionError {
 
     #[msg("DEX not found for token pair")]
     DexNotFound,
+
+    #[msg("Failed attempt already reported recently for this strategy")]
+    FailureReportTooFrequent,
+
+    #[msg("Strategy is cooling down after its last execution")]
+    ExecutionCooldown,
+
+    #[msg("Strategy reached its execution limit for this epoch")]
+    EpochExecutionLimit,
+
+    #[msg("Borrow amount exceeds the strategy's max borrow")]
+    BorrowCapExceeded,
+
+    #[msg("This instruction group is paused")]
+    ProgramPaused,
+
+    #[msg("Invalid pause groups")]
+    InvalidPauseGroups,
+
+    #[msg("Unauthorized: signer cannot change the engine's pause settings")]
+    UnauthorizedPauser,
+
+    #[msg("Only executors with a successful execution of this strategy can report its failures")]
+    UnregisteredExecutor,
+
+    #[msg("Guardian pause window must be between 1 second and 24 hours")]
+    InvalidGuardianPause,
 }
@@ -18,6 +18,7 @@ use flash_loan::program::FlashLoan;
 /// 4. Calculate profit after repaying flashloan
 /// 5. Distribute profits (40% creator, 40% executor, 20% treasury)
 /// 6. Update strategy metrics via CPI
+/// 7. Update executor stats
 #[derive(Accounts)]
 pub struct ExecuteStrategy<'info> {
     #[account(
@@ -37,6 +38,10 @@ pub struct ExecuteStrategy<'info> {
     #[account(mut)]
     pub strategy: Account<'info, strategy_registry::StrategyAccount>,
 
+    /// Strategy's rolling metrics (seeds validated by strategy-registry)
+    #[account(mut)]
+    pub strategy_metrics: Account<'info, strategy_registry::StrategyMetrics>,
+
     /// Strategy creator (receives 40% of profit)
     #[account(mut)]
     pub creator: SystemAccount<'info>,
@@ -45,6 +50,16 @@ pub struct ExecuteStrategy<'info> {
     #[account(mut)]
     pub executor: Signer<'info>,
 
+    /// Per-executor statistics (created on first execution, paid by executor)
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = ExecutorStats::LEN,
+        seeds = [ExecutorStats::SEEDS_PREFIX, executor.key().as_ref()],
+        bump
+    )]
+    pub executor_stats: Account<'info, ExecutorStats>,
+
     /// Treasury (receives 20% of profit) - DAO Treasury PDA
     #[account(
         mut,
@@ -58,6 +73,14 @@ pub struct ExecuteStrategy<'info> {
     /// Strategy registry program for CPI
     pub strategy_registry_program: Program<'info, strategy_registry::program::StrategyRegistry>,
 
+    /// Strategy registry admin config (authorizes vault PDA as metrics writer)
+    #[account(
+        seeds = [strategy_registry::CONFIG_SEED],
+        bump = registry_config.bump,
+        seeds::program = strategy_registry_program.key()
+    )]
+    pub registry_config: Account<'info, strategy_registry::AdminConfig>,
+
     /// Flash loan pool account
     #[account(mut)]
     pub flash_loan_pool: Account<'info, flash_loan::FlashLoanPool>,
@@ -104,13 +127,41 @@ pub fn handler(
     let vault = &mut ctx.accounts.vault;
     let profit_config = &ctx.accounts.profit_config;
     let strategy = &ctx.accounts.strategy;
+    let clock = Clock::get()?;
+
+    require!(
+        !vault.pause.is_paused(PAUSE_EXECUTE, clock.unix_timestamp),
+        ExecutionError::ProgramPaused
+    );
+
+    // Validate strategy is not paused by creator or governance
+    require!(
+        strategy.status != strategy_registry::StrategyStatus::Paused,
+        ExecutionError::StrategyPaused
+    );
 
-    // Validate strategy is approved
+    // Validate strategy is approved (also excludes Retired)
     require!(
         strategy.is_executable(),
         ExecutionError::StrategyNotApproved
     );
 
+    // Validate the borrow against the governance-approved cap
+    require!(
+        strategy.allows_borrow(borrow_amount),
+        ExecutionError::BorrowCapExceeded
+    );
+
+    // Validate the strategy's rate limits before borrowing
+    require!(
+        !strategy.is_cooling_down(clock.slot),
+        ExecutionError::ExecutionCooldown
+    );
+    require!(
+        !strategy.epoch_limit_reached(clock.epoch),
+        ExecutionError::EpochExecutionLimit
+    );
+
     // Step 1: Borrow from flash loan pool via CPI
     borrow_from_flash_loan_pool(
         &ctx.accounts.flash_loan_program.to_account_info(),
@@ -128,7 +179,8 @@ pub fn handler(
     msg!("Flashloan fee: {} lamports", flashloan_fee);
 
     // Step 2: Execute mock arbitrage (SOL → USDC → SOL)
-    let final_amount = execute_mock_arbitrage(borrow_amount)?;
+    // Each hop is bounded by the strategy's max_slippage
+    let final_amount = execute_mock_arbitrage(borrow_amount, strategy)?;
     msg!("Arbitrage result: {} lamports", final_amount);
 
     // Step 3: Repay flash loan via CPI
@@ -167,6 +219,12 @@ pub fn handler(
         ExecutionError::SlippageExceeded
     );
 
+    // Validate strategy's own profit threshold (bps of borrowed amount)
+    require!(
+        net_profit >= strategy.min_profit_for(borrow_amount),
+        ExecutionError::ProfitBelowThreshold
+    );
+
     msg!("Gross profit: {} lamports", gross_profit);
     msg!("Net profit: {} lamports", net_profit);
 
@@ -242,10 +300,24 @@ pub fn handler(
     update_strategy_metrics(
         ctx.accounts.strategy_registry_program.to_account_info(),
         ctx.accounts.strategy.to_account_info(),
+        ctx.accounts.strategy_metrics.to_account_info(),
+        ctx.accounts.registry_config.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
         ctx.accounts.executor.to_account_info(),
         net_profit,
+        true,
+        borrow_amount,
+        vault_bump,
     )?;
 
+    // Step 8: Update executor stats
+    let executor_stats = &mut ctx.accounts.executor_stats;
+    if executor_stats.executor == Pubkey::default() {
+        executor_stats.executor = ctx.accounts.executor.key();
+        executor_stats.bump = ctx.bumps.executor_stats;
+    }
+    executor_stats.record_execution(strategy.key(), executor_share, clock.slot)?;
+
     // Emit event
     emit!(StrategyExecuted {
         strategy: strategy.key(),
@@ -257,7 +329,7 @@ pub fn handler(
         creator_share,
         executor_share,
         treasury_share,
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: clock.unix_timestamp,
     });
 
     msg!("Strategy executed successfully!");
@@ -341,26 +413,28 @@ fn repay_to_flash_loan_pool<'info>(
 
 /// Execute mock arbitrage: SOL → USDC → SOL with 8% profit margin
 ///
-/// This simulates the following trade with simplified calculations:
-/// 1. Borrow 10 SOL
-/// 2. Apply 8% profit directly (10 * 1.08 = 10.8 SOL)
-/// 3. Result: 10.8 SOL
+/// Simulates two swap hops:
+/// 1. SOL → USDC on DEX A (1 SOL = 100 USDC)
+/// 2. USDC → SOL on DEX B (100 USDC = 1.08 SOL)
 ///
-/// This avoids large number issues from mock exchange rates while
-/// demonstrating the arbitrage concept clearly.
-fn execute_mock_arbitrage(input_sol: u64) -> Result<u64> {
-    // Calculate 8% profit directly
-    // Formula: output = input * 108 / 100
-    let profit_multiplier_numerator = 108_u64;
-    let profit_multiplier_denominator = 100_u64;
-
-    let output_sol = input_sol
-        .checked_mul(profit_multiplier_numerator)
-        .ok_or(ExecutionError::ArithmeticOverflow)?
-        .checked_div(profit_multiplier_denominator)
+/// Each hop fills below its quote by the pool's price impact and reverts if
+/// the fill is more than the strategy's max_slippage below the quote.
+fn execute_mock_arbitrage(
+    input_sol: u64,
+    strategy: &strategy_registry::StrategyAccount,
+) -> Result<u64> {
+    // USDC side of each pool holds the reserve's value at the DEX A rate
+    let usdc_reserve = MOCK_POOL_SOL_RESERVE
+        .checked_mul(100)
         .ok_or(ExecutionError::ArithmeticOverflow)?;
 
-    msg!("Mock arbitrage: {} lamports → {} lamports (8% profit)", input_sol, output_sol);
+    // Hop 1: SOL → USDC (DEX A)
+    let usdc_amount = execute_mock_swap(input_sol, 100, 1, MOCK_POOL_SOL_RESERVE, strategy)?;
+
+    // Hop 2: USDC → SOL (DEX B)
+    let output_sol = execute_mock_swap(usdc_amount, 108, 10_000, usdc_reserve, strategy)?;
+
+    msg!("Mock arbitrage: {} lamports → {} lamports", input_sol, output_sol);
 
     // Validate we made profit
     require!(
@@ -371,6 +445,41 @@ fn execute_mock_arbitrage(input_sol: u64) -> Result<u64> {
     Ok(output_sol)
 }
 
+/// Execute a single mock swap hop
+///
+/// The quote is the output at the pool's spot rate. The fill is the quote
+/// reduced by constant-product price impact against `input_reserve`.
+/// The minimum output is derived from the quote and the strategy's max_slippage;
+/// a real DEX CPI receives min_output as its limit.
+fn execute_mock_swap(
+    input_amount: u64,
+    rate_numerator: u64,
+    rate_denominator: u64,
+    input_reserve: u64,
+    strategy: &strategy_registry::StrategyAccount,
+) -> Result<u64> {
+    let quoted_output = input_amount
+        .checked_mul(rate_numerator)
+        .ok_or(ExecutionError::ArithmeticOverflow)?
+        .checked_div(rate_denominator)
+        .ok_or(ExecutionError::ArithmeticOverflow)?;
+
+    // output = quote * reserve / (reserve + input)
+    let output = (quoted_output as u128)
+        .checked_mul(input_reserve as u128)
+        .and_then(|scaled| scaled.checked_div(input_reserve as u128 + input_amount as u128))
+        .and_then(|output| u64::try_from(output).ok())
+        .ok_or(ExecutionError::ArithmeticOverflow)?;
+
+    let min_output = strategy.min_output_for(quoted_output);
+    require!(
+        output >= min_output,
+        ExecutionError::SlippageExceeded
+    );
+
+    Ok(output)
+}
+
 /// Transfer lamports from vault PDA to recipient
 ///
 /// Manual lamport transfer is required because vault is a PDA with data,
@@ -404,22 +513,41 @@ fn transfer_lamports<'info>(
 }
 
 /// Update strategy metrics via CPI to strategy-registry program
-fn update_strategy_metrics<'info>(
+/// The vault PDA signs as the registry's authorized execution authority
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn update_strategy_metrics<'info>(
     strategy_registry_program: AccountInfo<'info>,
     strategy_account: AccountInfo<'info>,
+    strategy_metrics: AccountInfo<'info>,
+    registry_config: AccountInfo<'info>,
+    vault_account: AccountInfo<'info>,
     executor_account: AccountInfo<'info>,
     profit: u64,
+    success: bool,
+    volume_borrowed: u64,
+    vault_bump: u8,
 ) -> Result<()> {
     // Build CPI context
     let cpi_accounts = strategy_registry::cpi::accounts::UpdateMetrics {
         strategy: strategy_account,
+        strategy_metrics,
+        admin_config: registry_config,
+        execution_authority: vault_account,
         executor: executor_account,
     };
 
-    let cpi_ctx = CpiContext::new(strategy_registry_program, cpi_accounts);
+    let vault_seed = ExecutionVault::SEEDS_PREFIX;
+    let bump = &[vault_bump];
+    let signer_seeds: &[&[&[u8]]] = &[&[vault_seed, bump]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        strategy_registry_program,
+        cpi_accounts,
+        signer_seeds,
+    );
 
     // Call update_metrics instruction
-    strategy_registry::cpi::update_metrics(cpi_ctx, profit, true)?;
+    strategy_registry::cpi::update_metrics(cpi_ctx, profit, success, volume_borrowed)?;
 
     msg!("Updated strategy metrics via CPI");
 
@@ -47,6 +47,7 @@ pub fn handler(ctx: Context<InitializeVault>) -> Result<()> {
     vault.total_fees_collected = 0;
     vault.total_executions = 0;
     vault.total_profit_distributed = 0;
+    vault.pause = PauseFlags::default();
     vault.bump = ctx.bumps.vault;
 
     // Initialize profit config with default 40/40/20 split
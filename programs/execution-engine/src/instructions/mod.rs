@@ -1,5 +1,13 @@
 pub mod initialize_vault;
 pub mod execute_strategy;
+pub mod report_failed_attempt;
+pub mod set_guardian;
+pub mod set_pause;
 
+// Every module defines its own `handler`, called through its module path
+#[allow(ambiguous_glob_reexports)]
 pub use initialize_vault::*;
 pub use execute_strategy::*;
+pub use report_failed_attempt::*;
+pub use set_guardian::*;
+pub use set_pause::*;
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::error::ExecutionError;
+use crate::instructions::execute_strategy::update_strategy_metrics;
+
+/// Report a failed execution attempt (executor-signed attestation)
+///
+/// A failed execute_strategy reverts entirely, so failures are recorded
+/// through this separate path. Metrics are updated via CPI with success=false.
+/// Reporters must hold an ExecutorStats record with at least one successful
+/// execution of this strategy, so fresh keypairs cannot sidestep the
+/// per-executor rate limit and executors cannot smear strategies they never ran.
+#[derive(Accounts)]
+pub struct ReportFailedAttempt<'info> {
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = FailureReport::LEN,
+        seeds = [
+            FailureReport::SEEDS_PREFIX,
+            strategy.key().as_ref(),
+            executor.key().as_ref()
+        ],
+        bump
+    )]
+    pub failure_report: Account<'info, FailureReport>,
+
+    /// Execution vault (signs metrics CPI as the registry's execution authority)
+    #[account(
+        seeds = [ExecutionVault::SEEDS_PREFIX],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ExecutionVault>,
+
+    /// Strategy account from strategy-registry program
+    #[account(mut)]
+    pub strategy: Account<'info, strategy_registry::StrategyAccount>,
+
+    /// Strategy's rolling metrics (seeds validated by strategy-registry)
+    #[account(mut)]
+    pub strategy_metrics: Account<'info, strategy_registry::StrategyMetrics>,
+
+    /// Executor attesting the failed attempt
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// Reporter's on-chain record (must have executed this strategy successfully)
+    #[account(
+        seeds = [ExecutorStats::SEEDS_PREFIX, executor.key().as_ref()],
+        bump = executor_stats.bump,
+        constraint = executor_stats.executions_for(&strategy.key()) > 0 @ ExecutionError::UnregisteredExecutor
+    )]
+    pub executor_stats: Account<'info, ExecutorStats>,
+
+    /// Strategy registry program for CPI
+    pub strategy_registry_program: Program<'info, strategy_registry::program::StrategyRegistry>,
+
+    /// Strategy registry admin config (authorizes vault PDA as metrics writer)
+    #[account(
+        seeds = [strategy_registry::CONFIG_SEED],
+        bump = registry_config.bump,
+        seeds::program = strategy_registry_program.key()
+    )]
+    pub registry_config: Account<'info, strategy_registry::AdminConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Event emitted when a failed attempt is recorded
+#[event]
+pub struct FailedAttemptReported {
+    pub strategy: Pubkey,
+    pub executor: Pubkey,
+    pub error_code: u32,
+    pub report_count: u64,
+    pub slot: u64,
+}
+
+pub fn handler(ctx: Context<ReportFailedAttempt>, error_code: u32) -> Result<()> {
+    let report = &mut ctx.accounts.failure_report;
+    let clock = Clock::get()?;
+
+    require!(
+        !ctx.accounts.vault.pause.is_paused(PAUSE_REPORT, clock.unix_timestamp),
+        ExecutionError::ProgramPaused
+    );
+
+    // Only executable strategies accumulate metrics
+    require!(
+        ctx.accounts.strategy.is_executable(),
+        ExecutionError::StrategyNotApproved
+    );
+
+    // Rate limit: one report per executor per strategy every N slots
+    if report.report_count > 0 {
+        let next_allowed_slot = report
+            .last_report_slot
+            .checked_add(MIN_SLOTS_BETWEEN_FAILURE_REPORTS)
+            .ok_or(ExecutionError::ArithmeticOverflow)?;
+        require!(
+            clock.slot >= next_allowed_slot,
+            ExecutionError::FailureReportTooFrequent
+        );
+    } else {
+        report.strategy = ctx.accounts.strategy.key();
+        report.executor = ctx.accounts.executor.key();
+        report.bump = ctx.bumps.failure_report;
+    }
+
+    report.last_report_slot = clock.slot;
+    report.report_count = report
+        .report_count
+        .checked_add(1)
+        .ok_or(ExecutionError::ArithmeticOverflow)?;
+
+    let report_count = report.report_count;
+
+    // Record failure in strategy metrics via CPI
+    update_strategy_metrics(
+        ctx.accounts.strategy_registry_program.to_account_info(),
+        ctx.accounts.strategy.to_account_info(),
+        ctx.accounts.strategy_metrics.to_account_info(),
+        ctx.accounts.registry_config.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        ctx.accounts.executor.to_account_info(),
+        0,
+        false,
+        0,
+        ctx.accounts.vault.bump,
+    )?;
+
+    emit!(FailedAttemptReported {
+        strategy: ctx.accounts.strategy.key(),
+        executor: ctx.accounts.executor.key(),
+        error_code,
+        report_count,
+        slot: clock.slot,
+    });
+
+    msg!("Failed attempt reported: error_code={}, reports={}", error_code, report_count);
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::ExecutionError;
+
+/// Set the guardian allowed to pause the engine temporarily
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(
+        mut,
+        seeds = [ExecutionVault::SEEDS_PREFIX],
+        bump = vault.bump,
+        constraint = vault.authority == authority.key() @ ExecutionError::UnauthorizedPauser
+    )]
+    pub vault: Account<'info, ExecutionVault>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGuardian>, guardian: Pubkey, pause_seconds: i64) -> Result<()> {
+    require!(
+        PauseFlags::is_valid_guardian_window(pause_seconds),
+        ExecutionError::InvalidGuardianPause
+    );
+
+    ctx.accounts.vault.pause.set_guardian(guardian, pause_seconds);
+
+    msg!("Engine guardian set to {} ({}s pauses)", guardian, pause_seconds);
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::constants::*;
+use crate::error::ExecutionError;
+use dao_governance::GOVERNANCE_SEED;
+
+/// Pause or unpause engine instruction groups (DAO governance or guardian)
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        mut,
+        seeds = [ExecutionVault::SEEDS_PREFIX],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, ExecutionVault>,
+
+    /// DAO governance PDA (no expiry, signs via a PauseChange proposal)
+    /// or guardian (expires after its pause window)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPause>, groups: u8) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    require!(groups & !PAUSE_ALL == 0, ExecutionError::InvalidPauseGroups);
+
+    let (governance, _) = Pubkey::find_program_address(&[GOVERNANCE_SEED], &dao_governance::ID);
+
+    if authority == governance {
+        vault.pause.set_by_admin(groups);
+    } else if vault.pause.is_guardian(&authority) {
+        vault.pause.set_by_guardian(groups, clock.unix_timestamp);
+    } else {
+        return err!(ExecutionError::UnauthorizedPauser);
+    }
+
+    msg!(
+        "Engine pause updated: admin={:#04b}, guardian={:#04b} until {}",
+        vault.pause.admin_paused,
+        vault.pause.guardian_paused,
+        vault.pause.guardian_expires_at
+    );
+
+    Ok(())
+}
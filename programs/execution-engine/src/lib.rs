@@ -53,7 +53,7 @@ pub mod execution_engine {
     /// 2. Execute multi-hop arbitrage (SOL → USDC → SOL)
     /// 3. Repay flashloan + fee
     /// 4. Distribute profit (40% creator, 40% executor, 20% treasury)
-    /// 5. Update strategy metrics via CPI
+    /// 5. Update strategy metrics via CPI and the executor's ExecutorStats
     ///
     /// # Arguments
     /// * `borrow_amount` - Amount of SOL to borrow (in lamports)
@@ -63,7 +63,12 @@ pub mod execution_engine {
     /// * `StrategyNotApproved` - Strategy must be approved first
     /// * `InsufficientVaultLiquidity` - Vault doesn't have enough SOL
     /// * `NegativeProfit` - Execution would result in loss
-    /// * `SlippageExceeded` - Profit below min_profit threshold
+    /// * `SlippageExceeded` - Profit below min_profit, or a swap hop exceeded the strategy's max_slippage
+    /// * `ProfitBelowThreshold` - Profit below the strategy's profit_threshold (bps of borrow_amount)
+    /// * `ExecutionCooldown` - Strategy's min_slots_between_executions has not elapsed
+    /// * `EpochExecutionLimit` - Strategy's max_executions_per_epoch reached
+    /// * `BorrowCapExceeded` - borrow_amount above the strategy's max_borrow
+    /// * `ProgramPaused` - Execution paused by the vault authority or guardian
     pub fn execute_strategy(
         ctx: Context<ExecuteStrategy>,
         borrow_amount: u64,
@@ -71,6 +76,40 @@ pub mod execution_engine {
     ) -> Result<()> {
         instructions::execute_strategy::handler(ctx, borrow_amount, min_profit)
     }
+
+    /// Report a failed execution attempt (executor-signed attestation)
+    ///
+    /// A reverted execute_strategy leaves no on-chain trace, so executors
+    /// attest failures here to keep the strategy's success rate honest.
+    /// Rate limited to one report per (strategy, executor) every
+    /// MIN_SLOTS_BETWEEN_FAILURE_REPORTS slots; the executor needs an
+    /// ExecutorStats record with at least one successful execution.
+    ///
+    /// # Arguments
+    /// * `error_code` - Error code observed off-chain (for analytics only)
+    pub fn report_failed_attempt(
+        ctx: Context<ReportFailedAttempt>,
+        error_code: u32,
+    ) -> Result<()> {
+        instructions::report_failed_attempt::handler(ctx, error_code)
+    }
+
+    /// Set the guardian allowed to pause the engine temporarily, and for how
+    /// long each guardian pause lasts (vault authority only, at most 24 hours)
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey, pause_seconds: i64) -> Result<()> {
+        instructions::set_guardian::handler(ctx, guardian, pause_seconds)
+    }
+
+    /// Pause execution and/or failure reports (PAUSE_* bitmask, 0 unpauses)
+    ///
+    /// The DAO governance PDA's pause has no expiry; the guardian's pause
+    /// lapses after the guardian's window.
+    ///
+    /// # Arguments
+    /// * `groups` - Bitmask of PAUSE_EXECUTE / PAUSE_REPORT
+    pub fn set_pause(ctx: Context<SetPause>, groups: u8) -> Result<()> {
+        instructions::set_pause::handler(ctx, groups)
+    }
 }
 
 #[derive(Accounts)]
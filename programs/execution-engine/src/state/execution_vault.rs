@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::PauseFlags;
 
 /// ExecutionVault holds SOL for mock flashloans
 ///
@@ -31,6 +32,10 @@ pub struct ExecutionVault {
 
     /// Bump seed for PDA derivation
     pub bump: u8,
+
+    /// Circuit breaker for execution and failure reports (DAO governance is the pause admin)
+    /// Appended after bump so vaults created before it keep their layout
+    pub pause: PauseFlags,
 }
 
 impl ExecutionVault {
@@ -42,7 +47,8 @@ impl ExecutionVault {
         8 + // total_fees_collected
         8 + // total_executions
         8 + // total_profit_distributed
-        1; // bump
+        1 + // bump
+        PauseFlags::LEN; // pause
 
     /// PDA seeds for vault account
     pub const SEEDS_PREFIX: &'static [u8] = b"execution_vault";
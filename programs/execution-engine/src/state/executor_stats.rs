@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_TRACKED_STRATEGIES_PER_EXECUTOR;
+use crate::error::ExecutionError;
+
+/// ExecutorStats tracks on-chain performance of a single executor
+///
+/// Updated by execute_strategy on every successful execution, so reputation
+/// and rewards can be derived without trusting the off-chain executor_stats table.
+#[account]
+pub struct ExecutorStats {
+    /// Executor these stats belong to
+    pub executor: Pubkey,
+
+    /// Total number of successful executions
+    pub total_executions: u64,
+
+    /// Total executor share earned (in lamports)
+    pub total_earned: u64,
+
+    /// Slot of the most recent execution
+    pub last_execution_slot: u64,
+
+    /// Per-strategy execution counts (bounded by MAX_TRACKED_STRATEGIES_PER_EXECUTOR)
+    pub strategy_breakdown: Vec<StrategyExecutionCount>,
+
+    /// Executions of strategies not tracked once the breakdown is full
+    pub untracked_executions: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StrategyExecutionCount {
+    /// Strategy account executed
+    pub strategy: Pubkey,
+
+    /// Number of successful executions of this strategy
+    pub executions: u64,
+}
+
+impl StrategyExecutionCount {
+    pub const LEN: usize = 32 + // strategy
+        8; // executions
+}
+
+impl ExecutorStats {
+    /// Calculate space required for account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // executor
+        8 + // total_executions
+        8 + // total_earned
+        8 + // last_execution_slot
+        4 + (StrategyExecutionCount::LEN * MAX_TRACKED_STRATEGIES_PER_EXECUTOR) + // strategy_breakdown
+        8 + // untracked_executions
+        1; // bump
+
+    /// PDA seeds for executor stats account
+    pub const SEEDS_PREFIX: &'static [u8] = b"executor_stats";
+
+    /// Record a successful execution of `strategy` earning `earned` lamports
+    pub fn record_execution(&mut self, strategy: Pubkey, earned: u64, slot: u64) -> Result<()> {
+        self.total_executions = self
+            .total_executions
+            .checked_add(1)
+            .ok_or(ExecutionError::ArithmeticOverflow)?;
+
+        self.total_earned = self
+            .total_earned
+            .checked_add(earned)
+            .ok_or(ExecutionError::ArithmeticOverflow)?;
+
+        self.last_execution_slot = slot;
+
+        if let Some(entry) = self
+            .strategy_breakdown
+            .iter_mut()
+            .find(|entry| entry.strategy == strategy)
+        {
+            entry.executions = entry
+                .executions
+                .checked_add(1)
+                .ok_or(ExecutionError::ArithmeticOverflow)?;
+        } else if self.strategy_breakdown.len() < MAX_TRACKED_STRATEGIES_PER_EXECUTOR {
+            self.strategy_breakdown.push(StrategyExecutionCount {
+                strategy,
+                executions: 1,
+            });
+        } else {
+            self.untracked_executions = self
+                .untracked_executions
+                .checked_add(1)
+                .ok_or(ExecutionError::ArithmeticOverflow)?;
+        }
+
+        Ok(())
+    }
+
+    /// Number of successful executions of `strategy` (0 if untracked)
+    pub fn executions_for(&self, strategy: &Pubkey) -> u64 {
+        self.strategy_breakdown
+            .iter()
+            .find(|entry| entry.strategy == *strategy)
+            .map(|entry| entry.executions)
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+/// FailureReport rate-limits failed-attempt attestations
+///
+/// One account per (strategy, executor). Executors pay rent for their own
+/// report account, and can report at most once per MIN_SLOTS_BETWEEN_FAILURE_REPORTS.
+/// Only executors with a successful execution (ExecutorStats) may report.
+#[account]
+pub struct FailureReport {
+    /// Strategy the attempts were made against
+    pub strategy: Pubkey,
+
+    /// Executor attesting the failures
+    pub executor: Pubkey,
+
+    /// Slot of the most recent report
+    pub last_report_slot: u64,
+
+    /// Total failed attempts reported by this executor for this strategy
+    pub report_count: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl FailureReport {
+    /// Calculate space required for account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // strategy
+        32 + // executor
+        8 + // last_report_slot
+        8 + // report_count
+        1; // bump
+
+    /// PDA seeds for failure report account
+    pub const SEEDS_PREFIX: &'static [u8] = b"failure_report";
+}
@@ -1,5 +1,12 @@
 pub mod execution_vault;
 pub mod profit_config;
+pub mod failure_report;
+pub mod executor_stats;
 
 pub use execution_vault::*;
 pub use profit_config::*;
+pub use failure_report::*;
+pub use executor_stats::*;
+
+// Circuit breaker shared with the other MEVrebels programs
+pub use pause_flags::PauseFlags;
@@ -23,3 +23,21 @@ pub const MIN_BORROW_AMOUNT: u64 = 10_000_000; // 0.01 SOL
 /// Maximum borrow amount (1000 SOL)
 /// Limits risk per transaction
 pub const MAX_BORROW_AMOUNT: u64 = 1_000_000_000_000; // 1000 SOL
+
+/// DAO governance program, whose governance PDA holds the pool's no-expiry pause
+pub const DAO_GOVERNANCE_PROGRAM_ID: Pubkey = pubkey!("RECwcpcHwBeDAV7tBvUuhJzsih16BaveZRC74kbBkSS");
+
+/// Seed of the DAO governance PDA (signs PauseChange proposals via CPI)
+pub const GOVERNANCE_SEED: &[u8] = b"governance";
+
+/// Pause group: flash_borrow (flash_repay is never paused)
+#[constant]
+pub const PAUSE_BORROW: u8 = 1 << 0;
+
+/// Pause group: deposit_liquidity
+#[constant]
+pub const PAUSE_DEPOSIT: u8 = 1 << 1;
+
+/// Every flash loan pause group
+#[constant]
+pub const PAUSE_ALL: u8 = PAUSE_BORROW | PAUSE_DEPOSIT;
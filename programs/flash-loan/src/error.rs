@@ -31,4 +31,16 @@ pub enum FlashLoanError {
 
     #[msg("Invalid token account provided")]
     InvalidTokenAccount,
+
+    #[msg("Pool epoch borrow cap exceeded")]
+    EpochBorrowCapExceeded,
+
+    #[msg("This instruction group is paused")]
+    ProgramPaused,
+
+    #[msg("Invalid pause groups")]
+    InvalidPauseGroups,
+
+    #[msg("Guardian pause window must be between 1 second and 24 hours")]
+    InvalidGuardianPause,
 }
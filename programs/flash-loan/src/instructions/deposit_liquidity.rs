@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
-use crate::{FlashLoanPool, FlashLoanError};
+use crate::{FlashLoanPool, FlashLoanError, PAUSE_DEPOSIT};
 
 #[derive(Accounts)]
 pub struct DepositLiquidity<'info> {
@@ -29,6 +29,11 @@ pub struct DepositLiquidity<'info> {
 }
 
 pub fn handler(ctx: Context<DepositLiquidity>, amount: u64) -> Result<()> {
+    require!(
+        !ctx.accounts.pool.pause.is_paused(PAUSE_DEPOSIT, Clock::get()?.unix_timestamp),
+        FlashLoanError::ProgramPaused
+    );
+
     // Transfer WSOL tokens from depositor to pool
     token::transfer(
         CpiContext::new(
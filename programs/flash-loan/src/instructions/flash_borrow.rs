@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
-use crate::{FlashLoanPool, FlashLoanError, MIN_BORROW_AMOUNT, MAX_BORROW_AMOUNT};
+use crate::{FlashLoanPool, FlashLoanError, MIN_BORROW_AMOUNT, MAX_BORROW_AMOUNT, PAUSE_BORROW};
 
 #[derive(Accounts)]
 pub struct FlashBorrow<'info> {
@@ -43,6 +43,12 @@ pub struct FlashBorrow<'info> {
 }
 
 pub fn handler(ctx: Context<FlashBorrow>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        !ctx.accounts.pool.pause.is_paused(PAUSE_BORROW, clock.unix_timestamp),
+        FlashLoanError::ProgramPaused
+    );
+
     // Validate borrow amount
     require!(
         amount >= MIN_BORROW_AMOUNT,
@@ -66,6 +72,9 @@ pub fn handler(ctx: Context<FlashBorrow>, amount: u64) -> Result<()> {
         FlashLoanError::InsufficientLiquidity
     );
 
+    // Enforce pool-level volume limit
+    pool.record_borrow(amount, clock.epoch)?;
+
     // Mark flash loan as active
     pool.flash_loan_active = true;
     pool.active_borrow_amount = amount;
@@ -3,7 +3,7 @@ use anchor_spl::{
     token::{Token, TokenAccount, Mint},
     associated_token::AssociatedToken,
 };
-use crate::{FlashLoanPool, FlashLoanError, MAX_FEE_BPS, WSOL_MINT};
+use crate::{FlashLoanPool, FlashLoanError, PauseFlags, MAX_FEE_BPS, WSOL_MINT};
 
 #[derive(Accounts)]
 pub struct InitializePool<'info> {
@@ -57,6 +57,11 @@ pub fn handler(ctx: Context<InitializePool>, fee_bps: u16) -> Result<()> {
     pool.total_fees_collected = 0;
     pool.flash_loan_active = false;
     pool.active_borrow_amount = 0;
+    pool.admin = ctx.accounts.authority.key();
+    pool.epoch_borrow_cap = 0;
+    pool.borrow_epoch = 0;
+    pool.epoch_borrowed = 0;
+    pool.pause = PauseFlags::default();
     pool.bump = ctx.bumps.pool;
 
     msg!("Flash loan pool initialized with fee: {} bps", fee_bps);
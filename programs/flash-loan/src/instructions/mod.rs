@@ -2,8 +2,16 @@ pub mod initialize_pool;
 pub mod flash_borrow;
 pub mod flash_repay;
 pub mod deposit_liquidity;
+pub mod set_epoch_borrow_cap;
+pub mod set_guardian;
+pub mod set_pause;
 
+// Every module defines its own `handler`, called through its module path
+#[allow(ambiguous_glob_reexports)]
 pub use initialize_pool::*;
 pub use flash_borrow::*;
 pub use flash_repay::*;
 pub use deposit_liquidity::*;
+pub use set_epoch_borrow_cap::*;
+pub use set_guardian::*;
+pub use set_pause::*;
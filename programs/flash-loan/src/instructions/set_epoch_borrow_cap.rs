@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::{FlashLoanPool, FlashLoanError};
+
+#[derive(Accounts)]
+pub struct SetEpochBorrowCap<'info> {
+    #[account(
+        mut,
+        seeds = [FlashLoanPool::SEEDS_PREFIX],
+        bump = pool.bump,
+        has_one = admin @ FlashLoanError::Unauthorized
+    )]
+    pub pool: Account<'info, FlashLoanPool>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetEpochBorrowCap>, cap: u64) -> Result<()> {
+    ctx.accounts.pool.epoch_borrow_cap = cap;
+
+    msg!("Epoch borrow cap set to {} WSOL", cap);
+
+    Ok(())
+}
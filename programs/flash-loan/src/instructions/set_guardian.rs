@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::{FlashLoanPool, FlashLoanError, PauseFlags};
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(
+        mut,
+        seeds = [FlashLoanPool::SEEDS_PREFIX],
+        bump = pool.bump,
+        has_one = admin @ FlashLoanError::Unauthorized
+    )]
+    pub pool: Account<'info, FlashLoanPool>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGuardian>, guardian: Pubkey, pause_seconds: i64) -> Result<()> {
+    require!(
+        PauseFlags::is_valid_guardian_window(pause_seconds),
+        FlashLoanError::InvalidGuardianPause
+    );
+
+    ctx.accounts.pool.pause.set_guardian(guardian, pause_seconds);
+
+    msg!("Pool guardian set to {} ({}s pauses)", guardian, pause_seconds);
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{FlashLoanPool, FlashLoanError, DAO_GOVERNANCE_PROGRAM_ID, GOVERNANCE_SEED, PAUSE_ALL};
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        mut,
+        seeds = [FlashLoanPool::SEEDS_PREFIX],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, FlashLoanPool>,
+
+    /// DAO governance PDA (no expiry, signs via a PauseChange proposal)
+    /// or guardian (expires after its pause window)
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPause>, groups: u8) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    require!(groups & !PAUSE_ALL == 0, FlashLoanError::InvalidPauseGroups);
+
+    let (governance, _) = Pubkey::find_program_address(&[GOVERNANCE_SEED], &DAO_GOVERNANCE_PROGRAM_ID);
+
+    if authority == governance {
+        pool.pause.set_by_admin(groups);
+    } else if pool.pause.is_guardian(&authority) {
+        pool.pause.set_by_guardian(groups, clock.unix_timestamp);
+    } else {
+        return err!(FlashLoanError::Unauthorized);
+    }
+
+    msg!(
+        "Pool pause updated: admin={:#04b}, guardian={:#04b} until {}",
+        pool.pause.admin_paused,
+        pool.pause.guardian_paused,
+        pool.pause.guardian_expires_at
+    );
+
+    Ok(())
+}
@@ -64,4 +64,31 @@ pub mod flash_loan {
     pub fn deposit_liquidity(ctx: Context<DepositLiquidity>, amount: u64) -> Result<()> {
         instructions::deposit_liquidity::handler(ctx, amount)
     }
+
+    /// Set the pool's epoch borrow cap (pool admin only)
+    ///
+    /// Limits total volume lent per epoch across all borrowers.
+    ///
+    /// # Arguments
+    /// * `cap` - Max lamports lent per epoch (0 = unlimited)
+    pub fn set_epoch_borrow_cap(ctx: Context<SetEpochBorrowCap>, cap: u64) -> Result<()> {
+        instructions::set_epoch_borrow_cap::handler(ctx, cap)
+    }
+
+    /// Set the guardian allowed to pause the pool temporarily, and for how
+    /// long each guardian pause lasts (pool admin only, at most 24 hours)
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey, pause_seconds: i64) -> Result<()> {
+        instructions::set_guardian::handler(ctx, guardian, pause_seconds)
+    }
+
+    /// Pause borrow and/or deposit (PAUSE_* bitmask, 0 unpauses)
+    ///
+    /// The DAO governance PDA's pause has no expiry; the guardian's pause
+    /// lapses after the guardian's window. flash_repay is never paused.
+    ///
+    /// # Arguments
+    /// * `groups` - Bitmask of PAUSE_BORROW / PAUSE_DEPOSIT
+    pub fn set_pause(ctx: Context<SetPause>, groups: u8) -> Result<()> {
+        instructions::set_pause::handler(ctx, groups)
+    }
 }
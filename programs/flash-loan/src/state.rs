@@ -1,4 +1,8 @@
 use anchor_lang::prelude::*;
+use crate::FlashLoanError;
+
+// Circuit breaker shared with the other MEVrebels programs
+pub use pause_flags::PauseFlags;
 
 /// Flash Loan Pool State
 ///
@@ -33,6 +37,23 @@ pub struct FlashLoanPool {
 
     /// PDA bump
     pub bump: u8,
+
+    // Risk limits, appended after bump to leave the original pool layout intact
+
+    /// Pool admin (initializer), may adjust risk limits and set the guardian
+    pub admin: Pubkey,
+
+    /// Max total volume lent per epoch (0 = unlimited)
+    pub epoch_borrow_cap: u64,
+
+    /// Epoch that epoch_borrowed refers to
+    pub borrow_epoch: u64,
+
+    /// Total volume lent during borrow_epoch
+    pub epoch_borrowed: u64,
+
+    /// Circuit breaker for borrow and deposit (DAO governance is the pause admin)
+    pub pause: PauseFlags,
 }
 
 impl FlashLoanPool {
@@ -48,7 +69,12 @@ impl FlashLoanPool {
         8 +  // total_fees_collected
         1 +  // flash_loan_active
         8 +  // active_borrow_amount
-        1;   // bump
+        1 +  // bump
+        32 + // admin
+        8 +  // epoch_borrow_cap
+        8 +  // borrow_epoch
+        8 +  // epoch_borrowed
+        PauseFlags::LEN; // pause
 
     /// Calculate fee for given borrow amount
     pub fn calculate_fee(&self, amount: u64) -> Result<u64> {
@@ -60,6 +86,27 @@ impl FlashLoanPool {
         Ok(fee)
     }
 
+    /// Count `amount` against the epoch borrow cap (counter resets each epoch)
+    pub fn record_borrow(&mut self, amount: u64, epoch: u64) -> Result<()> {
+        if self.borrow_epoch != epoch {
+            self.borrow_epoch = epoch;
+            self.epoch_borrowed = 0;
+        }
+
+        let borrowed = self
+            .epoch_borrowed
+            .checked_add(amount)
+            .ok_or(FlashLoanError::ArithmeticOverflow)?;
+
+        require!(
+            self.epoch_borrow_cap == 0 || borrowed <= self.epoch_borrow_cap,
+            FlashLoanError::EpochBorrowCapExceeded
+        );
+
+        self.epoch_borrowed = borrowed;
+        Ok(())
+    }
+
     /// Validate repayment amount includes fee
     pub fn validate_repayment(&self, amount_borrowed: u64, amount_repaid: u64) -> Result<()> {
         let fee = self.calculate_fee(amount_borrowed)?;
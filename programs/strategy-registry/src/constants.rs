@@ -12,14 +12,78 @@ pub const STRATEGY_SEED: &[u8] = b"strategy";
 /// PDA seed for global config
 pub const CONFIG_SEED: &[u8] = b"config";
 
+/// PDA seed for per-strategy rolling metrics
+pub const METRICS_SEED: &[u8] = b"metrics";
+
+/// Number of buckets in the StrategyMetrics ring buffer (30 days of daily buckets)
+pub const METRICS_BUCKET_COUNT: usize = 30;
+
+/// Length of a metrics bucket in seconds (1 day)
+pub const METRICS_BUCKET_SECONDS: i64 = 86_400;
+
+/// PDA seed for per-creator strategy index
+pub const CREATOR_INDEX_SEED: &[u8] = b"creator_index";
+
+/// PDA seed for per-token-pair strategy index
+pub const PAIR_INDEX_SEED: &[u8] = b"pair_index";
+
+/// Strategies a new creator index has room for (it is reallocated to grow past this)
+pub const CREATOR_INDEX_INITIAL_CAPACITY: usize = 32;
+
+/// Maximum strategies listed in a token pair index
+/// (once full, unapproved strategies are left out or evicted first)
+pub const MAX_PAIR_INDEX_ENTRIES: usize = 64;
+
+/// PDA seed for strategy creation bond escrows
+pub const BOND_SEED: &[u8] = b"bond";
+
+/// PDA seed for strategy routes
+pub const ROUTE_SEED: &[u8] = b"route";
+
 /// PDA seed for strategy counter
 pub const COUNTER_SEED: &[u8] = b"counter";
 
+/// Pause group: create_strategy and create_strategy_auto
+#[constant]
+pub const PAUSE_CREATE: u8 = 1 << 0;
+
+/// Pause group: strategy updates (parameters, routes, limits, resume)
+#[constant]
+pub const PAUSE_UPDATE: u8 = 1 << 1;
+
+/// Pause group: approvals and rejections (admin and governance)
+#[constant]
+pub const PAUSE_APPROVAL: u8 = 1 << 2;
+
+/// Every registry pause group
+#[constant]
+pub const PAUSE_ALL: u8 = PAUSE_CREATE | PAUSE_UPDATE | PAUSE_APPROVAL;
+
+/// Default execution engine program (authorized to update strategy metrics)
+pub const DEFAULT_EXECUTION_ENGINE: Pubkey = pubkey!("REC2Aq9iAu4hu7efgJhtyFyWS1bSBDYgeoVXXQFtfpk");
+
+/// PDA seed of the execution engine's signing authority
+/// Must match execution_engine::ExecutionVault::SEEDS_PREFIX
+pub const EXECUTION_AUTHORITY_SEED: &[u8] = b"execution_vault";
+
+/// Wrapped SOL mint, the only mint the flash loan pool lends
+/// (routes must start and end here)
+pub const WSOL_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+
+/// Borrow cap given to new strategies (100 SOL), adjustable via set_max_borrow
+pub const DEFAULT_MAX_BORROW: u64 = 100_000_000_000;
+
 /// Maximum number of DEXs per strategy
 pub const MAX_DEXS: usize = 5;
 
 /// Maximum number of token pairs per strategy
 pub const MAX_TOKEN_PAIRS: usize = 3;
 
+/// Minimum hops in a route (buy and sell back)
+pub const MIN_ROUTE_HOPS: usize = 2;
+
+/// Maximum hops in a route (covers triangular routes and one extra leg)
+pub const MAX_ROUTE_HOPS: usize = 4;
+
 #[constant]
 pub const BASIS_POINTS: u16 = 10000;
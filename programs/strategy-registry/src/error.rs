@@ -31,4 +31,91 @@ pub enum StrategyError {
 
     #[msg("Strategy ID counter overflow")]
     StrategyIdOverflow,
+
+    #[msg("Only the strategy creator can perform this action")]
+    UnauthorizedCreator,
+
+    #[msg("Strategy is under emergency pause, only governance can resume it")]
+    EmergencyPauseActive,
+
+    #[msg("Strategy must be retired or rejected before it can be closed")]
+    StrategyNotClosable,
+
+    #[msg("Only the current admin can perform this action")]
+    UnauthorizedAdmin,
+
+    #[msg("Governance authority must be set to enable governance-only mode")]
+    GovernanceNotConfigured,
+
+    #[msg("Strategy index is full")]
+    IndexFull,
+
+    #[msg("A token pair index is missing for one of the strategy's token pairs")]
+    MissingPairIndex,
+
+    #[msg("Account is not a token pair index")]
+    InvalidPairIndex,
+
+    #[msg("Token pair index mints must be in canonical order (token_a < token_b)")]
+    NonCanonicalTokenPair,
+
+    #[msg("Creation bond stays locked while the strategy is pending")]
+    BondLocked,
+
+    #[msg("Creation bond has already been settled")]
+    BondAlreadySettled,
+
+    #[msg("Treasury must be set before configuring a creation bond")]
+    TreasuryNotConfigured,
+
+    #[msg("Forfeited creation bond must be settled to the treasury before closing")]
+    BondNotSettled,
+
+    #[msg("Route must have between MIN_ROUTE_HOPS and MAX_ROUTE_HOPS hops")]
+    InvalidRouteLength,
+
+    #[msg("Route hop cannot swap a mint into itself")]
+    InvalidRouteHop,
+
+    #[msg("Each route hop must take the previous hop's output mint as input")]
+    RouteNotContinuous,
+
+    #[msg("Route must end in the borrowed mint")]
+    RouteNotClosed,
+
+    #[msg("Strategy is cooling down after its last execution")]
+    ExecutionCooldown,
+
+    #[msg("Strategy reached its execution limit for this epoch")]
+    EpochExecutionLimit,
+
+    #[msg("Borrow amount exceeds the strategy's max borrow")]
+    BorrowCapExceeded,
+
+    #[msg("Max borrow must be greater than zero")]
+    InvalidMaxBorrow,
+
+    #[msg("This instruction group is paused")]
+    ProgramPaused,
+
+    #[msg("Invalid pause groups")]
+    InvalidPauseGroups,
+
+    #[msg("Unauthorized: signer is neither governance nor the guardian")]
+    UnauthorizedPauser,
+
+    #[msg("Strategy was modified after the approval was requested")]
+    StrategyVersionMismatch,
+
+    #[msg("Route must start from wrapped SOL (the flash-loaned mint)")]
+    RouteNotFromWsol,
+
+    #[msg("Route hop uses a DEX the strategy does not list")]
+    RouteDexNotAllowed,
+
+    #[msg("Route hop swaps a token pair the strategy does not list")]
+    RoutePairNotAllowed,
+
+    #[msg("Guardian pause window must be between 1 second and 24 hours")]
+    InvalidGuardianPause,
 }
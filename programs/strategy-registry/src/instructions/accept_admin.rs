@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::AdminConfig,
+};
+
+#[event]
+pub struct AdminTransferred {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Accept a pending admin nomination (step 2 of 2, pending admin only)
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub new_admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    let new_admin = ctx.accounts.new_admin.key();
+    let clock = Clock::get()?;
+
+    require!(
+        admin_config.pending_admin != Pubkey::default()
+            && admin_config.pending_admin == new_admin,
+        StrategyError::UnauthorizedAdmin
+    );
+
+    let previous_admin = admin_config.admin;
+    admin_config.admin = new_admin;
+    admin_config.pending_admin = Pubkey::default();
+
+    emit!(AdminTransferred {
+        previous_admin,
+        new_admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Admin transferred: {} -> {}", previous_admin, new_admin);
+
+    Ok(())
+}
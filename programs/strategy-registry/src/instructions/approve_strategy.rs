@@ -2,14 +2,27 @@ use anchor_lang::prelude::*;
 use crate::{
     constants::*,
     error::StrategyError,
+    instructions::sync_strategy_index::sync_pair_indexes,
     state::*,
 };
 
+/// Which authority approved or rejected a strategy
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ApprovalPath {
+    /// Direct admin decision
+    Admin,
+    /// Passed DAO proposal (governance PDA signed via CPI)
+    Governance,
+}
+
 #[event]
 pub struct StrategyApproved {
     pub strategy_id: u64,
     pub creator: Pubkey,
     pub approver: Pubkey,
+    pub path: ApprovalPath,
+    pub version: u32,
+    pub max_borrow: u64,
     pub timestamp: i64,
 }
 
@@ -18,22 +31,90 @@ pub struct StrategyRejected {
     pub strategy_id: u64,
     pub creator: Pubkey,
     pub rejecter: Pubkey,
+    pub path: ApprovalPath,
     pub timestamp: i64,
 }
 
-/// Admin config account for hackathon (simplified governance)
+/// Admin config account (approval authorities)
 /// PDA seeds: [b"config"]
 #[account]
 pub struct AdminConfig {
+    /// Current admin (can approve unless governance_only is set)
     pub admin: Pubkey,
+
     pub bump: u8,
+
+    // Appended after the first deployment (keeps admin and bump in place)
+
+    /// Admin nominated by transfer_admin, must call accept_admin
+    /// (Pubkey::default() when no transfer is pending)
+    pub pending_admin: Pubkey,
+
+    /// DAO governance PDA allowed to approve/reject via execute_proposal
+    /// (Pubkey::default() when not configured)
+    pub governance: Pubkey,
+
+    /// When set, approvals and rejections must come from governance
+    pub governance_only: bool,
+
+    /// Program whose vault PDA may call update_metrics via CPI
+    pub execution_engine: Pubkey,
+
+    /// Lamports locked in escrow by create_strategy (0 disables the bond)
+    pub creation_bond: u64,
+
+    /// Receives bonds forfeited by rejected strategies
+    pub treasury: Pubkey,
+
+    /// Circuit breaker for registry instruction groups
+    pub pause: PauseFlags,
 }
 
 impl AdminConfig {
     pub const fn space() -> usize {
         8 +  // discriminator
         32 + // admin pubkey
-        1    // bump
+        1 +  // bump
+        32 + // pending_admin pubkey
+        32 + // governance pubkey
+        1 +  // governance_only
+        32 + // execution_engine program id
+        8 +  // creation_bond
+        32 + // treasury
+        PauseFlags::LEN // pause
+    }
+
+    /// Check if key is the configured governance authority
+    pub fn is_governance(&self, key: &Pubkey) -> bool {
+        self.governance != Pubkey::default() && *key == self.governance
+    }
+
+    /// Check if key can act as an admin-level authority (admin or governance)
+    pub fn is_authority(&self, key: &Pubkey) -> bool {
+        *key == self.admin || self.is_governance(key)
+    }
+
+    /// Check if key can change registry configuration
+    /// Only governance can once governance-only mode is enabled
+    /// (the DAO applies changes through RegistryConfig proposals)
+    pub fn can_configure(&self, key: &Pubkey) -> bool {
+        if self.governance_only {
+            self.is_governance(key)
+        } else {
+            self.is_authority(key)
+        }
+    }
+
+    /// Resolve which approval path a signer is using
+    /// Admin decisions are refused in governance-only mode
+    pub fn approval_path(&self, key: &Pubkey) -> Result<ApprovalPath> {
+        if self.is_governance(key) {
+            Ok(ApprovalPath::Governance)
+        } else if *key == self.admin && !self.governance_only {
+            Ok(ApprovalPath::Admin)
+        } else {
+            err!(StrategyError::UnauthorizedApprover)
+        }
     }
 }
 
@@ -102,42 +183,63 @@ pub struct InitializeAdmin<'info> {
 pub fn initialize_admin(ctx: Context<InitializeAdmin>) -> Result<()> {
     let admin_config = &mut ctx.accounts.admin_config;
     admin_config.admin = ctx.accounts.admin.key();
+    admin_config.pending_admin = Pubkey::default();
+    admin_config.governance = Pubkey::default();
+    admin_config.governance_only = false;
+    admin_config.execution_engine = DEFAULT_EXECUTION_ENGINE;
+    admin_config.creation_bond = 0;
+    admin_config.treasury = Pubkey::default();
+    admin_config.pause = PauseFlags::default();
     admin_config.bump = ctx.bumps.admin_config;
 
     msg!("Admin initialized: {}", ctx.accounts.admin.key());
     Ok(())
 }
 
-pub fn approve_strategy(ctx: Context<ApproveStrategy>) -> Result<()> {
+pub fn approve_strategy(ctx: Context<ApproveStrategy>, version: u32) -> Result<()> {
     let strategy = &mut ctx.accounts.strategy;
     let clock = Clock::get()?;
 
-    // Verify admin authority
-    // For hackathon: Also allows DAO governance PDA to approve via CPI
-    // The governance PDA will sign the transaction when executing approved proposals
     require!(
-        ctx.accounts.admin.key() == ctx.accounts.admin_config.admin,
-        StrategyError::UnauthorizedApprover
+        !ctx.accounts.admin_config.pause.is_paused(PAUSE_APPROVAL, clock.unix_timestamp),
+        StrategyError::ProgramPaused
     );
 
+    // Verify approval authority
+    // The DAO governance PDA signs via CPI when executing passed proposals
+    let path = ctx.accounts.admin_config.approval_path(&ctx.accounts.admin.key())?;
+
     // Verify strategy is in Pending status
     require!(
         strategy.status == StrategyStatus::Pending,
         StrategyError::InvalidStatus
     );
 
+    // Approve only the parameters that were reviewed
+    // (a proposal binds the version it was opened against)
+    require!(
+        strategy.version == version,
+        StrategyError::StrategyVersionMismatch
+    );
+
     // Update status to Approved
     strategy.status = StrategyStatus::Approved;
 
+    // Refresh the executable flag in any pair indexes supplied as remaining accounts
+    sync_pair_indexes(strategy.key(), Some(&**strategy), &[], ctx.remaining_accounts)?;
+
     // Emit event
     emit!(StrategyApproved {
         strategy_id: strategy.strategy_id,
         creator: strategy.creator,
         approver: ctx.accounts.admin.key(),
+        path,
+        version: strategy.version,
+        max_borrow: strategy.max_borrow,
         timestamp: clock.unix_timestamp,
     });
 
-    msg!("Strategy approved: ID={}", strategy.strategy_id);
+    msg!("Strategy approved: ID={}, Path={:?}", strategy.strategy_id, path);
 
     Ok(())
 }
@@ -146,12 +248,14 @@ pub fn reject_strategy(ctx: Context<RejectStrategy>) -> Result<()> {
     let strategy = &mut ctx.accounts.strategy;
     let clock = Clock::get()?;
 
-    // Verify admin authority
     require!(
-        ctx.accounts.admin.key() == ctx.accounts.admin_config.admin,
-        StrategyError::UnauthorizedApprover
+        !ctx.accounts.admin_config.pause.is_paused(PAUSE_APPROVAL, clock.unix_timestamp),
+        StrategyError::ProgramPaused
     );
 
+    // Verify rejection authority
+    let path = ctx.accounts.admin_config.approval_path(&ctx.accounts.admin.key())?;
+
     // Verify strategy is in Pending status
     require!(
         strategy.status == StrategyStatus::Pending,
@@ -161,15 +265,19 @@ pub fn reject_strategy(ctx: Context<RejectStrategy>) -> Result<()> {
     // Update status to Rejected
     strategy.status = StrategyStatus::Rejected;
 
+    // Refresh the executable flag in any pair indexes supplied as remaining accounts
+    sync_pair_indexes(strategy.key(), Some(&**strategy), &[], ctx.remaining_accounts)?;
+
     // Emit event
     emit!(StrategyRejected {
         strategy_id: strategy.strategy_id,
         creator: strategy.creator,
         rejecter: ctx.accounts.admin.key(),
+        path,
         timestamp: clock.unix_timestamp,
     });
 
-    msg!("Strategy rejected: ID={}", strategy.strategy_id);
+    msg!("Strategy rejected: ID={}, Path={:?}", strategy.strategy_id, path);
 
     Ok(())
 }
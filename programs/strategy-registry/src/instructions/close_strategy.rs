@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::{
+        settle_bond::settle_creation_bond,
+        sync_strategy_index::sync_pair_indexes,
+    },
+    state::*,
+};
+
+#[event]
+pub struct StrategyClosed {
+    pub strategy_id: u64,
+    pub creator: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Close a retired (or rejected) strategy and return rent to the creator
+#[derive(Accounts)]
+pub struct CloseStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump,
+        has_one = creator @ StrategyError::UnauthorizedCreator,
+        close = creator
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    #[account(
+        mut,
+        seeds = [METRICS_SEED, strategy.key().as_ref()],
+        bump = strategy_metrics.bump,
+        close = creator
+    )]
+    pub strategy_metrics: Account<'info, StrategyMetrics>,
+
+    #[account(
+        mut,
+        seeds = [CREATOR_INDEX_SEED, creator.key().as_ref()],
+        bump = creator_index.bump
+    )]
+    pub creator_index: Account<'info, CreatorIndex>,
+
+    /// Creation bond escrow (rent returned to creator)
+    /// An open bond is refunded here; a rejected strategy's bond must go through settle_bond first
+    #[account(
+        mut,
+        seeds = [BOND_SEED, strategy.key().as_ref()],
+        bump = bond_escrow.bump,
+        close = creator
+    )]
+    pub bond_escrow: Account<'info, BondEscrow>,
+
+    /// CHECK: Strategy route PDA, closed here if a route was ever set (rent
+    /// returned to creator). Always required so a route cannot be orphaned.
+    #[account(
+        mut,
+        seeds = [ROUTE_SEED, strategy.key().as_ref()],
+        bump
+    )]
+    pub strategy_route: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseStrategy>) -> Result<()> {
+    let strategy = &ctx.accounts.strategy;
+    let clock = Clock::get()?;
+
+    require!(
+        strategy.is_closable(),
+        StrategyError::StrategyNotClosable
+    );
+
+    // Refund a bond nobody settled yet (forfeits need the treasury, via settle_bond)
+    if !ctx.accounts.bond_escrow.settled {
+        let forfeited = strategy.status == StrategyStatus::Rejected;
+        require!(
+            !forfeited || ctx.accounts.bond_escrow.amount == 0,
+            StrategyError::BondNotSettled
+        );
+        settle_creation_bond(
+            &mut ctx.accounts.bond_escrow,
+            &ctx.accounts.creator.to_account_info(),
+            forfeited,
+        )?;
+    }
+
+    let route_info = ctx.accounts.strategy_route.to_account_info();
+    if !route_info.data_is_empty() {
+        // Same as a `close = creator` constraint: refund rent, hand back to the system program
+        let rent = route_info.lamports();
+        route_info.sub_lamports(rent)?;
+        ctx.accounts.creator.add_lamports(rent)?;
+        route_info.assign(&System::id());
+        route_info.resize(0)?;
+    }
+
+    // Drop from discovery indexes (remaining accounts: one TokenPairIndex per pair)
+    ctx.accounts.creator_index.remove(&strategy.key());
+    sync_pair_indexes(strategy.key(), None, &strategy.token_pairs, ctx.remaining_accounts)?;
+
+    emit!(StrategyClosed {
+        strategy_id: strategy.strategy_id,
+        creator: strategy.creator,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Strategy closed: ID={}", strategy.strategy_id);
+
+    Ok(())
+}
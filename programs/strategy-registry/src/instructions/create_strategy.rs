@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use crate::{
     constants::*,
     error::StrategyError,
+    instructions::{sync_strategy_index::sync_pair_indexes, AdminConfig},
     state::*,
 };
 
@@ -16,6 +18,14 @@ pub struct StrategyCreated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct BondLocked {
+    pub strategy: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
 #[derive(Accounts)]
 #[instruction(strategy_id: u64)]
 pub struct CreateStrategy<'info> {
@@ -32,12 +42,135 @@ pub struct CreateStrategy<'info> {
     )]
     pub strategy: Account<'info, StrategyAccount>,
 
+    /// Rolling performance buckets for the strategy
+    #[account(
+        init,
+        payer = creator,
+        space = StrategyMetrics::LEN,
+        seeds = [METRICS_SEED, strategy.key().as_ref()],
+        bump
+    )]
+    pub strategy_metrics: Account<'info, StrategyMetrics>,
+
+    /// Creator's strategy list and ID counter (created with their first strategy)
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorIndex::space(CREATOR_INDEX_INITIAL_CAPACITY),
+        seeds = [CREATOR_INDEX_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_index: Account<'info, CreatorIndex>,
+
+    /// Global strategy counter
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = StrategyCounter::LEN,
+        seeds = [COUNTER_SEED],
+        bump
+    )]
+    pub strategy_counter: Account<'info, StrategyCounter>,
+
+    /// Escrow holding the creation bond
+    #[account(
+        init,
+        payer = creator,
+        space = BondEscrow::LEN,
+        seeds = [BOND_SEED, strategy.key().as_ref()],
+        bump
+    )]
+    pub bond_escrow: Account<'info, BondEscrow>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a strategy with the next ID from the creator's counter
+#[derive(Accounts)]
+pub struct CreateStrategyAuto<'info> {
+    /// Creator's strategy list and ID counter (must precede strategy, its seeds use the counter)
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorIndex::space(CREATOR_INDEX_INITIAL_CAPACITY),
+        seeds = [CREATOR_INDEX_SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_index: Account<'info, CreatorIndex>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = StrategyAccount::space(MAX_DEXS, MAX_TOKEN_PAIRS),
+        seeds = [
+            STRATEGY_SEED,
+            creator.key().as_ref(),
+            &creator_index.next_strategy_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    /// Rolling performance buckets for the strategy
+    #[account(
+        init,
+        payer = creator,
+        space = StrategyMetrics::LEN,
+        seeds = [METRICS_SEED, strategy.key().as_ref()],
+        bump
+    )]
+    pub strategy_metrics: Account<'info, StrategyMetrics>,
+
+    /// Global strategy counter
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = StrategyCounter::LEN,
+        seeds = [COUNTER_SEED],
+        bump
+    )]
+    pub strategy_counter: Account<'info, StrategyCounter>,
+
+    /// Escrow holding the creation bond
+    #[account(
+        init,
+        payer = creator,
+        space = BondEscrow::LEN,
+        seeds = [BOND_SEED, strategy.key().as_ref()],
+        bump
+    )]
+    pub bond_escrow: Account<'info, BondEscrow>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
     #[account(mut)]
     pub creator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
+/// Strategy parameters shared by create_strategy and create_strategy_auto
+pub(crate) struct StrategyParams {
+    pub strategy_id: u64,
+    pub dexs: Vec<DexType>,
+    pub token_pairs: Vec<TokenPair>,
+    pub profit_threshold: u16,
+    pub max_slippage: u16,
+}
+
 pub fn handler(
     ctx: Context<CreateStrategy>,
     strategy_id: u64,
@@ -46,9 +179,250 @@ pub fn handler(
     profit_threshold: u16,
     max_slippage: u16,
 ) -> Result<()> {
-    let strategy = &mut ctx.accounts.strategy;
+    let accounts = ctx.accounts;
+
+    require!(
+        !accounts.admin_config.pause.is_paused(PAUSE_CREATE, Clock::get()?.unix_timestamp),
+        StrategyError::ProgramPaused
+    );
+
+    accounts.strategy.bump = ctx.bumps.strategy;
+    accounts.strategy_metrics.bump = ctx.bumps.strategy_metrics;
+    accounts.creator_index.bump = ctx.bumps.creator_index;
+    accounts.strategy_counter.bump = ctx.bumps.strategy_counter;
+
+    grow_creator_index(&accounts.creator_index, &accounts.creator, &accounts.system_program)?;
+
+    lock_creation_bond(
+        &mut accounts.bond_escrow,
+        ctx.bumps.bond_escrow,
+        accounts.strategy.key(),
+        &accounts.creator,
+        &accounts.system_program,
+        accounts.admin_config.creation_bond,
+    )?;
+
+    initialize_strategy(
+        &mut accounts.strategy,
+        &mut accounts.strategy_metrics,
+        &mut accounts.creator_index,
+        &mut accounts.strategy_counter,
+        accounts.creator.key(),
+        StrategyParams {
+            strategy_id,
+            dexs,
+            token_pairs,
+            profit_threshold,
+            max_slippage,
+        },
+        ctx.remaining_accounts,
+    )
+}
+
+pub fn auto_handler(
+    ctx: Context<CreateStrategyAuto>,
+    dexs: Vec<DexType>,
+    token_pairs: Vec<TokenPair>,
+    profit_threshold: u16,
+    max_slippage: u16,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    require!(
+        !accounts.admin_config.pause.is_paused(PAUSE_CREATE, Clock::get()?.unix_timestamp),
+        StrategyError::ProgramPaused
+    );
+    let strategy_id = accounts.creator_index.next_strategy_id;
+
+    accounts.strategy.bump = ctx.bumps.strategy;
+    accounts.strategy_metrics.bump = ctx.bumps.strategy_metrics;
+    accounts.creator_index.bump = ctx.bumps.creator_index;
+    accounts.strategy_counter.bump = ctx.bumps.strategy_counter;
+
+    grow_creator_index(&accounts.creator_index, &accounts.creator, &accounts.system_program)?;
+
+    lock_creation_bond(
+        &mut accounts.bond_escrow,
+        ctx.bumps.bond_escrow,
+        accounts.strategy.key(),
+        &accounts.creator,
+        &accounts.system_program,
+        accounts.admin_config.creation_bond,
+    )?;
+
+    initialize_strategy(
+        &mut accounts.strategy,
+        &mut accounts.strategy_metrics,
+        &mut accounts.creator_index,
+        &mut accounts.strategy_counter,
+        accounts.creator.key(),
+        StrategyParams {
+            strategy_id,
+            dexs,
+            token_pairs,
+            profit_threshold,
+            max_slippage,
+        },
+        ctx.remaining_accounts,
+    )
+}
+
+/// Initialize a new strategy and register it in counters and indexes
+/// Remaining accounts: one TokenPairIndex per token pair
+fn initialize_strategy(
+    strategy: &mut Account<StrategyAccount>,
+    strategy_metrics: &mut Account<StrategyMetrics>,
+    creator_index: &mut Account<CreatorIndex>,
+    strategy_counter: &mut Account<StrategyCounter>,
+    creator: Pubkey,
+    params: StrategyParams,
+    pair_indexes: &[AccountInfo],
+) -> Result<()> {
+    let StrategyParams {
+        strategy_id,
+        dexs,
+        token_pairs,
+        profit_threshold,
+        max_slippage,
+    } = params;
     let clock = Clock::get()?;
 
+    validate_strategy_params(&dexs, &token_pairs, profit_threshold, max_slippage)?;
+
+    // Initialize strategy account
+    strategy.creator = creator;
+    strategy.strategy_id = strategy_id;
+    strategy.dexs = dexs.clone();
+    strategy.token_pairs = token_pairs.clone();
+    strategy.profit_threshold = profit_threshold;
+    strategy.max_slippage = max_slippage;
+    strategy.status = StrategyStatus::Pending;
+    strategy.version = 1;
+    strategy.emergency_paused = false;
+    strategy.total_profit = 0;
+    strategy.execution_count = 0;
+    strategy.success_count = 0;
+    strategy.last_execution = 0;
+    strategy.min_slots_between_executions = 0;
+    strategy.max_executions_per_epoch = 0;
+    strategy.last_execution_slot = 0;
+    strategy.execution_epoch = 0;
+    strategy.epoch_execution_count = 0;
+    strategy.max_borrow = DEFAULT_MAX_BORROW;
+
+    strategy_metrics.strategy = strategy.key();
+    strategy_metrics.buckets = Default::default();
+
+    // Keep automatic IDs clear of every ID used so far
+    creator_index.creator = creator;
+    creator_index.reserve_id(strategy_id)?;
+    strategy_counter.increment()?;
+
+    // Register in discovery indexes
+    creator_index.insert(strategy.key());
+    sync_pair_indexes(
+        strategy.key(),
+        Some(&**strategy),
+        &strategy.token_pairs,
+        pair_indexes,
+    )?;
+
+    // Emit event for off-chain indexing
+    emit!(StrategyCreated {
+        strategy_id,
+        creator,
+        dexs,
+        token_pairs,
+        profit_threshold,
+        max_slippage,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Strategy created: ID={}, Creator={}, Total strategies={}",
+        strategy_id,
+        creator,
+        strategy_counter.total_strategies
+    );
+
+    Ok(())
+}
+
+/// Make room for one more strategy in the creator index, with the creator
+/// paying the extra rent, so the index never caps how many strategies a
+/// creator can have
+fn grow_creator_index<'info>(
+    creator_index: &Account<'info, CreatorIndex>,
+    creator: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let info = creator_index.to_account_info();
+    let space = CreatorIndex::space(creator_index.strategies.len() + 1);
+    if info.data_len() >= space {
+        return Ok(());
+    }
+
+    let shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(info.lamports());
+    if shortfall > 0 {
+        let cpi_ctx = CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: creator.to_account_info(),
+                to: info.clone(),
+            },
+        );
+        system_program::transfer(cpi_ctx, shortfall)?;
+    }
+
+    info.resize(space)?;
+    Ok(())
+}
+
+/// Move the configured creation bond from the creator into the strategy's escrow
+fn lock_creation_bond<'info>(
+    bond_escrow: &mut Account<'info, BondEscrow>,
+    bump: u8,
+    strategy: Pubkey,
+    creator: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    amount: u64,
+) -> Result<()> {
+    bond_escrow.strategy = strategy;
+    bond_escrow.creator = creator.key();
+    bond_escrow.amount = amount;
+    bond_escrow.settled = false;
+    bond_escrow.bump = bump;
+
+    if amount > 0 {
+        let cpi_ctx = CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: creator.to_account_info(),
+                to: bond_escrow.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, amount)?;
+    }
+
+    emit!(BondLocked {
+        strategy,
+        creator: creator.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Validate strategy parameters (shared by create_strategy and update_strategy)
+pub(crate) fn validate_strategy_params(
+    dexs: &[DexType],
+    token_pairs: &[TokenPair],
+    profit_threshold: u16,
+    max_slippage: u16,
+) -> Result<()> {
     // Validation: Profit threshold >= 0.1% (10 bps)
     require!(
         profit_threshold >= MIN_PROFIT_THRESHOLD_BPS,
@@ -68,7 +442,7 @@ pub fn handler(
     require!(!token_pairs.is_empty(), StrategyError::NoTokenPairSpecified);
 
     // Validation: Check for invalid token pairs (same token for both sides)
-    for pair in &token_pairs {
+    for pair in token_pairs {
         require!(
             pair.token_a != pair.token_b,
             StrategyError::InvalidTokenPair
@@ -86,32 +460,5 @@ pub fn handler(
         StrategyError::NoTokenPairSpecified
     );
 
-    // Initialize strategy account
-    strategy.creator = ctx.accounts.creator.key();
-    strategy.strategy_id = strategy_id;
-    strategy.dexs = dexs.clone();
-    strategy.token_pairs = token_pairs.clone();
-    strategy.profit_threshold = profit_threshold;
-    strategy.max_slippage = max_slippage;
-    strategy.status = StrategyStatus::Pending;
-    strategy.total_profit = 0;
-    strategy.execution_count = 0;
-    strategy.success_count = 0;
-    strategy.last_execution = 0;
-    strategy.bump = ctx.bumps.strategy;
-
-    // Emit event for off-chain indexing
-    emit!(StrategyCreated {
-        strategy_id,
-        creator: ctx.accounts.creator.key(),
-        dexs,
-        token_pairs,
-        profit_threshold,
-        max_slippage,
-        timestamp: clock.unix_timestamp,
-    });
-
-    msg!("Strategy created: ID={}, Creator={}", strategy_id, ctx.accounts.creator.key());
-
     Ok(())
 }
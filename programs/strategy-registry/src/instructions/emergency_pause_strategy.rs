@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::sync_strategy_index::sync_pair_indexes,
+    instructions::{AdminConfig, StrategyPaused},
+    state::*,
+};
+
+/// Emergency pause by the approval authority (admin or DAO governance PDA,
+/// governance only in governance-only mode)
+/// The creator cannot resume until governance lifts the pause
+#[derive(Accounts)]
+pub struct EmergencyPauseStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<EmergencyPauseStrategy>) -> Result<()> {
+    let strategy = &mut ctx.accounts.strategy;
+    let clock = Clock::get()?;
+
+    // Same authorities as approve_strategy, the admin is refused in governance-only mode
+    ctx.accounts.admin_config.approval_path(&ctx.accounts.admin.key())?;
+
+    // Approved or creator-paused strategies can be emergency paused
+    require!(
+        strategy.status == StrategyStatus::Approved
+            || strategy.status == StrategyStatus::Paused,
+        StrategyError::InvalidStatus
+    );
+
+    strategy.status = StrategyStatus::Paused;
+    strategy.emergency_paused = true;
+
+    // Refresh the executable flag in any pair indexes supplied as remaining accounts
+    sync_pair_indexes(strategy.key(), Some(&**strategy), &[], ctx.remaining_accounts)?;
+
+    emit!(StrategyPaused {
+        strategy_id: strategy.strategy_id,
+        creator: strategy.creator,
+        paused_by: ctx.accounts.admin.key(),
+        emergency: true,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Strategy emergency paused by governance: ID={}", strategy.strategy_id);
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::update_route::apply_route,
+    instructions::AdminConfig,
+    state::*,
+};
+
+/// Attach an ordered route to a strategy (creator-only)
+#[derive(Accounts)]
+#[instruction(hops: Vec<RouteHop>)]
+pub struct InitRoute<'info> {
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump,
+        has_one = creator @ StrategyError::UnauthorizedCreator
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = StrategyRoute::space(hops.len()),
+        seeds = [ROUTE_SEED, strategy.key().as_ref()],
+        bump
+    )]
+    pub strategy_route: Account<'info, StrategyRoute>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitRoute>, hops: Vec<RouteHop>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        !ctx.accounts.admin_config.pause.is_paused(PAUSE_UPDATE, clock.unix_timestamp),
+        StrategyError::ProgramPaused
+    );
+
+    let strategy_route = &mut ctx.accounts.strategy_route;
+    strategy_route.strategy = ctx.accounts.strategy.key();
+    strategy_route.hops = Vec::new();
+    strategy_route.bump = ctx.bumps.strategy_route;
+
+    apply_route(&mut ctx.accounts.strategy, strategy_route, hops, ctx.remaining_accounts)
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    state::*,
+};
+
+/// Create the index for a token pair (permissionless, payer funds rent)
+#[derive(Accounts)]
+#[instruction(token_a: Pubkey, token_b: Pubkey)]
+pub struct InitTokenPairIndex<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = TokenPairIndex::LEN,
+        seeds = [PAIR_INDEX_SEED, token_a.as_ref(), token_b.as_ref()],
+        bump
+    )]
+    pub pair_index: Account<'info, TokenPairIndex>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitTokenPairIndex>, token_a: Pubkey, token_b: Pubkey) -> Result<()> {
+    // One index per pair regardless of token order
+    require!(token_a < token_b, StrategyError::NonCanonicalTokenPair);
+
+    let pair_index = &mut ctx.accounts.pair_index;
+    pair_index.token_a = token_a;
+    pair_index.token_b = token_b;
+    pair_index.entries = Vec::new();
+    pair_index.bump = ctx.bumps.pair_index;
+
+    msg!("Token pair index created: {}/{}", token_a, token_b);
+
+    Ok(())
+}
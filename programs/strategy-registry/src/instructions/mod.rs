@@ -1,7 +1,49 @@
 pub mod create_strategy;
 pub mod approve_strategy;
 pub mod update_metrics;
+pub mod update_strategy;
+pub mod pause_strategy;
+pub mod emergency_pause_strategy;
+pub mod resume_strategy;
+pub mod retire_strategy;
+pub mod close_strategy;
+pub mod transfer_admin;
+pub mod accept_admin;
+pub mod set_approval_mode;
+pub mod set_execution_engine;
+pub mod init_token_pair_index;
+pub mod sync_strategy_index;
+pub mod set_creation_bond;
+pub mod settle_bond;
+pub mod init_route;
+pub mod update_route;
+pub mod set_rate_limits;
+pub mod set_max_borrow;
+pub mod set_guardian;
+pub mod set_pause;
 
+// Every module defines its own `handler`, called through its module path
+#[allow(ambiguous_glob_reexports)]
 pub use create_strategy::*;
 pub use approve_strategy::*;
 pub use update_metrics::*;
+pub use update_strategy::*;
+pub use pause_strategy::*;
+pub use emergency_pause_strategy::*;
+pub use resume_strategy::*;
+pub use retire_strategy::*;
+pub use close_strategy::*;
+pub use transfer_admin::*;
+pub use accept_admin::*;
+pub use set_approval_mode::*;
+pub use set_execution_engine::*;
+pub use init_token_pair_index::*;
+pub use sync_strategy_index::*;
+pub use set_creation_bond::*;
+pub use settle_bond::*;
+pub use init_route::*;
+pub use update_route::*;
+pub use set_rate_limits::*;
+pub use set_max_borrow::*;
+pub use set_guardian::*;
+pub use set_pause::*;
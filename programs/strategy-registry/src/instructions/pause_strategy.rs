@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::sync_strategy_index::sync_pair_indexes,
+    state::*,
+};
+
+#[event]
+pub struct StrategyPaused {
+    pub strategy_id: u64,
+    pub creator: Pubkey,
+    pub paused_by: Pubkey,
+    pub emergency: bool,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct PauseStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump,
+        has_one = creator @ StrategyError::UnauthorizedCreator
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<PauseStrategy>) -> Result<()> {
+    let strategy = &mut ctx.accounts.strategy;
+    let clock = Clock::get()?;
+
+    // Only approved strategies can be paused
+    require!(
+        strategy.status == StrategyStatus::Approved,
+        StrategyError::InvalidStatus
+    );
+
+    strategy.status = StrategyStatus::Paused;
+
+    // Refresh the executable flag in any pair indexes supplied as remaining accounts
+    sync_pair_indexes(strategy.key(), Some(&**strategy), &[], ctx.remaining_accounts)?;
+
+    emit!(StrategyPaused {
+        strategy_id: strategy.strategy_id,
+        creator: strategy.creator,
+        paused_by: ctx.accounts.creator.key(),
+        emergency: false,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Strategy paused by creator: ID={}", strategy.strategy_id);
+
+    Ok(())
+}
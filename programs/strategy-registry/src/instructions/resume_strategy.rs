@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::sync_strategy_index::sync_pair_indexes,
+    instructions::AdminConfig,
+    state::*,
+};
+
+#[event]
+pub struct StrategyResumed {
+    pub strategy_id: u64,
+    pub creator: Pubkey,
+    pub resumed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Resume a paused strategy
+/// Creator can resume its own pause, governance must lift an emergency pause
+#[derive(Accounts)]
+pub struct ResumeStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// Strategy creator, or the config authority (see AdminConfig::can_configure)
+    /// for emergency pauses
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ResumeStrategy>) -> Result<()> {
+    let strategy = &mut ctx.accounts.strategy;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    require!(
+        !ctx.accounts.admin_config.pause.is_paused(PAUSE_UPDATE, clock.unix_timestamp),
+        StrategyError::ProgramPaused
+    );
+
+    require!(
+        strategy.status == StrategyStatus::Paused,
+        StrategyError::InvalidStatus
+    );
+
+    if strategy.emergency_paused {
+        require!(
+            ctx.accounts.admin_config.can_configure(&authority),
+            StrategyError::EmergencyPauseActive
+        );
+        strategy.emergency_paused = false;
+    } else {
+        require!(
+            authority == strategy.creator,
+            StrategyError::UnauthorizedCreator
+        );
+    }
+
+    strategy.status = StrategyStatus::Approved;
+
+    // Refresh the executable flag in any pair indexes supplied as remaining accounts
+    sync_pair_indexes(strategy.key(), Some(&**strategy), &[], ctx.remaining_accounts)?;
+
+    emit!(StrategyResumed {
+        strategy_id: strategy.strategy_id,
+        creator: strategy.creator,
+        resumed_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Strategy resumed: ID={}", strategy.strategy_id);
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::sync_strategy_index::sync_pair_indexes,
+    state::*,
+};
+
+#[event]
+pub struct StrategyRetired {
+    pub strategy_id: u64,
+    pub creator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct RetireStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump,
+        has_one = creator @ StrategyError::UnauthorizedCreator
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RetireStrategy>) -> Result<()> {
+    let strategy = &mut ctx.accounts.strategy;
+    let clock = Clock::get()?;
+
+    // Retirement is permanent, rejected strategies can be closed directly
+    require!(
+        strategy.status == StrategyStatus::Pending
+            || strategy.status == StrategyStatus::Approved
+            || strategy.status == StrategyStatus::Paused,
+        StrategyError::InvalidStatus
+    );
+
+    strategy.status = StrategyStatus::Retired;
+
+    // Refresh the executable flag in any pair indexes supplied as remaining accounts
+    sync_pair_indexes(strategy.key(), Some(&**strategy), &[], ctx.remaining_accounts)?;
+    strategy.emergency_paused = false;
+
+    emit!(StrategyRetired {
+        strategy_id: strategy.strategy_id,
+        creator: strategy.creator,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Strategy retired: ID={}", strategy.strategy_id);
+
+    Ok(())
+}
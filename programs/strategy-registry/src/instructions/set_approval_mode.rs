@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::AdminConfig,
+};
+
+#[event]
+pub struct ApprovalModeUpdated {
+    pub governance: Pubkey,
+    pub governance_only: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Register the DAO governance PDA and toggle governance-only approvals
+/// Once governance-only is enabled, only governance can change the mode
+/// (via a DAO RegistryConfig proposal)
+#[derive(Accounts)]
+pub struct SetApprovalMode<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetApprovalMode>,
+    governance: Pubkey,
+    governance_only: bool,
+) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    // Admin controls the mode until governance-only is enabled
+    require!(
+        admin_config.can_configure(&authority),
+        StrategyError::UnauthorizedAdmin
+    );
+
+    // Governance-only mode needs a governance authority to approve through
+    require!(
+        !governance_only || governance != Pubkey::default(),
+        StrategyError::GovernanceNotConfigured
+    );
+
+    admin_config.governance = governance;
+    admin_config.governance_only = governance_only;
+
+    emit!(ApprovalModeUpdated {
+        governance,
+        governance_only,
+        updated_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Approval mode updated: governance={}, governance_only={}", governance, governance_only);
+
+    Ok(())
+}
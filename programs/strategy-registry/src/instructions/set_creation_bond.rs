@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::AdminConfig,
+};
+
+#[event]
+pub struct CreationBondUpdated {
+    pub creation_bond: u64,
+    pub treasury: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Set the anti-spam bond required by create_strategy
+#[derive(Accounts)]
+pub struct SetCreationBond<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetCreationBond>, creation_bond: u64, treasury: Pubkey) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    require!(
+        admin_config.can_configure(&authority),
+        StrategyError::UnauthorizedAdmin
+    );
+
+    // Forfeited bonds need somewhere to go (outstanding escrows included)
+    require!(
+        treasury != Pubkey::default(),
+        StrategyError::TreasuryNotConfigured
+    );
+
+    admin_config.creation_bond = creation_bond;
+    admin_config.treasury = treasury;
+
+    emit!(CreationBondUpdated {
+        creation_bond,
+        treasury,
+        updated_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Creation bond updated: {} lamports, treasury={}", creation_bond, treasury);
+
+    Ok(())
+}
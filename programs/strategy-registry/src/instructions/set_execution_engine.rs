@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::AdminConfig,
+};
+
+#[event]
+pub struct ExecutionEngineUpdated {
+    pub previous_engine: Pubkey,
+    pub new_engine: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Rotate the program authorized to update strategy metrics
+/// Governance only (a DAO RegistryConfig proposal), whatever the approval mode:
+/// the engine decides whose metrics writes the registry trusts
+#[derive(Accounts)]
+pub struct SetExecutionEngine<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetExecutionEngine>, execution_engine: Pubkey) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    require!(
+        admin_config.is_governance(&authority),
+        StrategyError::UnauthorizedAdmin
+    );
+
+    let previous_engine = admin_config.execution_engine;
+    admin_config.execution_engine = execution_engine;
+
+    emit!(ExecutionEngineUpdated {
+        previous_engine,
+        new_engine: execution_engine,
+        updated_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Execution engine updated: {} -> {}", previous_engine, execution_engine);
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::AdminConfig,
+    state::PauseFlags,
+};
+
+#[event]
+pub struct GuardianUpdated {
+    pub guardian: Pubkey,
+    pub pause_seconds: i64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Set the guardian allowed to pause registry instruction groups temporarily
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetGuardian>, guardian: Pubkey, pause_seconds: i64) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    require!(
+        admin_config.can_configure(&authority),
+        StrategyError::UnauthorizedAdmin
+    );
+
+    require!(
+        PauseFlags::is_valid_guardian_window(pause_seconds),
+        StrategyError::InvalidGuardianPause
+    );
+
+    admin_config.pause.set_guardian(guardian, pause_seconds);
+
+    emit!(GuardianUpdated {
+        guardian,
+        pause_seconds,
+        updated_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Guardian updated: {} ({}s pauses)", guardian, pause_seconds);
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::{sync_strategy_index::sync_pair_indexes, AdminConfig},
+    state::*,
+};
+
+#[event]
+pub struct MaxBorrowUpdated {
+    pub strategy_id: u64,
+    pub creator: Pubkey,
+    pub max_borrow: u64,
+    pub requires_reapproval: bool,
+    pub timestamp: i64,
+}
+
+/// Set a strategy's per-execution borrow cap (creator-only)
+#[derive(Accounts)]
+pub struct SetMaxBorrow<'info> {
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump,
+        has_one = creator @ StrategyError::UnauthorizedCreator
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub creator: Signer<'info>,
+}
+
+/// Governance approves the cap together with the strategy, so raising it bumps
+/// the version (invalidating open approval proposals) and sends an approved
+/// strategy back to Pending (pair indexes of all its pairs must then be
+/// supplied as remaining accounts). Lowering it takes effect immediately since
+/// it only reduces risk.
+pub fn handler(ctx: Context<SetMaxBorrow>, max_borrow: u64) -> Result<()> {
+    let strategy = &mut ctx.accounts.strategy;
+    let clock = Clock::get()?;
+
+    require!(
+        !ctx.accounts.admin_config.pause.is_paused(PAUSE_UPDATE, clock.unix_timestamp),
+        StrategyError::ProgramPaused
+    );
+
+    require!(max_borrow > 0, StrategyError::InvalidMaxBorrow);
+    // A paused strategy resumes straight to Approved, so it may only lower its cap
+    require!(
+        strategy.status == StrategyStatus::Pending
+            || strategy.status == StrategyStatus::Approved
+            || (strategy.status == StrategyStatus::Paused && max_borrow <= strategy.max_borrow),
+        StrategyError::InvalidStatus
+    );
+
+    let raised = max_borrow > strategy.max_borrow;
+    let requires_reapproval = strategy.status == StrategyStatus::Approved && raised;
+
+    strategy.max_borrow = max_borrow;
+
+    if raised {
+        strategy.version = strategy.version
+            .checked_add(1)
+            .ok_or(StrategyError::ArithmeticOverflow)?;
+    }
+
+    // Indexes still list the strategy as executable until they are synced
+    let required_pairs = if requires_reapproval {
+        strategy.status = StrategyStatus::Pending;
+        strategy.token_pairs.clone()
+    } else {
+        Vec::new()
+    };
+
+    sync_pair_indexes(strategy.key(), Some(&**strategy), &required_pairs, ctx.remaining_accounts)?;
+
+    emit!(MaxBorrowUpdated {
+        strategy_id: strategy.strategy_id,
+        creator: strategy.creator,
+        max_borrow,
+        requires_reapproval,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Max borrow updated: ID={}, Max borrow={}, Requires reapproval={}",
+        strategy.strategy_id,
+        max_borrow,
+        requires_reapproval
+    );
+
+    Ok(())
+}
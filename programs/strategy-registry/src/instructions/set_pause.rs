@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::AdminConfig,
+};
+
+#[event]
+pub struct PauseUpdated {
+    pub admin_paused: u8,
+    pub guardian_paused: u8,
+    pub guardian_expires_at: i64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Pause or unpause registry instruction groups (admin or guardian)
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// The registry admin is whoever can_configure allows: the admin key, or the
+/// governance PDA (DAO proposals) in governance-only mode. Its pause lasts
+/// until lifted (clearing any guardian pause). The guardian's pause lapses
+/// after the window set with set_guardian.
+pub fn handler(ctx: Context<SetPause>, groups: u8) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    require!(groups & !PAUSE_ALL == 0, StrategyError::InvalidPauseGroups);
+
+    if admin_config.can_configure(&authority) {
+        admin_config.pause.set_by_admin(groups);
+    } else if admin_config.pause.is_guardian(&authority) {
+        admin_config.pause.set_by_guardian(groups, clock.unix_timestamp);
+    } else {
+        return err!(StrategyError::UnauthorizedPauser);
+    }
+
+    emit!(PauseUpdated {
+        admin_paused: admin_config.pause.admin_paused,
+        guardian_paused: admin_config.pause.guardian_paused,
+        guardian_expires_at: admin_config.pause.guardian_expires_at,
+        updated_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Registry pause updated: admin={:#05b}, guardian={:#05b} until {}",
+        admin_config.pause.admin_paused,
+        admin_config.pause.guardian_paused,
+        admin_config.pause.guardian_expires_at
+    );
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::AdminConfig,
+    state::*,
+};
+
+#[event]
+pub struct RateLimitsUpdated {
+    pub strategy_id: u64,
+    pub creator: Pubkey,
+    pub min_slots_between_executions: u64,
+    pub max_executions_per_epoch: u32,
+    pub timestamp: i64,
+}
+
+/// Configure execution cooldown and per-epoch cap (creator-only, 0 disables each)
+#[derive(Accounts)]
+pub struct SetRateLimits<'info> {
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump,
+        has_one = creator @ StrategyError::UnauthorizedCreator
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetRateLimits>,
+    min_slots_between_executions: u64,
+    max_executions_per_epoch: u32,
+) -> Result<()> {
+    let strategy = &mut ctx.accounts.strategy;
+    let clock = Clock::get()?;
+
+    require!(
+        !ctx.accounts.admin_config.pause.is_paused(PAUSE_UPDATE, clock.unix_timestamp),
+        StrategyError::ProgramPaused
+    );
+
+    // Limits only throttle execution, so no re-approval is needed
+    require!(
+        strategy.status != StrategyStatus::Retired
+            && strategy.status != StrategyStatus::Rejected,
+        StrategyError::InvalidStatus
+    );
+
+    strategy.min_slots_between_executions = min_slots_between_executions;
+    strategy.max_executions_per_epoch = max_executions_per_epoch;
+
+    emit!(RateLimitsUpdated {
+        strategy_id: strategy.strategy_id,
+        creator: strategy.creator,
+        min_slots_between_executions,
+        max_executions_per_epoch,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Rate limits updated: ID={}, Cooldown={} slots, Max per epoch={}",
+        strategy.strategy_id,
+        min_slots_between_executions,
+        max_executions_per_epoch
+    );
+
+    Ok(())
+}
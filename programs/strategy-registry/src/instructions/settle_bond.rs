@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::AdminConfig,
+    state::*,
+};
+
+#[event]
+pub struct BondSettled {
+    pub strategy: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub forfeited: bool,
+    pub timestamp: i64,
+}
+
+/// Release a strategy's creation bond once it has left Pending (permissionless)
+///
+/// Approved, paused and retired strategies refund the creator (retiring a pending
+/// strategy is the voluntary withdrawal path). Rejected strategies forfeit the bond
+/// to the treasury.
+#[derive(Accounts)]
+pub struct SettleBond<'info> {
+    #[account(
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    #[account(
+        mut,
+        seeds = [BOND_SEED, strategy.key().as_ref()],
+        bump = bond_escrow.bump,
+        has_one = creator
+    )]
+    pub bond_escrow: Account<'info, BondEscrow>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// CHECK: Bond refund recipient, validated against bond_escrow.creator
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// CHECK: Forfeited bond recipient, validated against admin_config.treasury
+    #[account(mut, address = admin_config.treasury)]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<SettleBond>) -> Result<()> {
+    require!(
+        ctx.accounts.strategy.status != StrategyStatus::Pending,
+        StrategyError::BondLocked
+    );
+
+    let forfeited = ctx.accounts.strategy.status == StrategyStatus::Rejected;
+    let recipient = if forfeited {
+        ctx.accounts.treasury.to_account_info()
+    } else {
+        ctx.accounts.creator.to_account_info()
+    };
+
+    settle_creation_bond(&mut ctx.accounts.bond_escrow, &recipient, forfeited)
+}
+
+/// Move the bond out of escrow to `recipient` (creator refund or treasury forfeit)
+/// The escrow keeps its rent until the strategy is closed
+pub(crate) fn settle_creation_bond<'info>(
+    bond_escrow: &mut Account<'info, BondEscrow>,
+    recipient: &AccountInfo<'info>,
+    forfeited: bool,
+) -> Result<()> {
+    require!(!bond_escrow.settled, StrategyError::BondAlreadySettled);
+
+    let amount = bond_escrow.amount;
+
+    if amount > 0 {
+        bond_escrow.sub_lamports(amount)?;
+        recipient.add_lamports(amount)?;
+    }
+    bond_escrow.settled = true;
+
+    emit!(BondSettled {
+        strategy: bond_escrow.strategy,
+        creator: bond_escrow.creator,
+        amount,
+        recipient: recipient.key(),
+        forfeited,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Creation bond settled: {} lamports to {} (forfeited={})",
+        amount,
+        recipient.key(),
+        forfeited
+    );
+
+    Ok(())
+}
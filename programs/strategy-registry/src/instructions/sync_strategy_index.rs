@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    state::*,
+};
+
+/// Refresh a strategy's token pair index entries (permissionless)
+///
+/// Status changes sync whatever pair indexes they are given, so an index can
+/// lag behind (e.g. DAO approvals executed without them). Anyone can call this
+/// with every pair index of the strategy as remaining accounts to fix it.
+#[derive(Accounts)]
+pub struct SyncStrategyIndex<'info> {
+    #[account(
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+}
+
+pub fn handler(ctx: Context<SyncStrategyIndex>) -> Result<()> {
+    let strategy = &ctx.accounts.strategy;
+
+    sync_pair_indexes(
+        strategy.key(),
+        Some(&**strategy),
+        &strategy.token_pairs,
+        ctx.remaining_accounts,
+    )?;
+
+    msg!("Strategy index synced: ID={}", strategy.strategy_id);
+
+    Ok(())
+}
+
+/// Sync the token pair indexes passed as remaining accounts with a strategy
+///
+/// An index gets an up-to-date entry if the strategy trades its pair, and
+/// loses the entry otherwise (`strategy` is None when the strategy is closing).
+/// A full index skips non-executable strategies (see TokenPairIndex::upsert).
+/// Every pair in `required_pairs` must be covered by one of the indexes.
+pub(crate) fn sync_pair_indexes(
+    strategy_key: Pubkey,
+    strategy: Option<&StrategyAccount>,
+    required_pairs: &[TokenPair],
+    pair_indexes: &[AccountInfo],
+) -> Result<()> {
+    let mut covered = vec![false; required_pairs.len()];
+
+    for info in pair_indexes {
+        require_keys_eq!(*info.owner, crate::ID, StrategyError::InvalidPairIndex);
+        require!(info.is_writable, StrategyError::InvalidPairIndex);
+
+        // Discriminator check rejects any other registry account type
+        let mut index = TokenPairIndex::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+
+        for (pair, is_covered) in required_pairs.iter().zip(covered.iter_mut()) {
+            *is_covered |= index.covers(pair);
+        }
+
+        match strategy {
+            Some(strategy) if strategy.token_pairs.iter().any(|pair| index.covers(pair)) => {
+                let listed = index.upsert(strategy_key, strategy.dex_mask(), strategy.is_executable())?;
+                if !listed {
+                    msg!("Pair index {} is full, strategy not listed until approved", info.key);
+                }
+            }
+            _ => index.remove(&strategy_key),
+        }
+
+        index.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+    }
+
+    require!(
+        covered.iter().all(|is_covered| *is_covered),
+        StrategyError::MissingPairIndex
+    );
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::AdminConfig,
+};
+
+#[event]
+pub struct AdminTransferProposed {
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Nominate a new admin (step 1 of 2, current admin only)
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump,
+        has_one = admin @ StrategyError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    let clock = Clock::get()?;
+
+    // Passing Pubkey::default() cancels a pending transfer
+    admin_config.pending_admin = new_admin;
+
+    emit!(AdminTransferProposed {
+        current_admin: admin_config.admin,
+        pending_admin: new_admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Admin transfer proposed: {} -> {}", admin_config.admin, new_admin);
+
+    Ok(())
+}
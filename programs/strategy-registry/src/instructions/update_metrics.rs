@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use crate::{
     constants::*,
     error::StrategyError,
+    instructions::AdminConfig,
     state::*,
 };
 
@@ -12,6 +13,7 @@ pub struct StrategyExecuted {
     pub executor: Pubkey,
     pub profit: u64,
     pub success: bool,
+    pub volume_borrowed: u64,
     pub execution_count: u64,
     pub success_count: u64,
     pub timestamp: i64,
@@ -30,6 +32,28 @@ pub struct UpdateMetrics<'info> {
     )]
     pub strategy: Account<'info, StrategyAccount>,
 
+    #[account(
+        mut,
+        seeds = [METRICS_SEED, strategy.key().as_ref()],
+        bump = strategy_metrics.bump
+    )]
+    pub strategy_metrics: Account<'info, StrategyMetrics>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// Registered execution engine's vault PDA
+    /// Only that program can sign for it, so metrics can only change via its CPI
+    #[account(
+        seeds = [EXECUTION_AUTHORITY_SEED],
+        bump,
+        seeds::program = admin_config.execution_engine
+    )]
+    pub execution_authority: Signer<'info>,
+
     /// Executor of the strategy (not necessarily the creator)
     pub executor: Signer<'info>,
 }
@@ -38,6 +62,7 @@ pub fn handler(
     ctx: Context<UpdateMetrics>,
     profit: u64,
     success: bool,
+    volume_borrowed: u64,
 ) -> Result<()> {
     let strategy = &mut ctx.accounts.strategy;
     let clock = Clock::get()?;
@@ -48,6 +73,22 @@ pub fn handler(
         StrategyError::StrategyNotApproved
     );
 
+    // Borrow cap and rate limits apply to successful executions (the engine checks them up front too)
+    if success {
+        require!(
+            strategy.allows_borrow(volume_borrowed),
+            StrategyError::BorrowCapExceeded
+        );
+        require!(
+            !strategy.is_cooling_down(clock.slot),
+            StrategyError::ExecutionCooldown
+        );
+        require!(
+            !strategy.epoch_limit_reached(clock.epoch),
+            StrategyError::EpochExecutionLimit
+        );
+    }
+
     // Update execution count with overflow check
     strategy.execution_count = strategy.execution_count
         .checked_add(1)
@@ -63,10 +104,17 @@ pub fn handler(
         strategy.total_profit = strategy.total_profit
             .checked_add(profit)
             .ok_or(StrategyError::ArithmeticOverflow)?;
+
+        // Update last execution timestamp (failed attempts don't count)
+        strategy.last_execution = clock.unix_timestamp;
+        strategy.record_execution_slot(clock.slot, clock.epoch);
     }
 
-    // Update last execution timestamp
-    strategy.last_execution = clock.unix_timestamp;
+    // Record in the rolling bucket (failed attempts add to executions only)
+    let bucket_profit = if success { profit } else { 0 };
+    ctx.accounts
+        .strategy_metrics
+        .record(clock.unix_timestamp, bucket_profit, volume_borrowed)?;
 
     // Emit event
     emit!(StrategyExecuted {
@@ -75,6 +123,7 @@ pub fn handler(
         executor: ctx.accounts.executor.key(),
         profit,
         success,
+        volume_borrowed,
         execution_count: strategy.execution_count,
         success_count: strategy.success_count,
         timestamp: clock.unix_timestamp,
@@ -104,9 +153,19 @@ pub struct GetStrategyStats<'info> {
         bump = strategy.bump
     )]
     pub strategy: Account<'info, StrategyAccount>,
+
+    #[account(
+        seeds = [METRICS_SEED, strategy.key().as_ref()],
+        bump = strategy_metrics.bump
+    )]
+    pub strategy_metrics: Account<'info, StrategyMetrics>,
 }
 
 pub fn get_strategy_stats(ctx: Context<GetStrategyStats>) -> Result<StrategyStats> {
-    let strategy = &ctx.accounts.strategy;
-    Ok(StrategyStats::from(&**strategy))
+    let clock = Clock::get()?;
+    Ok(StrategyStats::with_metrics(
+        &ctx.accounts.strategy,
+        &ctx.accounts.strategy_metrics,
+        clock.unix_timestamp,
+    ))
 }
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::{sync_strategy_index::sync_pair_indexes, AdminConfig},
+    state::*,
+};
+
+#[event]
+pub struct RouteUpdated {
+    pub strategy_id: u64,
+    pub creator: Pubkey,
+    pub version: u32,
+    pub hops: Vec<RouteHop>,
+    pub requires_reapproval: bool,
+    pub timestamp: i64,
+}
+
+/// Replace a strategy's route, resizing the route account to fit
+#[derive(Accounts)]
+#[instruction(hops: Vec<RouteHop>)]
+pub struct UpdateRoute<'info> {
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump,
+        has_one = creator @ StrategyError::UnauthorizedCreator
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    #[account(
+        mut,
+        seeds = [ROUTE_SEED, strategy.key().as_ref()],
+        bump = strategy_route.bump,
+        realloc = StrategyRoute::space(hops.len()),
+        realloc::payer = creator,
+        realloc::zero = false
+    )]
+    pub strategy_route: Account<'info, StrategyRoute>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<UpdateRoute>, hops: Vec<RouteHop>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        !ctx.accounts.admin_config.pause.is_paused(PAUSE_UPDATE, clock.unix_timestamp),
+        StrategyError::ProgramPaused
+    );
+
+    apply_route(
+        &mut ctx.accounts.strategy,
+        &mut ctx.accounts.strategy_route,
+        hops,
+        ctx.remaining_accounts,
+    )
+}
+
+/// Validate and store a route (shared by init_route and update_route)
+///
+/// Routes are only editable while Pending or Approved. A route change on an
+/// approved strategy sends it back to Pending since pools are not risk-ranked,
+/// in which case the pair indexes of all its pairs must be supplied.
+pub(crate) fn apply_route(
+    strategy: &mut Account<StrategyAccount>,
+    strategy_route: &mut Account<StrategyRoute>,
+    hops: Vec<RouteHop>,
+    pair_indexes: &[AccountInfo],
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        strategy.status == StrategyStatus::Pending
+            || strategy.status == StrategyStatus::Approved,
+        StrategyError::InvalidStatus
+    );
+
+    StrategyRoute::validate_hops(&hops, strategy)?;
+
+    let requires_reapproval = strategy.status == StrategyStatus::Approved
+        && strategy_route.hops != hops;
+
+    strategy_route.hops = hops.clone();
+    strategy.version = strategy.version
+        .checked_add(1)
+        .ok_or(StrategyError::ArithmeticOverflow)?;
+
+    // A demoted strategy must drop out of every index as executable
+    let required_pairs = if requires_reapproval {
+        strategy.status = StrategyStatus::Pending;
+        strategy.token_pairs.clone()
+    } else {
+        Vec::new()
+    };
+
+    sync_pair_indexes(strategy.key(), Some(&**strategy), &required_pairs, pair_indexes)?;
+
+    emit!(RouteUpdated {
+        strategy_id: strategy.strategy_id,
+        creator: strategy.creator,
+        version: strategy.version,
+        hops,
+        requires_reapproval,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Strategy route set: ID={}, Hops={}, Requires reapproval={}",
+        strategy.strategy_id,
+        strategy_route.hops.len(),
+        requires_reapproval
+    );
+
+    Ok(())
+}
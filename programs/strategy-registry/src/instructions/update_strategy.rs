@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use crate::{
+    constants::*,
+    error::StrategyError,
+    instructions::create_strategy::validate_strategy_params,
+    instructions::sync_strategy_index::sync_pair_indexes,
+    instructions::AdminConfig,
+    state::*,
+};
+
+#[event]
+pub struct StrategyUpdated {
+    pub strategy_id: u64,
+    pub creator: Pubkey,
+    pub version: u32,
+    pub dexs: Vec<DexType>,
+    pub token_pairs: Vec<TokenPair>,
+    pub profit_threshold: u16,
+    pub max_slippage: u16,
+    pub requires_reapproval: bool,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct UpdateStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [
+            STRATEGY_SEED,
+            strategy.creator.as_ref(),
+            &strategy.strategy_id.to_le_bytes()
+        ],
+        bump = strategy.bump,
+        has_one = creator @ StrategyError::UnauthorizedCreator
+    )]
+    pub strategy: Account<'info, StrategyAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// CHECK: Strategy route PDA, re-validated against the new DEXs and pairs
+    /// if a route was set (it may not exist yet)
+    #[account(
+        seeds = [ROUTE_SEED, strategy.key().as_ref()],
+        bump
+    )]
+    pub strategy_route: UncheckedAccount<'info>,
+
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateStrategy>,
+    dexs: Vec<DexType>,
+    token_pairs: Vec<TokenPair>,
+    profit_threshold: u16,
+    max_slippage: u16,
+) -> Result<()> {
+    let strategy = &mut ctx.accounts.strategy;
+    let clock = Clock::get()?;
+
+    require!(
+        !ctx.accounts.admin_config.pause.is_paused(PAUSE_UPDATE, clock.unix_timestamp),
+        StrategyError::ProgramPaused
+    );
+
+    // Rejected strategies must be re-created
+    require!(
+        strategy.status == StrategyStatus::Pending
+            || strategy.status == StrategyStatus::Approved,
+        StrategyError::InvalidStatus
+    );
+
+    validate_strategy_params(&dexs, &token_pairs, profit_threshold, max_slippage)?;
+
+    // Loosening changes on an approved strategy send it back for approval,
+    // tightening changes apply immediately
+    let requires_reapproval = strategy.status == StrategyStatus::Approved
+        && strategy.is_risk_increasing(&dexs, &token_pairs, profit_threshold, max_slippage);
+
+    // Indexes of both the old and new pairs must be supplied so entries move with the strategy
+    let mut affected_pairs = strategy.token_pairs.clone();
+    affected_pairs.extend(token_pairs.iter().cloned());
+
+    // Update parameters (metrics are preserved)
+    strategy.dexs = dexs.clone();
+    strategy.token_pairs = token_pairs.clone();
+    strategy.profit_threshold = profit_threshold;
+    strategy.max_slippage = max_slippage;
+    strategy.version = strategy.version
+        .checked_add(1)
+        .ok_or(StrategyError::ArithmeticOverflow)?;
+
+    if requires_reapproval {
+        strategy.status = StrategyStatus::Pending;
+    }
+
+    // An existing route must still only use the strategy's DEXs and pairs
+    let route_info = ctx.accounts.strategy_route.to_account_info();
+    if !route_info.data_is_empty() {
+        // Only this program can allocate the PDA, the discriminator check does the rest
+        let strategy_route = StrategyRoute::try_deserialize(&mut &route_info.try_borrow_data()?[..])?;
+        StrategyRoute::validate_hops(&strategy_route.hops, strategy)?;
+    }
+
+    sync_pair_indexes(strategy.key(), Some(&**strategy), &affected_pairs, ctx.remaining_accounts)?;
+
+    // Emit event for off-chain indexing
+    emit!(StrategyUpdated {
+        strategy_id: strategy.strategy_id,
+        creator: strategy.creator,
+        version: strategy.version,
+        dexs,
+        token_pairs,
+        profit_threshold,
+        max_slippage,
+        requires_reapproval,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Strategy updated: ID={}, Version={}, Requires reapproval={}",
+        strategy.strategy_id,
+        strategy.version,
+        requires_reapproval
+    );
+
+    Ok(())
+}
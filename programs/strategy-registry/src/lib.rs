@@ -6,6 +6,8 @@ pub mod state;
 use anchor_lang::prelude::*;
 
 pub use constants::*;
+// Some instruction modules export handlers named after their #[program] entry point
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::*;
 pub use state::*;
 
@@ -20,7 +22,58 @@ pub mod strategy_registry {
         approve_strategy::initialize_admin(ctx)
     }
 
+    /// Nominate a new admin (current admin only, accepted via accept_admin)
+    pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+        transfer_admin::handler(ctx, new_admin)
+    }
+
+    /// Accept a pending admin nomination
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        accept_admin::handler(ctx)
+    }
+
+    /// Register the DAO governance PDA and toggle governance-only approvals
+    pub fn set_approval_mode(
+        ctx: Context<SetApprovalMode>,
+        governance: Pubkey,
+        governance_only: bool,
+    ) -> Result<()> {
+        set_approval_mode::handler(ctx, governance, governance_only)
+    }
+
+    /// Rotate the execution engine program authorized to update metrics
+    /// Governance only, via a DAO RegistryConfig proposal
+    pub fn set_execution_engine(
+        ctx: Context<SetExecutionEngine>,
+        execution_engine: Pubkey,
+    ) -> Result<()> {
+        set_execution_engine::handler(ctx, execution_engine)
+    }
+
+    /// Set the creation bond and the treasury that receives forfeited bonds
+    pub fn set_creation_bond(
+        ctx: Context<SetCreationBond>,
+        creation_bond: u64,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        set_creation_bond::handler(ctx, creation_bond, treasury)
+    }
+
+    /// Set the guardian allowed to pause instruction groups, and for how long
+    /// each guardian pause lasts (at most 24 hours)
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey, pause_seconds: i64) -> Result<()> {
+        set_guardian::handler(ctx, guardian, pause_seconds)
+    }
+
+    /// Pause instruction groups (PAUSE_* bitmask, 0 unpauses)
+    /// Admin (or governance) pauses have no expiry; guardian pauses lapse automatically
+    pub fn set_pause(ctx: Context<SetPause>, groups: u8) -> Result<()> {
+        set_pause::handler(ctx, groups)
+    }
+
     /// Create a new arbitrage strategy
+    /// Locks AdminConfig::creation_bond in a BondEscrow PDA
+    /// Remaining accounts: the TokenPairIndex of every token pair (writable)
     pub fn create_strategy(
         ctx: Context<CreateStrategy>,
         strategy_id: u64,
@@ -32,26 +85,133 @@ pub mod strategy_registry {
         create_strategy::handler(ctx, strategy_id, dexs, token_pairs, profit_threshold, max_slippage)
     }
 
-    /// Approve a pending strategy (admin-only for hackathon)
-    pub fn approve_strategy(ctx: Context<ApproveStrategy>) -> Result<()> {
-        approve_strategy::approve_strategy(ctx)
+    /// Create a new arbitrage strategy with the creator's next free strategy ID
+    /// Locks AdminConfig::creation_bond in a BondEscrow PDA
+    /// Remaining accounts: the TokenPairIndex of every token pair (writable)
+    pub fn create_strategy_auto(
+        ctx: Context<CreateStrategyAuto>,
+        dexs: Vec<DexType>,
+        token_pairs: Vec<TokenPair>,
+        profit_threshold: u16,
+        max_slippage: u16,
+    ) -> Result<()> {
+        create_strategy::auto_handler(ctx, dexs, token_pairs, profit_threshold, max_slippage)
+    }
+
+    /// Update strategy parameters (creator-only)
+    /// Risk-increasing changes send an approved strategy back to Pending
+    /// An existing route must still fit the new DEXs and token pairs
+    /// Remaining accounts: the TokenPairIndex of every old and new token pair (writable)
+    pub fn update_strategy(
+        ctx: Context<UpdateStrategy>,
+        dexs: Vec<DexType>,
+        token_pairs: Vec<TokenPair>,
+        profit_threshold: u16,
+        max_slippage: u16,
+    ) -> Result<()> {
+        update_strategy::handler(ctx, dexs, token_pairs, profit_threshold, max_slippage)
     }
 
-    /// Reject a pending strategy (admin-only for hackathon)
+    /// Attach an ordered route (DEX, pool, input/output mint per hop) to a strategy
+    /// The route must cycle from WSOL back to WSOL using only the strategy's DEXs and pairs
+    /// Remaining accounts: the TokenPairIndex of every token pair if the strategy is Approved
+    pub fn init_route(ctx: Context<InitRoute>, hops: Vec<RouteHop>) -> Result<()> {
+        init_route::handler(ctx, hops)
+    }
+
+    /// Replace a strategy's route (route account is reallocated to fit)
+    /// Changing the route of an approved strategy sends it back to Pending
+    /// Remaining accounts: the TokenPairIndex of every token pair if the strategy is Approved
+    pub fn update_route(ctx: Context<UpdateRoute>, hops: Vec<RouteHop>) -> Result<()> {
+        update_route::handler(ctx, hops)
+    }
+
+    /// Set execution cooldown (slots) and per-epoch execution cap (creator-only, 0 disables)
+    pub fn set_rate_limits(
+        ctx: Context<SetRateLimits>,
+        min_slots_between_executions: u64,
+        max_executions_per_epoch: u32,
+    ) -> Result<()> {
+        set_rate_limits::handler(ctx, min_slots_between_executions, max_executions_per_epoch)
+    }
+
+    /// Set the largest flash loan a single execution may take (creator-only)
+    /// Raising the cap bumps the version and sends an approved strategy back to Pending
+    /// Remaining accounts: the TokenPairIndex of every token pair if the strategy is Approved
+    pub fn set_max_borrow(ctx: Context<SetMaxBorrow>, max_borrow: u64) -> Result<()> {
+        set_max_borrow::handler(ctx, max_borrow)
+    }
+
+    /// Approve a pending strategy (admin, or DAO governance via execute_proposal)
+    /// `version` must match the strategy version that was reviewed
+    pub fn approve_strategy(ctx: Context<ApproveStrategy>, version: u32) -> Result<()> {
+        approve_strategy::approve_strategy(ctx, version)
+    }
+
+    /// Reject a pending strategy (admin, or DAO governance via execute_proposal)
     pub fn reject_strategy(ctx: Context<RejectStrategy>) -> Result<()> {
         approve_strategy::reject_strategy(ctx)
     }
 
-    /// Update strategy metrics after execution (called by execution engine)
+    /// Pause an approved strategy (creator-only)
+    pub fn pause_strategy(ctx: Context<PauseStrategy>) -> Result<()> {
+        pause_strategy::handler(ctx)
+    }
+
+    /// Emergency pause a strategy (admin or governance, governance only in
+    /// governance-only mode; creator cannot resume)
+    pub fn emergency_pause_strategy(ctx: Context<EmergencyPauseStrategy>) -> Result<()> {
+        emergency_pause_strategy::handler(ctx)
+    }
+
+    /// Resume a paused strategy (creator, or governance for emergency pauses)
+    pub fn resume_strategy(ctx: Context<ResumeStrategy>) -> Result<()> {
+        resume_strategy::handler(ctx)
+    }
+
+    /// Permanently retire a strategy (creator-only)
+    pub fn retire_strategy(ctx: Context<RetireStrategy>) -> Result<()> {
+        retire_strategy::handler(ctx)
+    }
+
+    /// Close a retired or rejected strategy and reclaim rent (creator-only)
+    /// The strategy's route is closed with it if one was set
+    /// Remaining accounts: the TokenPairIndex of every token pair (writable)
+    pub fn close_strategy(ctx: Context<CloseStrategy>) -> Result<()> {
+        close_strategy::handler(ctx)
+    }
+
+    /// Create the discovery index for a token pair (permissionless)
+    /// Mints must be passed in canonical order (token_a < token_b)
+    pub fn init_token_pair_index(
+        ctx: Context<InitTokenPairIndex>,
+        token_a: Pubkey,
+        token_b: Pubkey,
+    ) -> Result<()> {
+        init_token_pair_index::handler(ctx, token_a, token_b)
+    }
+
+    /// Re-sync a strategy's entries in its token pair indexes (permissionless)
+    pub fn sync_strategy_index(ctx: Context<SyncStrategyIndex>) -> Result<()> {
+        sync_strategy_index::handler(ctx)
+    }
+
+    /// Refund (or forfeit, if rejected) a creation bond once the strategy leaves Pending
+    pub fn settle_bond(ctx: Context<SettleBond>) -> Result<()> {
+        settle_bond::handler(ctx)
+    }
+
+    /// Update strategy metrics after execution (execution engine CPI only)
     pub fn update_metrics(
         ctx: Context<UpdateMetrics>,
         profit: u64,
         success: bool,
+        volume_borrowed: u64,
     ) -> Result<()> {
-        update_metrics::handler(ctx, profit, success)
+        update_metrics::handler(ctx, profit, success, volume_borrowed)
     }
 
-    /// Get strategy statistics (view function)
+    /// Get strategy statistics, including trailing 7-day and 30-day totals (view function)
     pub fn get_strategy_stats(ctx: Context<GetStrategyStats>) -> Result<StrategyStats> {
         update_metrics::get_strategy_stats(ctx)
     }
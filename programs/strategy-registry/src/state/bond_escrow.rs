@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Anti-spam bond locked when a strategy is created
+/// PDA seeds: [b"bond", strategy.key()]
+///
+/// Holds `amount` lamports on top of its rent until the strategy leaves Pending.
+/// Approved or withdrawn (retired) strategies get the bond back, rejected ones
+/// forfeit it to the treasury.
+#[account]
+pub struct BondEscrow {
+    /// Strategy the bond was posted for
+    pub strategy: Pubkey,
+
+    /// Creator who posted the bond (receives refunds and escrow rent)
+    pub creator: Pubkey,
+
+    /// Bond amount in lamports (AdminConfig::creation_bond at creation time)
+    pub amount: u64,
+
+    /// Set once the bond has been refunded or forfeited
+    pub settled: bool,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BondEscrow {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // strategy
+        32 + // creator
+        8 + // amount
+        1 + // settled
+        1; // bump
+}
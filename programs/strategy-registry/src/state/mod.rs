@@ -1,3 +1,16 @@
 pub mod strategy;
+pub mod strategy_metrics;
+pub mod strategy_index;
+pub mod strategy_counter;
+pub mod bond_escrow;
+pub mod strategy_route;
 
 pub use strategy::*;
+pub use strategy_metrics::*;
+pub use strategy_index::*;
+pub use strategy_counter::*;
+pub use bond_escrow::*;
+pub use strategy_route::*;
+
+// Circuit breaker shared with the other MEVrebels programs
+pub use pause_flags::PauseFlags;
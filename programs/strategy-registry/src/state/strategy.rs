@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::constants::BASIS_POINTS;
+use crate::state::{StrategyMetrics, WindowTotals};
 
 /// Status of a strategy in the approval lifecycle
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
@@ -6,6 +8,10 @@ pub enum StrategyStatus {
     Pending,
     Approved,
     Rejected,
+    /// Temporarily halted by the creator or by governance (emergency pause)
+    Paused,
+    /// Permanently withdrawn by the creator, can be closed to reclaim rent
+    Retired,
 }
 
 /// Supported DEX types for arbitrage
@@ -19,7 +25,7 @@ pub enum DexType {
 }
 
 /// Token pair for arbitrage (e.g., SOL/USDC)
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub struct TokenPair {
     pub token_a: Pubkey,  // First token mint
     pub token_b: Pubkey,  // Second token mint
@@ -55,17 +61,46 @@ pub struct StrategyAccount {
     /// Cumulative profit generated (in lamports)
     pub total_profit: u64,
 
-    /// Total number of execution attempts
+    /// Total number of execution attempts (successes + reported failures)
     pub execution_count: u64,
 
     /// Number of successful executions
     pub success_count: u64,
 
-    /// Timestamp of last execution (Unix timestamp)
+    /// Timestamp of last successful execution (Unix timestamp)
     pub last_execution: i64,
 
     /// PDA bump seed
     pub bump: u8,
+
+    // Fields added after the first deployment are appended below bump
+    // so the original account layout is unchanged
+
+    /// Parameter version, starts at 1 and increments on every update_strategy,
+    /// route change and borrow cap increase
+    pub version: u32,
+
+    /// Set when governance paused the strategy (creator cannot resume)
+    pub emergency_paused: bool,
+
+    /// Minimum slots between successful executions (0 = no cooldown)
+    pub min_slots_between_executions: u64,
+
+    /// Maximum successful executions per Solana epoch (0 = unlimited)
+    pub max_executions_per_epoch: u32,
+
+    /// Slot of last successful execution
+    pub last_execution_slot: u64,
+
+    /// Epoch that epoch_execution_count refers to
+    pub execution_epoch: u64,
+
+    /// Successful executions during execution_epoch
+    pub epoch_execution_count: u32,
+
+    /// Largest flash loan a single execution may take (lamports)
+    /// Raising it on an approved strategy requires re-approval
+    pub max_borrow: u64,
 }
 
 impl StrategyAccount {
@@ -84,7 +119,15 @@ impl StrategyAccount {
         8 +     // execution_count (u64)
         8 +     // success_count (u64)
         8 +     // last_execution (i64)
-        1       // bump (u8)
+        1 +     // bump (u8)
+        4 +     // version (u32)
+        1 +     // emergency_paused (bool)
+        8 +     // min_slots_between_executions (u64)
+        4 +     // max_executions_per_epoch (u32)
+        8 +     // last_execution_slot (u64)
+        8 +     // execution_epoch (u64)
+        4 +     // epoch_execution_count (u32)
+        8       // max_borrow (u64)
     }
 
     /// Calculate success rate as percentage (0-100)
@@ -96,10 +139,78 @@ impl StrategyAccount {
     }
 
     /// Check if strategy is approved and ready for execution
+    /// Paused and Retired strategies are never executable
     pub fn is_executable(&self) -> bool {
         self.status == StrategyStatus::Approved
     }
 
+    /// Minimum net profit for a given borrow size
+    /// Formula: borrow_amount * profit_threshold / 10000
+    pub fn min_profit_for(&self, borrow_amount: u64) -> u64 {
+        ((borrow_amount as u128 * self.profit_threshold as u128) / BASIS_POINTS as u128) as u64
+    }
+
+    /// Minimum acceptable output for a swap hop under max_slippage
+    /// Formula: expected_output * (10000 - max_slippage) / 10000
+    pub fn min_output_for(&self, expected_output: u64) -> u64 {
+        let tolerance = BASIS_POINTS.saturating_sub(self.max_slippage) as u128;
+        ((expected_output as u128 * tolerance) / BASIS_POINTS as u128) as u64
+    }
+
+    /// Check if `borrow_amount` is within the strategy's borrow cap
+    pub fn allows_borrow(&self, borrow_amount: u64) -> bool {
+        borrow_amount <= self.max_borrow
+    }
+
+    /// Check if the execution cooldown is still running at `slot`
+    pub fn is_cooling_down(&self, slot: u64) -> bool {
+        self.min_slots_between_executions > 0
+            && self.success_count > 0
+            && slot < self.last_execution_slot.saturating_add(self.min_slots_between_executions)
+    }
+
+    /// Check if the per-epoch execution cap has been reached in `epoch`
+    pub fn epoch_limit_reached(&self, epoch: u64) -> bool {
+        self.max_executions_per_epoch > 0
+            && self.execution_epoch == epoch
+            && self.epoch_execution_count >= self.max_executions_per_epoch
+    }
+
+    /// Record a successful execution for rate limiting
+    pub fn record_execution_slot(&mut self, slot: u64, epoch: u64) {
+        if self.execution_epoch != epoch {
+            self.execution_epoch = epoch;
+            self.epoch_execution_count = 0;
+        }
+        self.epoch_execution_count = self.epoch_execution_count.saturating_add(1);
+        self.last_execution_slot = slot;
+    }
+
+    /// Bitmask of the strategy's DEXs (see DexType::mask)
+    pub fn dex_mask(&self) -> u8 {
+        self.dexs.iter().fold(0, |mask, dex| mask | dex.mask())
+    }
+
+    /// Check if strategy has reached a terminal state and can be closed
+    pub fn is_closable(&self) -> bool {
+        self.status == StrategyStatus::Retired || self.status == StrategyStatus::Rejected
+    }
+
+    /// Check if new parameters loosen the strategy's risk profile
+    /// (higher slippage, lower profit floor, or DEXs/token pairs not previously approved)
+    pub fn is_risk_increasing(
+        &self,
+        dexs: &[DexType],
+        token_pairs: &[TokenPair],
+        profit_threshold: u16,
+        max_slippage: u16,
+    ) -> bool {
+        max_slippage > self.max_slippage
+            || profit_threshold < self.profit_threshold
+            || dexs.iter().any(|dex| !self.dexs.contains(dex))
+            || token_pairs.iter().any(|pair| !self.token_pairs.contains(pair))
+    }
+
     /// Helper function to get seeds array length for PDA derivation
     /// Note: Actual seeds must be constructed at call site to avoid temporary value issues
     pub const SEED_LEN: usize = 3;
@@ -116,6 +227,22 @@ pub struct StrategyStats {
     pub success_rate: u8,
     pub last_execution: i64,
     pub status: StrategyStatus,
+    pub version: u32,
+    /// Totals over the trailing 7 days
+    pub last_7d: WindowTotals,
+    /// Totals over the trailing 30 days
+    pub last_30d: WindowTotals,
+}
+
+impl StrategyStats {
+    /// Build stats including trailing windows from the strategy's metrics account
+    pub fn with_metrics(strategy: &StrategyAccount, metrics: &StrategyMetrics, now: i64) -> Self {
+        Self {
+            last_7d: metrics.window(now, 7),
+            last_30d: metrics.window(now, 30),
+            ..Self::from(strategy)
+        }
+    }
 }
 
 impl From<&StrategyAccount> for StrategyStats {
@@ -129,6 +256,9 @@ impl From<&StrategyAccount> for StrategyStats {
             success_rate: strategy.success_rate(),
             last_execution: strategy.last_execution,
             status: strategy.status.clone(),
+            version: strategy.version,
+            last_7d: WindowTotals::default(),
+            last_30d: WindowTotals::default(),
         }
     }
 }
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::error::StrategyError;
+
+/// Global strategy counter
+/// PDA seeds: [b"counter"]
+#[account]
+pub struct StrategyCounter {
+    /// Total strategies ever created (closing does not decrement)
+    pub total_strategies: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl StrategyCounter {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // total_strategies
+        1; // bump
+
+    /// Count a newly created strategy
+    pub fn increment(&mut self) -> Result<()> {
+        self.total_strategies = self.total_strategies
+            .checked_add(1)
+            .ok_or(StrategyError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
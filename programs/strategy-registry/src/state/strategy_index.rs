@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_PAIR_INDEX_ENTRIES;
+use crate::error::StrategyError;
+use crate::state::{DexType, TokenPair};
+
+/// Per-creator list of strategies
+/// PDA seeds: [b"creator_index", creator.key()]
+#[account]
+pub struct CreatorIndex {
+    /// Creator whose strategies are listed
+    pub creator: Pubkey,
+
+    /// Strategy accounts owned by the creator (removed on close)
+    pub strategies: Vec<Pubkey>,
+
+    /// Next ID handed out by create_strategy_auto
+    /// (always above every ID the creator has used, explicit or automatic)
+    pub next_strategy_id: u64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl CreatorIndex {
+    /// Calculate space needed for an index listing `num_strategies` strategies
+    pub const fn space(num_strategies: usize) -> usize {
+        8 +     // discriminator
+        32 +    // creator
+        4 + (num_strategies * 32) + // strategies
+        8 +     // next_strategy_id
+        1       // bump
+    }
+
+    /// Add a strategy to the index (no-op if already present)
+    /// The account must already have room for it (see create_strategy)
+    pub fn insert(&mut self, strategy: Pubkey) {
+        if !self.strategies.contains(&strategy) {
+            self.strategies.push(strategy);
+        }
+    }
+
+    /// Remove a strategy from the index
+    pub fn remove(&mut self, strategy: &Pubkey) {
+        self.strategies.retain(|entry| entry != strategy);
+    }
+
+    /// Mark `strategy_id` as used so automatic IDs never collide with it
+    pub fn reserve_id(&mut self, strategy_id: u64) -> Result<()> {
+        let next = strategy_id
+            .checked_add(1)
+            .ok_or(StrategyError::StrategyIdOverflow)?;
+        self.next_strategy_id = self.next_strategy_id.max(next);
+        Ok(())
+    }
+}
+
+/// Strategy entry in a token pair index
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PairIndexEntry {
+    /// Strategy account
+    pub strategy: Pubkey,
+
+    /// DEXs used by the strategy (bit per DexType, see DexType::mask)
+    pub dex_mask: u8,
+
+    /// Whether the strategy was executable (Approved) when last synced
+    pub executable: bool,
+}
+
+impl PairIndexEntry {
+    pub const LEN: usize = 32 + // strategy
+        1 + // dex_mask
+        1; // executable
+}
+
+/// Per-token-pair list of strategies, so clients can find every strategy
+/// for e.g. SOL/USDC on Orca without a getProgramAccounts scan
+/// PDA seeds: [b"pair_index", token_a, token_b] with token_a < token_b
+#[account]
+pub struct TokenPairIndex {
+    /// Lower mint of the pair (canonical ordering)
+    pub token_a: Pubkey,
+
+    /// Higher mint of the pair (canonical ordering)
+    pub token_b: Pubkey,
+
+    /// Strategies trading this pair
+    pub entries: Vec<PairIndexEntry>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl TokenPairIndex {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // token_a
+        32 + // token_b
+        4 + (PairIndexEntry::LEN * MAX_PAIR_INDEX_ENTRIES) + // entries
+        1; // bump
+
+    /// Whether this index covers `pair` (in either token order)
+    pub fn covers(&self, pair: &TokenPair) -> bool {
+        (pair.token_a == self.token_a && pair.token_b == self.token_b)
+            || (pair.token_a == self.token_b && pair.token_b == self.token_a)
+    }
+
+    /// Insert or refresh a strategy's entry, returning whether it is listed
+    ///
+    /// Once the index is full, non-executable entries are best effort: a new
+    /// one is left out, and an executable strategy evicts one to take its
+    /// place. Unapproved strategies therefore cannot block creation or
+    /// crowd approved ones out of the index.
+    pub fn upsert(&mut self, strategy: Pubkey, dex_mask: u8, executable: bool) -> Result<bool> {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.strategy == strategy) {
+            entry.dex_mask = dex_mask;
+            entry.executable = executable;
+            return Ok(true);
+        }
+
+        if self.entries.len() >= MAX_PAIR_INDEX_ENTRIES {
+            if !executable {
+                return Ok(false);
+            }
+            let evicted = self
+                .entries
+                .iter()
+                .position(|entry| !entry.executable)
+                .ok_or(StrategyError::IndexFull)?;
+            self.entries.swap_remove(evicted);
+        }
+
+        self.entries.push(PairIndexEntry {
+            strategy,
+            dex_mask,
+            executable,
+        });
+        Ok(true)
+    }
+
+    /// Remove a strategy's entry
+    pub fn remove(&mut self, strategy: &Pubkey) {
+        self.entries.retain(|entry| entry.strategy != *strategy);
+    }
+}
+
+impl DexType {
+    /// Bit for this DEX in PairIndexEntry::dex_mask
+    pub fn mask(&self) -> u8 {
+        1 << (self.clone() as u8)
+    }
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::constants::{METRICS_BUCKET_COUNT, METRICS_BUCKET_SECONDS};
+use crate::error::StrategyError;
+
+/// Aggregated performance for one time bucket (one day by default)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct MetricsBucket {
+    /// Bucket index (unix_timestamp / METRICS_BUCKET_SECONDS), 0 if never written
+    pub period: i64,
+
+    /// Profit generated in this bucket (in lamports)
+    pub profit: u64,
+
+    /// Execution attempts in this bucket (successes + reported failures)
+    pub executions: u64,
+
+    /// Amount borrowed for executions in this bucket (in lamports)
+    pub volume_borrowed: u64,
+}
+
+impl MetricsBucket {
+    pub const LEN: usize = 8 + // period
+        8 + // profit
+        8 + // executions
+        8; // volume_borrowed
+}
+
+/// Rolling performance metrics for a strategy (companion to StrategyAccount)
+/// PDA seeds: [b"metrics", strategy.key()]
+///
+/// Ring buffer of METRICS_BUCKET_COUNT buckets indexed by period, so recent
+/// activity can be told apart from lifetime totals.
+#[account]
+pub struct StrategyMetrics {
+    /// Strategy these metrics belong to
+    pub strategy: Pubkey,
+
+    /// Ring buffer of time buckets (slot = period % METRICS_BUCKET_COUNT)
+    pub buckets: [MetricsBucket; METRICS_BUCKET_COUNT],
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Totals over a trailing window of buckets
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct WindowTotals {
+    pub profit: u64,
+    pub executions: u64,
+    pub volume_borrowed: u64,
+}
+
+impl StrategyMetrics {
+    /// Calculate space needed for this account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // strategy
+        (MetricsBucket::LEN * METRICS_BUCKET_COUNT) + // buckets
+        1; // bump
+
+    /// Bucket period for a unix timestamp
+    pub fn period_for(timestamp: i64) -> i64 {
+        timestamp.div_euclid(METRICS_BUCKET_SECONDS)
+    }
+
+    /// Record an execution in the bucket for `timestamp`, resetting stale slots
+    pub fn record(
+        &mut self,
+        timestamp: i64,
+        profit: u64,
+        volume_borrowed: u64,
+    ) -> Result<()> {
+        let period = Self::period_for(timestamp);
+        let slot = period.rem_euclid(METRICS_BUCKET_COUNT as i64) as usize;
+        let bucket = &mut self.buckets[slot];
+
+        if bucket.period != period {
+            *bucket = MetricsBucket {
+                period,
+                ..MetricsBucket::default()
+            };
+        }
+
+        bucket.profit = bucket.profit
+            .checked_add(profit)
+            .ok_or(StrategyError::ArithmeticOverflow)?;
+        bucket.executions = bucket.executions
+            .checked_add(1)
+            .ok_or(StrategyError::ArithmeticOverflow)?;
+        bucket.volume_borrowed = bucket.volume_borrowed
+            .checked_add(volume_borrowed)
+            .ok_or(StrategyError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Sum of the last `periods` buckets ending at `timestamp` (inclusive)
+    pub fn window(&self, timestamp: i64, periods: i64) -> WindowTotals {
+        let current = Self::period_for(timestamp);
+        let oldest = current - periods + 1;
+
+        self.buckets
+            .iter()
+            .filter(|bucket| bucket.executions > 0 && bucket.period >= oldest && bucket.period <= current)
+            .fold(WindowTotals::default(), |totals, bucket| WindowTotals {
+                profit: totals.profit.saturating_add(bucket.profit),
+                executions: totals.executions.saturating_add(bucket.executions),
+                volume_borrowed: totals.volume_borrowed.saturating_add(bucket.volume_borrowed),
+            })
+    }
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_ROUTE_HOPS, MIN_ROUTE_HOPS, WSOL_MINT};
+use crate::error::StrategyError;
+use crate::state::{DexType, StrategyAccount};
+
+/// One swap in a strategy route
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct RouteHop {
+    /// DEX the swap executes on
+    pub dex: DexType,
+
+    /// Pool (AMM / whirlpool / market) account used for the swap
+    pub pool: Pubkey,
+
+    /// Mint sold into the pool
+    pub input_mint: Pubkey,
+
+    /// Mint received from the pool
+    pub output_mint: Pubkey,
+}
+
+impl RouteHop {
+    pub const LEN: usize = 1 + // dex
+        32 + // pool
+        32 + // input_mint
+        32; // output_mint
+}
+
+/// Ordered execution route for a strategy (companion to StrategyAccount)
+/// PDA seeds: [b"route", strategy.key()]
+///
+/// Sized to the current route and reallocated by update_route. The route must
+/// be a closed cycle starting and ending at WSOL (the flash-loaned mint), and
+/// only use the strategy's approved DEXs and token pairs.
+#[account]
+pub struct StrategyRoute {
+    /// Strategy this route belongs to
+    pub strategy: Pubkey,
+
+    /// Ordered hops; hops[0].input_mint is the borrowed mint
+    pub hops: Vec<RouteHop>,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl StrategyRoute {
+    /// Calculate space needed for a route with `num_hops` hops
+    pub const fn space(num_hops: usize) -> usize {
+        8 +     // discriminator
+        32 +    // strategy
+        4 + (num_hops * RouteHop::LEN) + // hops
+        1       // bump
+    }
+
+    /// Validate that hops chain together, cycle back to the borrowed WSOL and
+    /// stay within the DEXs and token pairs the strategy was approved for
+    pub fn validate_hops(hops: &[RouteHop], strategy: &StrategyAccount) -> Result<()> {
+        require!(
+            hops.len() >= MIN_ROUTE_HOPS && hops.len() <= MAX_ROUTE_HOPS,
+            StrategyError::InvalidRouteLength
+        );
+
+        for hop in hops {
+            require!(
+                hop.input_mint != hop.output_mint,
+                StrategyError::InvalidRouteHop
+            );
+        }
+
+        // Each hop must sell what the previous hop bought
+        for window in hops.windows(2) {
+            require!(
+                window[0].output_mint == window[1].input_mint,
+                StrategyError::RouteNotContinuous
+            );
+        }
+
+        // Last hop must return to the borrowed mint so the loan can be repaid
+        let first = &hops[0];
+        let last = &hops[hops.len() - 1];
+        require!(
+            last.output_mint == first.input_mint,
+            StrategyError::RouteNotClosed
+        );
+
+        // Flash loans are WSOL only
+        require!(
+            first.input_mint == WSOL_MINT,
+            StrategyError::RouteNotFromWsol
+        );
+
+        // Hops may only use what governance reviewed
+        for hop in hops {
+            require!(
+                strategy.dexs.contains(&hop.dex),
+                StrategyError::RouteDexNotAllowed
+            );
+            require!(
+                strategy.token_pairs.iter().any(|pair| {
+                    (pair.token_a == hop.input_mint && pair.token_b == hop.output_mint)
+                        || (pair.token_a == hop.output_mint && pair.token_b == hop.input_mint)
+                }),
+                StrategyError::RoutePairNotAllowed
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -62,6 +62,17 @@ describe("DAO Governance", () => {
   const QUORUM_PERCENTAGE = 10; // 10%
   const VOTING_PERIOD = 3 * 24 * 60 * 60; // 3 days
 
+  // SOL/USDC token pair index (required when creating, updating or closing strategies)
+  const [pairMintA, pairMintB] = [
+    new PublicKey("So11111111111111111111111111111111111111112"),
+    new PublicKey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+  ].sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()));
+  const [solUsdcPairIndex] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pair_index"), pairMintA.toBuffer(), pairMintB.toBuffer()],
+    strategyProgram.programId
+  );
+  const pairIndexAccounts = [{ pubkey: solUsdcPairIndex, isWritable: true, isSigner: false }];
+
   before(async () => {
     // Generate test keypairs
     voter1 = Keypair.generate();
@@ -125,6 +136,14 @@ describe("DAO Governance", () => {
       [Buffer.from("config")],
       strategyProgram.programId
     );
+
+    // Create the SOL/USDC pair index once (shared across test files)
+    if (!(await provider.connection.getAccountInfo(solUsdcPairIndex))) {
+      await strategyProgram.methods
+        .initTokenPairIndex(pairMintA, pairMintB)
+        .accounts({ payer: authority.publicKey })
+        .rpc();
+    }
   });
 
   describe("Initialization", () => {
@@ -306,6 +325,7 @@ describe("DAO Governance", () => {
           creator: proposer.publicKey,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(pairIndexAccounts)
         .signers([proposer])
         .rpc();
 
@@ -358,11 +378,13 @@ describe("DAO Governance", () => {
       await program.methods
         .createProposal(
           strategyPda,
-          "Approve SOL/USDC arbitrage strategy"
+          "Approve SOL/USDC arbitrage strategy",
+          { strategyApproval: {} }
         )
         .accounts({
           proposal: proposalPda,
           governanceConfig,
+          strategy: strategyPda,
           proposer: proposer.publicKey,
           proposerTokenAccount: proposerAta,
           rebelMint: rebelMint.publicKey,
@@ -376,6 +398,8 @@ describe("DAO Governance", () => {
       expect(proposal.proposalId.toNumber()).to.equal(proposalId);
       expect(proposal.proposer.toString()).to.equal(proposer.publicKey.toString());
       expect(proposal.strategyToApprove.toString()).to.equal(strategyPda.toString());
+      const strategyAccount = await strategyProgram.account.strategyAccount.fetch(strategyPda);
+      expect(proposal.strategyVersion).to.equal(strategyAccount.version);
       expect(proposal.votesYes.toString()).to.equal("0");
       expect(proposal.votesNo.toString()).to.equal("0");
 
@@ -410,11 +434,13 @@ describe("DAO Governance", () => {
         await program.methods
           .createProposal(
             strategyPda,
-            "Should fail - no tokens"
+            "Should fail - no tokens",
+            { strategyApproval: {} }
           )
           .accounts({
             proposal: proposalPda,
             governanceConfig,
+            strategy: strategyPda,
             proposer: poorUser.publicKey,
             proposerTokenAccount: poorUserAta,
             rebelMint: rebelMint.publicKey,
@@ -550,6 +576,51 @@ describe("DAO Governance", () => {
       console.log("✅ Proposal execution structure verified");
       console.log("   Full execution tested in integration suite");
     });
+
+    it("Proposes registry configuration changes for the governance PDA to apply", async () => {
+      const config = await program.account.governanceConfig.fetch(governanceConfig);
+      const [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), config.nextProposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const proposerAta = await getAssociatedTokenAddress(rebelMint.publicKey, proposer.publicKey);
+
+      await program.methods
+        .createRegistryProposal("Lift the registry pause", { setPause: { groups: 0 } })
+        .accounts({
+          proposal: proposalPda,
+          governanceConfig,
+          proposer: proposer.publicKey,
+          proposerTokenAccount: proposerAta,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([proposer])
+        .rpc();
+
+      const proposal = await program.account.proposal.fetch(proposalPda);
+      expect(proposal.proposalType).to.deep.equal({ registryConfig: {} });
+      expect(proposal.registryAction).to.deep.equal({ setPause: { groups: 0 } });
+
+      // Applied through the registry's set_pause once the vote passes
+      try {
+        await program.methods
+          .executeProposal()
+          .accounts({
+            governanceConfig,
+            proposal: proposalPda,
+            strategyAccount: adminConfig,
+            adminConfig,
+            strategyRegistryProgram: strategyProgram.programId,
+            executor: authority.publicKey,
+          })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("VotingStillActive");
+      }
+
+      console.log("✅ Registry config proposal created");
+    });
   });
 
   describe("Treasury", () => {
@@ -624,6 +695,293 @@ describe("DAO Governance", () => {
     });
   });
 
+  describe("Circuit Breaker", () => {
+    const PAUSE_PROPOSE = 1 << 0;
+    const PAUSE_VOTE = 1 << 1;
+    const PAUSE_EXECUTE = 1 << 2;
+    const guardian = Keypair.generate();
+    let user: Keypair;
+    let userAta: PublicKey;
+    let proposalPda: PublicKey;
+
+    const setPause = (groups: number, signer?: Keypair) =>
+      program.methods
+        .setPause(groups)
+        .accounts({
+          governanceConfig,
+          authority: signer ? signer.publicKey : authority.publicKey,
+        })
+        .signers(signer ? [signer] : [])
+        .rpc();
+
+    before(async () => {
+      user = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(user.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL)
+      );
+      userAta = await createAssociatedTokenAccount(
+        provider.connection,
+        user,
+        rebelMint.publicKey,
+        user.publicKey
+      );
+      [proposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+    });
+
+    after(async () => {
+      await setPause(0, guardian);
+    });
+
+    it("Only the admin can set the guardian", async () => {
+      try {
+        await program.methods
+          .setGuardian(guardian.publicKey, new anchor.BN(24 * 60 * 60))
+          .accounts({ governanceConfig, admin: user.publicKey })
+          .signers([user])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedGovernance");
+      }
+
+      await program.methods
+        .setGuardian(guardian.publicKey, new anchor.BN(24 * 60 * 60))
+        .accounts({ governanceConfig, admin: authority.publicKey })
+        .rpc();
+
+      const config = await program.account.governanceConfig.fetch(governanceConfig);
+      expect(config.pause.guardian.toString()).to.equal(guardian.publicKey.toString());
+      expect(config.pause.guardianPauseSeconds.toNumber()).to.equal(24 * 60 * 60);
+    });
+
+    it("Caps the guardian pause window at 24 hours", async () => {
+      try {
+        await program.methods
+          .setGuardian(guardian.publicKey, new anchor.BN(24 * 60 * 60 + 1))
+          .accounts({ governanceConfig, admin: authority.publicKey })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidGuardianPause");
+      }
+    });
+
+    it("Guardian pause blocks proposal creation for a limited time", async () => {
+      await setPause(PAUSE_PROPOSE, guardian);
+
+      const config = await program.account.governanceConfig.fetch(governanceConfig);
+      expect(config.pause.guardianPaused).to.equal(PAUSE_PROPOSE);
+      expect(config.pause.adminPaused).to.equal(0);
+      const now = Math.floor(Date.now() / 1000);
+      expect(config.pause.guardianExpiresAt.toNumber()).to.be.closeTo(now + 24 * 60 * 60, 120);
+
+      const [nextProposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), config.nextProposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createProposal(strategyPda, "Paused", { strategyApproval: {} })
+          .accounts({
+            proposal: nextProposalPda,
+            governanceConfig,
+            strategy: strategyPda,
+            proposer: user.publicKey,
+            proposerTokenAccount: userAta,
+            rebelMint: rebelMint.publicKey,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("ProgramPaused");
+      }
+
+      console.log("✅ Guardian pause blocks create_proposal");
+    });
+
+    it("Guardian pause blocks voting", async () => {
+      await setPause(PAUSE_VOTE, guardian);
+
+      const config = await program.account.governanceConfig.fetch(governanceConfig);
+      expect(config.pause.guardianPaused).to.equal(PAUSE_VOTE);
+      expect(config.pause.adminPaused).to.equal(0);
+
+      const [voteRecordPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote_record"), proposalPda.toBuffer(), user.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .castVote({ yes: {} })
+          .accounts({
+            proposal: proposalPda,
+            voteRecord: voteRecordPda,
+            voter: user.publicKey,
+            voterTokenAccount: userAta,
+            rebelMint: rebelMint.publicKey,
+            governanceConfig,
+            systemProgram: SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([user])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("ProgramPaused");
+      }
+
+      console.log("✅ Guardian pause blocks cast_vote");
+    });
+
+    it("Guardian pause blocks proposal execution", async () => {
+      await setPause(PAUSE_EXECUTE, guardian);
+
+      try {
+        await program.methods
+          .executeProposal()
+          .accounts({
+            governanceConfig,
+            proposal: proposalPda,
+            strategyAccount: strategyPda,
+            adminConfig,
+            strategyRegistryProgram: strategyProgram.programId,
+            executor: authority.publicKey,
+          })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("ProgramPaused");
+      }
+
+      console.log("✅ Guardian pause blocks execute_proposal");
+    });
+
+    it("Pause proposals can be created and voted on while governance is paused", async () => {
+      await setPause(PAUSE_PROPOSE | PAUSE_VOTE | PAUSE_EXECUTE, guardian);
+
+      const config = await program.account.governanceConfig.fetch(governanceConfig);
+      const [pauseProposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), config.nextProposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      const proposerAta = await getAssociatedTokenAddress(rebelMint.publicKey, proposer.publicKey);
+
+      await program.methods
+        .createPauseProposal("Pause flash loan borrowing", { target: { flashLoan: {} }, groups: 1 })
+        .accounts({
+          proposal: pauseProposalPda,
+          governanceConfig,
+          proposer: proposer.publicKey,
+          proposerTokenAccount: proposerAta,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([proposer])
+        .rpc();
+
+      const proposal = await program.account.proposal.fetch(pauseProposalPda);
+      expect(proposal.proposalType).to.deep.equal({ pauseChange: {} });
+      expect(proposal.pauseAction).to.deep.equal({ target: { flashLoan: {} }, groups: 1 });
+
+      const [voteRecordPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote_record"), pauseProposalPda.toBuffer(), proposer.publicKey.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .castVote({ yes: {} })
+        .accounts({
+          proposal: pauseProposalPda,
+          voteRecord: voteRecordPda,
+          voter: proposer.publicKey,
+          voterTokenAccount: proposerAta,
+          rebelMint: rebelMint.publicKey,
+          governanceConfig,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([proposer])
+        .rpc();
+
+      // Execution is only held back by the voting period, not by the pause
+      try {
+        await program.methods
+          .executeProposal()
+          .accounts({
+            governanceConfig,
+            proposal: pauseProposalPda,
+            strategyAccount: adminConfig,
+            adminConfig,
+            strategyRegistryProgram: strategyProgram.programId,
+            executor: authority.publicKey,
+          })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("VotingStillActive");
+      }
+
+      console.log("✅ Pause proposals bypass the governance pause");
+    });
+
+    it("Admin cannot set the unexpiring pause (PauseChange proposals only)", async () => {
+      try {
+        await setPause(PAUSE_VOTE);
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedGovernance");
+      }
+
+      const config = await program.account.governanceConfig.fetch(governanceConfig);
+      expect(config.pause.adminPaused).to.equal(0);
+    });
+
+    it("Rejects pauses from other signers and unknown groups", async () => {
+      try {
+        await setPause(PAUSE_VOTE, user);
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedGovernance");
+      }
+
+      try {
+        await setPause(1 << 3, guardian);
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidPauseGroups");
+      }
+
+      // Flash loan pause groups are PAUSE_BORROW | PAUSE_DEPOSIT
+      const config = await program.account.governanceConfig.fetch(governanceConfig);
+      const [nextProposalPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proposal"), config.nextProposalId.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+      try {
+        await program.methods
+          .createPauseProposal("Unknown group", { target: { flashLoan: {} }, groups: 1 << 2 })
+          .accounts({
+            proposal: nextProposalPda,
+            governanceConfig,
+            proposer: proposer.publicKey,
+            proposerTokenAccount: await getAssociatedTokenAddress(rebelMint.publicKey, proposer.publicKey),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([proposer])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidPauseGroups");
+      }
+    });
+  });
+
   describe("Error Handling", () => {
     it("Validates governance configuration integrity", async () => {
       const config = await program.account.governanceConfig.fetch(governanceConfig);
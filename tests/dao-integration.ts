@@ -57,6 +57,20 @@ describe("DAO Integration", () => {
   const SOL_MINT = new PublicKey("So11111111111111111111111111111111111111112");
   const USDC_MINT = new PublicKey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
 
+  // SOL/USDC token pair index (required when creating, updating or closing strategies)
+  const [pairMintA, pairMintB] = [
+    new PublicKey("So11111111111111111111111111111111111111112"),
+    new PublicKey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+  ].sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()));
+  const [solUsdcPairIndex] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pair_index"), pairMintA.toBuffer(), pairMintB.toBuffer()],
+    strategyProgram.programId
+  );
+  const pairIndexAccounts = [{ pubkey: solUsdcPairIndex, isWritable: true, isSigner: false }];
+
+  const strategyVersion = async (strategyPda: PublicKey) =>
+    (await strategyProgram.account.strategyAccount.fetch(strategyPda)).version;
+
   before(async () => {
     // Generate test keypairs
     strategist = Keypair.generate();
@@ -186,6 +200,14 @@ describe("DAO Integration", () => {
       ],
       strategyProgram.programId
     );
+
+    // Create the SOL/USDC pair index once (shared across test files)
+    if (!(await provider.connection.getAccountInfo(solUsdcPairIndex))) {
+      await strategyProgram.methods
+        .initTokenPairIndex(pairMintA, pairMintB)
+        .accounts({ payer: authority.publicKey })
+        .rpc();
+    }
   });
 
   describe("Full Governance Flow", () => {
@@ -207,6 +229,7 @@ describe("DAO Integration", () => {
           creator: strategist.publicKey,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(pairIndexAccounts)
         .signers([strategist])
         .rpc();
 
@@ -259,7 +282,7 @@ describe("DAO Integration", () => {
       // Instead, let's directly approve strategy via admin (bypass governance for test)
       console.log("\n   📝 Using admin approval for integration test...");
       await strategyProgram.methods
-        .approveStrategy()
+        .approveStrategy(await strategyVersion(strategyPda))
         .accounts({
           adminConfig,
           strategy: strategyPda,
@@ -42,6 +42,16 @@ describe("Execution Engine", () => {
   let executor: Keypair;
   let adminConfig: PublicKey;
   let strategy: PublicKey;
+  let executorStats: PublicKey;
+
+  const metricsFor = (strategyPda: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("metrics"), strategyPda.toBuffer()],
+      strategyProgram.programId
+    )[0];
+
+  const strategyVersion = async (strategyPda: PublicKey) =>
+    (await strategyProgram.account.strategyAccount.fetch(strategyPda)).version;
 
   // Test constants
   const STRATEGY_ID = new BN(1);
@@ -52,6 +62,17 @@ describe("Execution Engine", () => {
   const SOL_MINT = new PublicKey("So11111111111111111111111111111111111111112");
   const USDC_MINT = new PublicKey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
 
+  // SOL/USDC token pair index (required when creating, updating or closing strategies)
+  const [pairMintA, pairMintB] = [
+    new PublicKey("So11111111111111111111111111111111111111112"),
+    new PublicKey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+  ].sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()));
+  const [solUsdcPairIndex] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pair_index"), pairMintA.toBuffer(), pairMintB.toBuffer()],
+    strategyProgram.programId
+  );
+  const pairIndexAccounts = [{ pubkey: solUsdcPairIndex, isWritable: true, isSigner: false }];
+
   before(async () => {
     // Generate test keypairs
     treasury = Keypair.generate();
@@ -116,10 +137,23 @@ describe("Execution Engine", () => {
       true // allowOwnerOffCurve
     );
 
+    [executorStats] = PublicKey.findProgramAddressSync(
+      [Buffer.from("executor_stats"), executor.publicKey.toBuffer()],
+      executionProgram.programId
+    );
+
     console.log("Flash Loan Pool:", flashLoanPool.toString());
     console.log("Pool Authority:", flashLoanPoolAuthority.toString());
     console.log("Pool Token Account:", flashLoanPoolTokenAccount.toString());
     console.log("Vault Token Account:", vaultTokenAccount.toString());
+
+    // Create the SOL/USDC pair index once (shared across test files)
+    if (!(await provider.connection.getAccountInfo(solUsdcPairIndex))) {
+      await strategyProgram.methods
+        .initTokenPairIndex(pairMintA, pairMintB)
+        .accounts({ payer: payer.publicKey })
+        .rpc();
+    }
   });
 
   describe("Vault Initialization", () => {
@@ -273,7 +307,7 @@ describe("Execution Engine", () => {
             { tokenA: SOL_MINT, tokenB: USDC_MINT },
             { tokenA: USDC_MINT, tokenB: SOL_MINT },
           ],
-          800, // 8% profit threshold
+          500, // 5% profit threshold (mock arbitrage nets ~7.9%)
           50   // 0.5% max slippage
         )
         .accounts({
@@ -281,12 +315,13 @@ describe("Execution Engine", () => {
           creator: creator.publicKey,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(pairIndexAccounts)
         .signers([creator])
         .rpc();
 
       // Approve strategy
       await strategyProgram.methods
-        .approveStrategy()
+        .approveStrategy(await strategyVersion(strategy))
         .accounts({
           adminConfig,
           strategy,
@@ -329,10 +364,13 @@ describe("Execution Engine", () => {
           vault,
           profitConfig,
           strategy,
+          strategyMetrics: metricsFor(strategy),
           creator: creator.publicKey,
           executor: executor.publicKey,
+          executorStats,
           treasury: treasury.publicKey,
           strategyRegistryProgram: strategyProgram.programId,
+          registryConfig: adminConfig,
           daoGovernanceProgram: daoGovernanceProgram.programId,
           flashLoanPool,
           flashLoanPoolAuthority,
@@ -358,14 +396,14 @@ describe("Execution Engine", () => {
       const vaultBalanceAfter = await provider.connection.getBalance(vault);
 
       // Calculate expected profit distribution
-      // 10 SOL borrowed → 1000 USDC → 10.8 SOL
-      // Gross profit: 0.8 SOL
+      // 10 SOL borrowed → ~999 USDC → ~10.778 SOL (quoted 10.8 SOL, ~0.1% price impact per hop)
+      // Gross profit: ~0.778 SOL
       // Flashloan fee: 0.009 SOL (0.09%)
-      // Net profit: 0.791 SOL
-      const expectedNetProfit = 0.791 * LAMPORTS_PER_SOL;
-      const expectedCreatorShare = expectedNetProfit * 0.4; // 0.3164 SOL
-      const expectedExecutorShare = expectedNetProfit * 0.4; // 0.3164 SOL
-      const expectedTreasuryShare = expectedNetProfit * 0.2; // 0.1582 SOL
+      // Net profit: ~0.769 SOL
+      const expectedNetProfit = 0.7694 * LAMPORTS_PER_SOL;
+      const expectedCreatorShare = expectedNetProfit * 0.4; // ~0.3078 SOL
+      const expectedExecutorShare = expectedNetProfit * 0.4; // ~0.3078 SOL
+      const expectedTreasuryShare = expectedNetProfit * 0.2; // ~0.1539 SOL
 
       // Verify creator received profit
       const creatorProfit = creatorBalanceAfter - creatorBalanceBefore;
@@ -393,6 +431,20 @@ describe("Execution Engine", () => {
       console.log(`  Total profit distributed: ${vaultAccount.totalProfitDistributed.toNumber() / LAMPORTS_PER_SOL} SOL`);
     });
 
+    it("Updates executor stats", async () => {
+      const stats = await executionProgram.account.executorStats.fetch(executorStats);
+
+      assert.equal(stats.executor.toString(), executor.publicKey.toString());
+      assert.equal(stats.totalExecutions.toNumber(), 1);
+      assert.isAbove(stats.totalEarned.toNumber(), 0);
+      assert.isAbove(stats.lastExecutionSlot.toNumber(), 0);
+      assert.equal(stats.strategyBreakdown.length, 1);
+      assert.equal(stats.strategyBreakdown[0].strategy.toString(), strategy.toString());
+      assert.equal(stats.strategyBreakdown[0].executions.toNumber(), 1);
+
+      console.log(`✓ Executor stats: ${stats.totalExecutions} executions, ${stats.totalEarned.toNumber() / LAMPORTS_PER_SOL} SOL earned`);
+    });
+
     it("Updates strategy metrics via CPI", async () => {
       const strategyAccount = await strategyProgram.account.strategyAccount.fetch(strategy);
 
@@ -400,6 +452,14 @@ describe("Execution Engine", () => {
       assert.isAbove(strategyAccount.totalProfit.toNumber(), 0);
       assert.equal(strategyAccount.successCount.toNumber(), 1);
 
+      const stats = await strategyProgram.methods
+        .getStrategyStats()
+        .accounts({ strategy, strategyMetrics: metricsFor(strategy) })
+        .view();
+      assert.equal(stats.last7d.executions.toNumber(), 1);
+      assert.equal(stats.last7d.volumeBorrowed.toString(), BORROW_AMOUNT.toString());
+      assert.equal(stats.last30d.profit.toString(), strategyAccount.totalProfit.toString());
+
       console.log(`✓ Strategy metrics updated: ${strategyAccount.executionCount} executions, ${strategyAccount.totalProfit.toNumber() / LAMPORTS_PER_SOL} SOL profit`);
     });
   });
@@ -415,10 +475,13 @@ describe("Execution Engine", () => {
             vault,
             profitConfig,
             strategy,
+            strategyMetrics: metricsFor(strategy),
             creator: creator.publicKey,
             executor: executor.publicKey,
+            executorStats,
             treasury: treasury.publicKey,
             strategyRegistryProgram: strategyProgram.programId,
+            registryConfig: adminConfig,
           daoGovernanceProgram: daoGovernanceProgram.programId,
           flashLoanPool,
           flashLoanPoolAuthority,
@@ -437,6 +500,141 @@ describe("Execution Engine", () => {
         console.log("✓ Correctly reverted on slippage protection");
       }
     });
+
+    it("Reverts when a hop fills more than max_slippage below its quote", async () => {
+      // 10 SOL moves the mock pools ~0.1% (10 bps), above this strategy's 5 bps tolerance
+      const tightStrategyId = new BN(3);
+      const [tightStrategy] = PublicKey.findProgramAddressSync(
+        [Buffer.from("strategy"), creator.publicKey.toBuffer(), tightStrategyId.toArrayLike(Buffer, "le", 8)],
+        strategyProgram.programId
+      );
+
+      await strategyProgram.methods
+        .createStrategy(
+          tightStrategyId,
+          [{ raydium: {} }, { orca: {} }],
+          [{ tokenA: SOL_MINT, tokenB: USDC_MINT }],
+          500,
+          5 // 0.05% max slippage
+        )
+        .accounts({
+          strategy: tightStrategy,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+      await strategyProgram.methods
+        .approveStrategy(await strategyVersion(tightStrategy))
+        .accounts({
+          adminConfig,
+          strategy: tightStrategy,
+          admin: payer.publicKey,
+        })
+        .rpc();
+
+      try {
+        await executionProgram.methods
+          .executeStrategy(BORROW_AMOUNT, new BN(0))
+          .accounts({
+            vault,
+            profitConfig,
+            strategy: tightStrategy,
+            strategyMetrics: metricsFor(tightStrategy),
+            creator: creator.publicKey,
+            executor: executor.publicKey,
+            executorStats,
+            treasury: treasury.publicKey,
+            strategyRegistryProgram: strategyProgram.programId,
+            registryConfig: adminConfig,
+            daoGovernanceProgram: daoGovernanceProgram.programId,
+            flashLoanPool,
+            flashLoanPoolAuthority,
+            flashLoanPoolTokenAccount,
+            vaultTokenAccount,
+            flashLoanProgram: flashLoanProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([executor])
+          .rpc();
+
+        assert.fail("Expected SlippageExceeded error");
+      } catch (err) {
+        assert.include(err.toString(), "SlippageExceeded");
+        console.log("✓ Per-hop max_slippage enforced against the quote");
+      }
+    });
+  });
+
+  describe("Strategy Risk Settings", () => {
+    it("Reverts when net profit is below the strategy's profit threshold", async () => {
+      const strictStrategyId = new BN(2);
+      const [strictStrategy] = PublicKey.findProgramAddressSync(
+        [Buffer.from("strategy"), creator.publicKey.toBuffer(), strictStrategyId.toArrayLike(Buffer, "le", 8)],
+        strategyProgram.programId
+      );
+
+      await strategyProgram.methods
+        .createStrategy(
+          strictStrategyId,
+          [{ raydium: {} }, { orca: {} }],
+          [{ tokenA: SOL_MINT, tokenB: USDC_MINT }],
+          1000, // 10% profit threshold (mock arbitrage nets ~7.9%)
+          50
+        )
+        .accounts({
+          strategy: strictStrategy,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+      await strategyProgram.methods
+        .approveStrategy(await strategyVersion(strictStrategy))
+        .accounts({
+          adminConfig,
+          strategy: strictStrategy,
+          admin: payer.publicKey,
+        })
+        .rpc();
+
+      try {
+        await executionProgram.methods
+          .executeStrategy(BORROW_AMOUNT, new BN(0))
+          .accounts({
+            vault,
+            profitConfig,
+            strategy: strictStrategy,
+            strategyMetrics: metricsFor(strictStrategy),
+            creator: creator.publicKey,
+            executor: executor.publicKey,
+            executorStats,
+            treasury: treasury.publicKey,
+            strategyRegistryProgram: strategyProgram.programId,
+            registryConfig: adminConfig,
+            daoGovernanceProgram: daoGovernanceProgram.programId,
+            flashLoanPool,
+            flashLoanPoolAuthority,
+            flashLoanPoolTokenAccount,
+            vaultTokenAccount,
+            flashLoanProgram: flashLoanProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([executor])
+          .rpc();
+
+        assert.fail("Expected ProfitBelowThreshold error");
+      } catch (err) {
+        assert.include(err.toString(), "ProfitBelowThreshold");
+        console.log("✓ Strategy profit threshold enforced on-chain");
+      }
+    });
   });
 
   describe("Negative Profit Handling", () => {
@@ -462,10 +660,13 @@ describe("Execution Engine", () => {
             vault,
             profitConfig,
             strategy,
+            strategyMetrics: metricsFor(strategy),
             creator: creator.publicKey,
             executor: executor.publicKey,
+            executorStats,
             treasury: treasury.publicKey,
             strategyRegistryProgram: strategyProgram.programId,
+            registryConfig: adminConfig,
           daoGovernanceProgram: daoGovernanceProgram.programId,
           flashLoanPool,
           flashLoanPoolAuthority,
@@ -511,10 +712,13 @@ describe("Execution Engine", () => {
             vault,
             profitConfig,
             strategy,
+            strategyMetrics: metricsFor(strategy),
             creator: creator.publicKey,
             executor: executor.publicKey,
+            executorStats,
             treasury: treasury.publicKey,
             strategyRegistryProgram: strategyProgram.programId,
+            registryConfig: adminConfig,
           daoGovernanceProgram: daoGovernanceProgram.programId,
           flashLoanPool,
           flashLoanPoolAuthority,
@@ -558,6 +762,7 @@ describe("Execution Engine", () => {
           creator: creator.publicKey,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(pairIndexAccounts)
         .signers([creator])
         .rpc();
 
@@ -569,10 +774,13 @@ describe("Execution Engine", () => {
             vault,
             profitConfig,
             strategy: unapprovedStrategy,
+            strategyMetrics: metricsFor(unapprovedStrategy),
             creator: creator.publicKey,
             executor: executor.publicKey,
+            executorStats,
             treasury: treasury.publicKey,
             strategyRegistryProgram: strategyProgram.programId,
+            registryConfig: adminConfig,
           daoGovernanceProgram: daoGovernanceProgram.programId,
           flashLoanPool,
           flashLoanPoolAuthority,
@@ -593,6 +801,449 @@ describe("Execution Engine", () => {
     });
   });
 
+  describe("Failed Attempt Reporting", () => {
+    let failureReport: PublicKey;
+
+    before(() => {
+      [failureReport] = PublicKey.findProgramAddressSync(
+        [Buffer.from("failure_report"), strategy.toBuffer(), executor.publicKey.toBuffer()],
+        executionProgram.programId
+      );
+    });
+
+    it("Records a failed attempt in strategy metrics", async () => {
+      const before = await strategyProgram.account.strategyAccount.fetch(strategy);
+
+      await executionProgram.methods
+        .reportFailedAttempt(6003)
+        .accounts({
+          failureReport,
+          vault,
+          strategy,
+          strategyMetrics: metricsFor(strategy),
+          executor: executor.publicKey,
+          executorStats,
+          strategyRegistryProgram: strategyProgram.programId,
+          registryConfig: adminConfig,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([executor])
+        .rpc();
+
+      const after = await strategyProgram.account.strategyAccount.fetch(strategy);
+      assert.equal(after.executionCount.toNumber(), before.executionCount.toNumber() + 1);
+      assert.equal(after.successCount.toNumber(), before.successCount.toNumber());
+      assert.equal(after.totalProfit.toString(), before.totalProfit.toString());
+
+      const report = await executionProgram.account.failureReport.fetch(failureReport);
+      assert.equal(report.reportCount.toNumber(), 1);
+    });
+
+    it("Rate limits repeated reports from the same executor", async () => {
+      try {
+        await executionProgram.methods
+          .reportFailedAttempt(6003)
+          .accounts({
+            failureReport,
+            vault,
+            strategy,
+            strategyMetrics: metricsFor(strategy),
+            executor: executor.publicKey,
+            executorStats,
+            strategyRegistryProgram: strategyProgram.programId,
+            registryConfig: adminConfig,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([executor])
+          .rpc();
+
+        assert.fail("Expected FailureReportTooFrequent error");
+      } catch (err) {
+        assert.include(err.toString(), "FailureReportTooFrequent");
+      }
+    });
+
+    it("Rejects reports from executors without a successful execution", async () => {
+      const stranger = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(stranger.publicKey, LAMPORTS_PER_SOL)
+      );
+      const [strangerReport] = PublicKey.findProgramAddressSync(
+        [Buffer.from("failure_report"), strategy.toBuffer(), stranger.publicKey.toBuffer()],
+        executionProgram.programId
+      );
+      const [strangerStats] = PublicKey.findProgramAddressSync(
+        [Buffer.from("executor_stats"), stranger.publicKey.toBuffer()],
+        executionProgram.programId
+      );
+
+      try {
+        await executionProgram.methods
+          .reportFailedAttempt(6003)
+          .accounts({
+            failureReport: strangerReport,
+            vault,
+            strategy,
+            strategyMetrics: metricsFor(strategy),
+            executor: stranger.publicKey,
+            executorStats: strangerStats,
+            strategyRegistryProgram: strategyProgram.programId,
+            registryConfig: adminConfig,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([stranger])
+          .rpc();
+
+        assert.fail("Expected report from an unknown executor to fail");
+      } catch (err) {
+        // No ExecutorStats record exists for a fresh keypair
+        assert.include(err.toString(), "AccountNotInitialized");
+      }
+    });
+
+    it("Rejects reports against strategies the executor never executed", async () => {
+      // Strategy 3 is approved but its only execution reverted on slippage
+      const [tightStrategy] = PublicKey.findProgramAddressSync(
+        [Buffer.from("strategy"), creator.publicKey.toBuffer(), new BN(3).toArrayLike(Buffer, "le", 8)],
+        strategyProgram.programId
+      );
+      const [tightReport] = PublicKey.findProgramAddressSync(
+        [Buffer.from("failure_report"), tightStrategy.toBuffer(), executor.publicKey.toBuffer()],
+        executionProgram.programId
+      );
+
+      try {
+        await executionProgram.methods
+          .reportFailedAttempt(6003)
+          .accounts({
+            failureReport: tightReport,
+            vault,
+            strategy: tightStrategy,
+            strategyMetrics: metricsFor(tightStrategy),
+            executor: executor.publicKey,
+            executorStats,
+            strategyRegistryProgram: strategyProgram.programId,
+            registryConfig: adminConfig,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([executor])
+          .rpc();
+
+        assert.fail("Expected UnregisteredExecutor error");
+      } catch (err) {
+        assert.include(err.toString(), "UnregisteredExecutor");
+      }
+    });
+  });
+
+  describe("Rate Limits", () => {
+    const execute = () =>
+      executionProgram.methods
+        .executeStrategy(BORROW_AMOUNT, new BN(0))
+        .accounts({
+          vault,
+          profitConfig,
+          strategy,
+          strategyMetrics: metricsFor(strategy),
+          creator: creator.publicKey,
+          executor: executor.publicKey,
+          executorStats,
+          treasury: treasury.publicKey,
+          strategyRegistryProgram: strategyProgram.programId,
+          registryConfig: adminConfig,
+          daoGovernanceProgram: daoGovernanceProgram.programId,
+          flashLoanPool,
+          flashLoanPoolAuthority,
+          flashLoanPoolTokenAccount,
+          vaultTokenAccount,
+          flashLoanProgram: flashLoanProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([executor])
+        .rpc();
+
+    const setRateLimits = (minSlots: number, maxPerEpoch: number) =>
+      strategyProgram.methods
+        .setRateLimits(new BN(minSlots), maxPerEpoch)
+        .accounts({ strategy, creator: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+    afterEach(async () => {
+      await setRateLimits(0, 0);
+    });
+
+    it("Rejects executions during the cooldown", async () => {
+      await setRateLimits(1_000_000, 0);
+
+      try {
+        await execute();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("ExecutionCooldown");
+      }
+    });
+
+    it("Rejects executions past the per-epoch cap", async () => {
+      await execute();
+      const strategyAccount = await strategyProgram.account.strategyAccount.fetch(strategy);
+      assert.isAtLeast(strategyAccount.epochExecutionCount, 1);
+
+      await setRateLimits(0, strategyAccount.epochExecutionCount);
+
+      try {
+        await execute();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("EpochExecutionLimit");
+      }
+    });
+  });
+
+  describe("Borrow Caps", () => {
+    const setMaxBorrow = (maxBorrow: BN) =>
+      strategyProgram.methods
+        .setMaxBorrow(maxBorrow)
+        .accounts({ strategy, creator: creator.publicKey })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+    it("Rejects borrows above the strategy's max borrow", async () => {
+      const { maxBorrow } = await strategyProgram.account.strategyAccount.fetch(strategy);
+
+      // Lowering the cap keeps the strategy approved
+      await setMaxBorrow(BORROW_AMOUNT.subn(1));
+      let strategyAccount = await strategyProgram.account.strategyAccount.fetch(strategy);
+      assert.property(strategyAccount.status, 'approved');
+
+      try {
+        await executionProgram.methods
+          .executeStrategy(BORROW_AMOUNT, new BN(0))
+          .accounts({
+            vault,
+            profitConfig,
+            strategy,
+            strategyMetrics: metricsFor(strategy),
+            creator: creator.publicKey,
+            executor: executor.publicKey,
+            executorStats,
+            treasury: treasury.publicKey,
+            strategyRegistryProgram: strategyProgram.programId,
+            registryConfig: adminConfig,
+            daoGovernanceProgram: daoGovernanceProgram.programId,
+            flashLoanPool,
+            flashLoanPoolAuthority,
+            flashLoanPoolTokenAccount,
+            vaultTokenAccount,
+            flashLoanProgram: flashLoanProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([executor])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("BorrowCapExceeded");
+      }
+
+      // Raising it back needs governance to approve the strategy again
+      await setMaxBorrow(maxBorrow);
+      strategyAccount = await strategyProgram.account.strategyAccount.fetch(strategy);
+      assert.property(strategyAccount.status, 'pending');
+
+      await strategyProgram.methods
+        .approveStrategy(await strategyVersion(strategy))
+        .accounts({
+          adminConfig,
+          strategy,
+          admin: payer.publicKey,
+        })
+        .rpc();
+
+      console.log("✓ Strategy borrow cap enforced");
+    });
+  });
+
+  describe("Circuit Breaker", () => {
+    const PAUSE_EXECUTE = 1 << 0;
+    const PAUSE_REPORT = 1 << 1;
+    const POOL_PAUSE_BORROW = 1 << 0;
+    const guardian = Keypair.generate();
+    // Short guardian window so the lapse can be observed on the local validator
+    const GUARDIAN_PAUSE_SECONDS = 2;
+
+    const setPause = (groups: number, signer?: Keypair) =>
+      executionProgram.methods
+        .setPause(groups)
+        .accounts({ vault, authority: signer ? signer.publicKey : payer.publicKey })
+        .signers(signer ? [signer] : [])
+        .rpc();
+
+    const execute = () =>
+      executionProgram.methods
+        .executeStrategy(BORROW_AMOUNT, new BN(0))
+        .accounts({
+          vault,
+          profitConfig,
+          strategy,
+          strategyMetrics: metricsFor(strategy),
+          creator: creator.publicKey,
+          executor: executor.publicKey,
+          executorStats,
+          treasury: treasury.publicKey,
+          strategyRegistryProgram: strategyProgram.programId,
+          registryConfig: adminConfig,
+          daoGovernanceProgram: daoGovernanceProgram.programId,
+          flashLoanPool,
+          flashLoanPoolAuthority,
+          flashLoanPoolTokenAccount,
+          vaultTokenAccount,
+          flashLoanProgram: flashLoanProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([executor])
+        .rpc();
+
+    before(async () => {
+      await setGuardianWindow(GUARDIAN_PAUSE_SECONDS);
+      await flashLoanProgram.methods
+        .setGuardian(guardian.publicKey, new BN(60))
+        .accounts({ pool: flashLoanPool, admin: payer.publicKey })
+        .rpc();
+    });
+
+    const setPoolPause = (groups: number) =>
+      flashLoanProgram.methods
+        .setPause(groups)
+        .accounts({ pool: flashLoanPool, authority: guardian.publicKey })
+        .signers([guardian])
+        .rpc();
+
+    // Longer guardian window for tests that act after pausing
+    const setGuardianWindow = (seconds: number) =>
+      executionProgram.methods
+        .setGuardian(guardian.publicKey, new BN(seconds))
+        .accounts({ vault, authority: payer.publicKey })
+        .rpc();
+
+    afterEach(async () => {
+      // Unexpiring pauses need a DAO PauseChange proposal, so tests only use guardian pauses
+      await setPause(0, guardian);
+      await setPoolPause(0);
+    });
+
+    it("Guardian pause blocks execution until it expires", async () => {
+      await setPause(PAUSE_EXECUTE, guardian);
+
+      const vaultAccount = await executionProgram.account.executionVault.fetch(vault);
+      assert.equal(vaultAccount.pause.guardianPaused, PAUSE_EXECUTE);
+      const expiresAt = vaultAccount.pause.guardianExpiresAt.toNumber();
+      const now = Math.floor(Date.now() / 1000);
+      expect(expiresAt).to.be.closeTo(now + GUARDIAN_PAUSE_SECONDS, 120);
+
+      try {
+        await execute();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("ProgramPaused");
+      }
+
+      // Wait for the validator clock to pass the expiry, the guardian's flag stays set
+      const connection = executionProgram.provider.connection;
+      while ((await connection.getBlockTime(await connection.getSlot())) < expiresAt) {
+        await new Promise((resolve) => setTimeout(resolve, 500));
+      }
+      const lapsed = await executionProgram.account.executionVault.fetch(vault);
+      assert.equal(lapsed.pause.guardianPaused, PAUSE_EXECUTE);
+
+      // Execution resumes without anyone lifting the pause
+      const statsBefore = await executionProgram.account.executorStats.fetch(executorStats);
+      await execute();
+      const statsAfter = await executionProgram.account.executorStats.fetch(executorStats);
+      assert.equal(statsAfter.totalExecutions.toNumber(), statsBefore.totalExecutions.toNumber() + 1);
+    });
+
+    it("Refuses guardian pause windows longer than 24 hours", async () => {
+      try {
+        await executionProgram.methods
+          .setGuardian(guardian.publicKey, new BN(86_400 + 1))
+          .accounts({ vault, authority: payer.publicKey })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidGuardianPause");
+      }
+    });
+
+    it("Guardian pause blocks failed-attempt reports", async () => {
+      await setGuardianWindow(60);
+      await setPause(PAUSE_REPORT, guardian);
+
+      const [failureReport] = PublicKey.findProgramAddressSync(
+        [Buffer.from("failure_report"), strategy.toBuffer(), executor.publicKey.toBuffer()],
+        executionProgram.programId
+      );
+
+      try {
+        await executionProgram.methods
+          .reportFailedAttempt(6003)
+          .accounts({
+            failureReport,
+            vault,
+            strategy,
+            strategyMetrics: metricsFor(strategy),
+            executor: executor.publicKey,
+            executorStats,
+            strategyRegistryProgram: strategyProgram.programId,
+            registryConfig: adminConfig,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([executor])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("ProgramPaused");
+      } finally {
+        await setGuardianWindow(GUARDIAN_PAUSE_SECONDS);
+      }
+    });
+
+    it("Paused flash loan pool blocks execution", async () => {
+      await setPoolPause(POOL_PAUSE_BORROW);
+
+      try {
+        await execute();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("ProgramPaused");
+      }
+    });
+
+    it("Rejects pauses from other signers", async () => {
+      try {
+        await setPause(PAUSE_EXECUTE, executor);
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedPauser");
+      }
+    });
+
+    it("Vault authority cannot set the unexpiring pause (DAO governance only)", async () => {
+      try {
+        await setPause(PAUSE_EXECUTE);
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedPauser");
+      }
+
+      const vaultAccount = await executionProgram.account.executionVault.fetch(vault);
+      assert.equal(vaultAccount.pause.adminPaused, 0);
+    });
+  });
+
   describe("Profit Distribution Math Verification", () => {
     it("Verifies 40/40/20 split is mathematically correct", async () => {
       const profitConfigAccount = await executionProgram.account.profitConfig.fetch(profitConfig);
@@ -84,6 +84,8 @@ describe("flash-loan", () => {
     expect(pool.totalDeposited.toNumber()).to.equal(0);
     expect(pool.totalLoans.toNumber()).to.equal(0);
     expect(pool.flashLoanActive).to.equal(false);
+    expect(pool.admin.toString()).to.equal(authority.publicKey.toString());
+    expect(pool.epochBorrowCap.toNumber()).to.equal(0);
 
     console.log("✓ Flash loan pool initialized successfully");
   });
@@ -217,4 +219,192 @@ describe("flash-loan", () => {
 
     console.log("✓ Flash loan borrow and repay successful");
   });
+
+  describe("Epoch Borrow Cap", () => {
+    after(async () => {
+      // Leave the pool uncapped for other suites
+      await program.methods
+        .setEpochBorrowCap(new anchor.BN(0))
+        .accounts({
+          pool: flashLoanPool,
+          admin: authority.publicKey,
+        })
+        .rpc();
+    });
+
+    it("Fails to set the cap without pool admin", async () => {
+      const stranger = anchor.web3.Keypair.generate();
+
+      try {
+        await program.methods
+          .setEpochBorrowCap(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL))
+          .accounts({
+            pool: flashLoanPool,
+            admin: stranger.publicKey,
+          })
+          .signers([stranger])
+          .rpc();
+        expect.fail("Should have thrown Unauthorized");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      console.log("✓ Only the pool admin can set the epoch borrow cap");
+    });
+
+    it("Rejects borrows beyond the epoch borrow cap", async () => {
+      const borrowAmount = 10 * anchor.web3.LAMPORTS_PER_SOL;
+      const borrower = anchor.web3.Keypair.generate();
+      const airdropSig = await provider.connection.requestAirdrop(
+        borrower.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+      const borrowerWsolAccount = await wrapSol(provider, borrower.publicKey, 1000, borrower);
+
+      // Leave room for less than one more loan this epoch
+      const pool = await program.account.flashLoanPool.fetch(flashLoanPool);
+      const cap = pool.epochBorrowed.add(new anchor.BN(borrowAmount - 1));
+
+      await program.methods
+        .setEpochBorrowCap(cap)
+        .accounts({
+          pool: flashLoanPool,
+          admin: authority.publicKey,
+        })
+        .rpc();
+
+      const poolAfter = await program.account.flashLoanPool.fetch(flashLoanPool);
+      expect(poolAfter.epochBorrowCap.toString()).to.equal(cap.toString());
+
+      try {
+        await program.methods
+          .flashBorrow(new anchor.BN(borrowAmount))
+          .accounts({
+            pool: flashLoanPool,
+            poolAuthority: poolAuthority,
+            poolTokenAccount: poolTokenAccount,
+            borrowerProgram: program.programId,
+            borrowerTokenAccount: borrowerWsolAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        expect.fail("Should have thrown EpochBorrowCapExceeded");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("EpochBorrowCapExceeded");
+      }
+
+      console.log("✓ Borrow beyond the epoch cap rejected");
+    });
+  });
+
+  describe("Circuit Breaker", () => {
+    const PAUSE_BORROW = 1 << 0;
+    const PAUSE_DEPOSIT = 1 << 1;
+    const guardian = anchor.web3.Keypair.generate();
+
+    const setPause = (groups: number, signer?: anchor.web3.Keypair) =>
+      program.methods
+        .setPause(groups)
+        .accounts({
+          pool: flashLoanPool,
+          authority: signer ? signer.publicKey : authority.publicKey,
+        })
+        .signers(signer ? [signer] : [])
+        .rpc();
+
+    before(async () => {
+      await program.methods
+        .setGuardian(guardian.publicKey, new anchor.BN(86_400))
+        .accounts({ pool: flashLoanPool, admin: authority.publicKey })
+        .rpc();
+    });
+
+    after(async () => {
+      await setPause(0, guardian);
+    });
+
+    it("Guardian pause blocks flash_borrow", async () => {
+      await setPause(PAUSE_BORROW, guardian);
+
+      const pool = await program.account.flashLoanPool.fetch(flashLoanPool);
+      expect(pool.pause.guardianPaused).to.equal(PAUSE_BORROW);
+      expect(pool.pause.guardianExpiresAt.toNumber()).to.be.greaterThan(Math.floor(Date.now() / 1000));
+
+      try {
+        await program.methods
+          .flashBorrow(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL))
+          .accounts({
+            pool: flashLoanPool,
+            poolAuthority: poolAuthority,
+            poolTokenAccount: poolTokenAccount,
+            borrowerProgram: program.programId,
+            borrowerTokenAccount: authorityWsolAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        expect.fail("Should have thrown ProgramPaused");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("ProgramPaused");
+      }
+
+      console.log("✓ Guardian pause blocks borrowing");
+    });
+
+    it("Guardian pause of deposit_liquidity replaces the borrow pause", async () => {
+      await setPause(PAUSE_DEPOSIT, guardian);
+
+      const pool = await program.account.flashLoanPool.fetch(flashLoanPool);
+      expect(pool.pause.guardianPaused).to.equal(PAUSE_DEPOSIT);
+      expect(pool.pause.adminPaused).to.equal(0);
+
+      try {
+        await program.methods
+          .depositLiquidity(new anchor.BN(1000))
+          .accounts({
+            pool: flashLoanPool,
+            poolTokenAccount: poolTokenAccount,
+            depositorTokenAccount: authorityWsolAccount,
+            depositor: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        expect.fail("Should have thrown ProgramPaused");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("ProgramPaused");
+      }
+
+      console.log("✓ Guardian pause blocks deposits");
+    });
+
+    it("Pool admin cannot set the unexpiring pause (DAO governance only)", async () => {
+      try {
+        await setPause(PAUSE_DEPOSIT);
+        expect.fail("Should have thrown Unauthorized");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      const pool = await program.account.flashLoanPool.fetch(flashLoanPool);
+      expect(pool.pause.adminPaused).to.equal(0);
+    });
+
+    it("Rejects pauses from other signers and unknown groups", async () => {
+      const stranger = anchor.web3.Keypair.generate();
+
+      try {
+        await setPause(PAUSE_BORROW, stranger);
+        expect.fail("Should have thrown Unauthorized");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("Unauthorized");
+      }
+
+      try {
+        await setPause(1 << 2, guardian);
+        expect.fail("Should have thrown InvalidPauseGroups");
+      } catch (error) {
+        expect(error.error.errorCode.code).to.equal("InvalidPauseGroups");
+      }
+    });
+  });
 });
@@ -20,6 +20,20 @@ describe("Strategy Registry", () => {
   const USDC_MINT = new PublicKey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"); // Devnet USDC
   const SOL_MINT = new PublicKey("So11111111111111111111111111111111111111112");
 
+  // SOL/USDC token pair index (required when creating, updating or closing strategies)
+  const [pairMintA, pairMintB] = [
+    new PublicKey("So11111111111111111111111111111111111111112"),
+    new PublicKey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+  ].sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()));
+  const [solUsdcPairIndex] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pair_index"), pairMintA.toBuffer(), pairMintB.toBuffer()],
+    program.programId
+  );
+  const pairIndexAccounts = [{ pubkey: solUsdcPairIndex, isWritable: true, isSigner: false }];
+
+  const strategyVersion = async (strategyPda: PublicKey) =>
+    (await program.account.strategyAccount.fetch(strategyPda)).version;
+
   before(async () => {
     // Generate test keypairs
     creator = Keypair.generate();
@@ -38,6 +52,14 @@ describe("Strategy Registry", () => {
       [Buffer.from("config")],
       program.programId
     );
+
+    // Create the SOL/USDC pair index once (shared across test files)
+    if (!(await provider.connection.getAccountInfo(solUsdcPairIndex))) {
+      await program.methods
+        .initTokenPairIndex(pairMintA, pairMintB)
+        .accounts({ payer: admin.publicKey })
+        .rpc();
+    }
   });
 
   describe("Admin Initialization", () => {
@@ -111,6 +133,7 @@ describe("Strategy Registry", () => {
           creator: creator.publicKey,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(pairIndexAccounts)
         .signers([creator])
         .rpc();
 
@@ -153,6 +176,7 @@ describe("Strategy Registry", () => {
             creator: creator.publicKey,
             systemProgram: SystemProgram.programId,
           })
+          .remainingAccounts(pairIndexAccounts)
           .signers([creator])
           .rpc();
         assert.fail("Should have failed");
@@ -186,6 +210,7 @@ describe("Strategy Registry", () => {
             creator: creator.publicKey,
             systemProgram: SystemProgram.programId,
           })
+          .remainingAccounts(pairIndexAccounts)
           .signers([creator])
           .rpc();
         assert.fail("Should have failed");
@@ -219,6 +244,7 @@ describe("Strategy Registry", () => {
             creator: creator.publicKey,
             systemProgram: SystemProgram.programId,
           })
+          .remainingAccounts(pairIndexAccounts)
           .signers([creator])
           .rpc();
         assert.fail("Should have failed");
@@ -252,6 +278,7 @@ describe("Strategy Registry", () => {
             creator: creator.publicKey,
             systemProgram: SystemProgram.programId,
           })
+          .remainingAccounts(pairIndexAccounts)
           .signers([creator])
           .rpc();
         assert.fail("Should have failed");
@@ -285,6 +312,7 @@ describe("Strategy Registry", () => {
             creator: creator.publicKey,
             systemProgram: SystemProgram.programId,
           })
+          .remainingAccounts(pairIndexAccounts)
           .signers([creator])
           .rpc();
         assert.fail("Should have failed");
@@ -321,13 +349,14 @@ describe("Strategy Registry", () => {
           creator: creator.publicKey,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(pairIndexAccounts)
         .signers([creator])
         .rpc();
     });
 
     it("Admin approves a strategy", async () => {
       await program.methods
-        .approveStrategy()
+        .approveStrategy(await strategyVersion(strategyPda))
         .accounts({
           strategy: strategyPda,
           adminConfig,
@@ -363,12 +392,13 @@ describe("Strategy Registry", () => {
           creator: creator.publicKey,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(pairIndexAccounts)
         .signers([creator])
         .rpc();
 
       try {
         await program.methods
-          .approveStrategy()
+          .approveStrategy(await strategyVersion(strategy2Pda))
           .accounts({
             strategy: strategy2Pda,
             adminConfig,
@@ -406,6 +436,7 @@ describe("Strategy Registry", () => {
           creator: creator.publicKey,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(pairIndexAccounts)
         .signers([creator])
         .rpc();
 
@@ -450,11 +481,12 @@ describe("Strategy Registry", () => {
           creator: creator.publicKey,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(pairIndexAccounts)
         .signers([creator])
         .rpc();
 
       await program.methods
-        .approveStrategy()
+        .approveStrategy(await strategyVersion(strategyPda))
         .accounts({
           strategy: strategyPda,
           adminConfig,
@@ -463,122 +495,1540 @@ describe("Strategy Registry", () => {
         .rpc();
     });
 
-    it("Updates metrics after successful execution", async () => {
-      const profit = new anchor.BN(1_000_000); // 1 USDC worth of lamports
+    it("Rejects update_metrics not signed by the execution engine", async () => {
+      // Metrics are only writable via execution-engine CPI (vault PDA signer);
+      // successful and failed execution tracking is covered in execution-engine tests
+      const fakeAuthority = Keypair.generate();
+
+      try {
+        await program.methods
+          .updateMetrics(new anchor.BN(1_000_000), true)
+          .accounts({
+            strategy: strategyPda,
+            adminConfig,
+            executionAuthority: fakeAuthority.publicKey,
+            executor: otherUser.publicKey,
+          })
+          .signers([fakeAuthority, otherUser])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("ConstraintSeeds");
+      }
+
+      const strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.equal(strategy.executionCount.toNumber(), 0);
+      assert.equal(strategy.totalProfit.toNumber(), 0);
+    });
+
+    it("Gets strategy stats", async () => {
+      const stats = await program.methods
+        .getStrategyStats()
+        .accounts({
+          strategy: strategyPda,
+        })
+        .view();
+
+      assert.equal(stats.strategyId.toNumber(), 20);
+      assert.equal(stats.creator.toString(), creator.publicKey.toString());
+      assert.equal(stats.totalProfit.toNumber(), 0);
+      assert.equal(stats.executionCount.toNumber(), 0);
+      assert.equal(stats.successRate, 0);
+      assert.deepEqual(stats.status, { approved: {} });
+      assert.equal(stats.last7d.executions.toNumber(), 0);
+      assert.equal(stats.last30d.profit.toNumber(), 0);
+    });
+
+    it("Rotates the authorized execution engine through governance only", async () => {
+      const config = await program.account.adminConfig.fetch(adminConfig);
+      const engine = config.executionEngine;
+
+      // Stands in for the DAO governance PDA (execute_proposal signs the same setter)
+      const governance = Keypair.generate();
+      await program.methods
+        .setApprovalMode(governance.publicKey, false)
+        .accounts({ adminConfig, authority: admin.publicKey })
+        .rpc();
+
+      const replacement = Keypair.generate().publicKey;
+      try {
+        await program.methods
+          .setExecutionEngine(replacement)
+          .accounts({ adminConfig, authority: admin.publicKey })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedAdmin");
+      }
+
+      await program.methods
+        .setExecutionEngine(replacement)
+        .accounts({ adminConfig, authority: governance.publicKey })
+        .signers([governance])
+        .rpc();
+
+      let updated = await program.account.adminConfig.fetch(adminConfig);
+      assert.equal(updated.executionEngine.toString(), replacement.toString());
+
+      // Restore the real engine and governance authority for other suites
+      await program.methods
+        .setExecutionEngine(engine)
+        .accounts({ adminConfig, authority: governance.publicKey })
+        .signers([governance])
+        .rpc();
+      await program.methods
+        .setApprovalMode(config.governance, config.governanceOnly)
+        .accounts({ adminConfig, authority: admin.publicKey })
+        .rpc();
+
+      updated = await program.account.adminConfig.fetch(adminConfig);
+      assert.equal(updated.executionEngine.toString(), engine.toString());
+    });
+
+    it("Fails to rotate the execution engine without authority", async () => {
+      try {
+        await program.methods
+          .setExecutionEngine(otherUser.publicKey)
+          .accounts({ adminConfig, authority: otherUser.publicKey })
+          .signers([otherUser])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedAdmin");
+      }
+    });
+  });
+
+  describe("Strategy Updates", () => {
+    let strategyPda: PublicKey;
+    const strategyId = new anchor.BN(30);
+
+    before(async () => {
+      [strategyPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("strategy"),
+          creator.publicKey.toBuffer(),
+          strategyId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
 
       await program.methods
-        .updateMetrics(profit, true)
+        .createStrategy(
+          strategyId,
+          [{ raydium: {} }, { orca: {} }],
+          [{ tokenA: SOL_MINT, tokenB: USDC_MINT }],
+          50,
+          100
+        )
         .accounts({
           strategy: strategyPda,
-          executor: otherUser.publicKey,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
         })
-        .signers([otherUser])
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .approveStrategy(await strategyVersion(strategyPda))
+        .accounts({
+          strategy: strategyPda,
+          adminConfig,
+          admin: admin.publicKey,
+        })
+        .rpc();
+    });
+
+    it("Applies tightening changes without re-approval", async () => {
+      await program.methods
+        .updateStrategy(
+          [{ raydium: {} }],
+          [{ tokenA: SOL_MINT, tokenB: USDC_MINT }],
+          75, // Higher profit floor
+          50 // Lower slippage
+        )
+        .accounts({
+          strategy: strategyPda,
+          creator: creator.publicKey,
+        })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
         .rpc();
 
       const strategy = await program.account.strategyAccount.fetch(strategyPda);
-      assert.equal(strategy.executionCount.toNumber(), 1);
-      assert.equal(strategy.successCount.toNumber(), 1);
-      assert.equal(strategy.totalProfit.toNumber(), 1_000_000);
-      assert.isAbove(strategy.lastExecution.toNumber(), 0);
+      assert.equal(strategy.version, 2);
+      assert.equal(strategy.profitThreshold, 75);
+      assert.equal(strategy.maxSlippage, 50);
+      assert.equal(strategy.dexs.length, 1);
+      assert.deepEqual(strategy.status, { approved: {} });
     });
 
-    it("Updates metrics after failed execution", async () => {
+    it("Sends risk-increasing changes back to Pending", async () => {
       await program.methods
-        .updateMetrics(new anchor.BN(0), false)
+        .updateStrategy(
+          [{ raydium: {} }, { meteora: {} }], // New DEX
+          [{ tokenA: SOL_MINT, tokenB: USDC_MINT }],
+          75,
+          200 // More slippage
+        )
         .accounts({
           strategy: strategyPda,
-          executor: otherUser.publicKey,
+          creator: creator.publicKey,
         })
-        .signers([otherUser])
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
         .rpc();
 
       const strategy = await program.account.strategyAccount.fetch(strategyPda);
-      assert.equal(strategy.executionCount.toNumber(), 2);
-      assert.equal(strategy.successCount.toNumber(), 1); // Still 1
-      assert.equal(strategy.totalProfit.toNumber(), 1_000_000); // No change
+      assert.equal(strategy.version, 3);
+      assert.equal(strategy.maxSlippage, 200);
+      assert.deepEqual(strategy.status, { pending: {} });
     });
 
-    it("Handles multiple successful executions", async () => {
-      for (let i = 0; i < 5; i++) {
+    it("Refuses to approve parameters that changed after review", async () => {
+      // The reviewed version (2) was edited into version 3 before the decision
+      try {
         await program.methods
-          .updateMetrics(new anchor.BN(500_000), true)
-          .accounts({
-            strategy: strategyPda,
-            executor: otherUser.publicKey,
-          })
-          .signers([otherUser])
+          .approveStrategy(2)
+          .accounts({ strategy: strategyPda, adminConfig, admin: admin.publicKey })
           .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("StrategyVersionMismatch");
       }
 
       const strategy = await program.account.strategyAccount.fetch(strategyPda);
-      assert.equal(strategy.executionCount.toNumber(), 7); // 2 + 5
-      assert.equal(strategy.successCount.toNumber(), 6); // 1 + 5
-      assert.equal(strategy.totalProfit.toNumber(), 3_500_000); // 1M + (5 * 500K)
+      assert.deepEqual(strategy.status, { pending: {} });
     });
 
-    it("Calculates success rate correctly", async () => {
-      const strategy = await program.account.strategyAccount.fetch(strategyPda);
-      const successRate = Math.floor((strategy.successCount.toNumber() * 100) / strategy.executionCount.toNumber());
-      // 6 successes out of 7 executions = 85.7% ~= 85%
-      assert.approximately(successRate, 85, 1);
+    it("Fails when non-creator tries to update", async () => {
+      try {
+        await program.methods
+          .updateStrategy(
+            [{ raydium: {} }],
+            [{ tokenA: SOL_MINT, tokenB: USDC_MINT }],
+            50,
+            100
+          )
+          .accounts({
+            strategy: strategyPda,
+            creator: otherUser.publicKey,
+          })
+          .remainingAccounts(pairIndexAccounts)
+          .signers([otherUser])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedCreator");
+      }
     });
+  });
 
-    it("Gets strategy stats", async () => {
-      const stats = await program.methods
-        .getStrategyStats()
-        .accounts({
-          strategy: strategyPda,
-        })
-        .view();
-
-      assert.equal(stats.strategyId.toNumber(), 20);
-      assert.equal(stats.creator.toString(), creator.publicKey.toString());
-      assert.equal(stats.totalProfit.toNumber(), 3_500_000);
-      assert.equal(stats.executionCount.toNumber(), 7);
-      assert.equal(stats.successCount.toNumber(), 6);
-      assert.deepEqual(stats.status, { approved: {} });
-    });
+  describe("Strategy Lifecycle", () => {
+    let strategyPda: PublicKey;
+    const strategyId = new anchor.BN(40);
 
-    it("Fails to update metrics for unapproved strategy", async () => {
-      const strategyId2 = new anchor.BN(21);
-      const [strategy2Pda] = PublicKey.findProgramAddressSync(
+    before(async () => {
+      [strategyPda] = PublicKey.findProgramAddressSync(
         [
           Buffer.from("strategy"),
           creator.publicKey.toBuffer(),
-          strategyId2.toArrayLike(Buffer, "le", 8),
+          strategyId.toArrayLike(Buffer, "le", 8),
         ],
         program.programId
       );
 
       await program.methods
         .createStrategy(
-          strategyId2,
+          strategyId,
           [{ raydium: {} }],
           [{ tokenA: SOL_MINT, tokenB: USDC_MINT }],
           50,
           100
         )
         .accounts({
-          strategy: strategy2Pda,
+          strategy: strategyPda,
           creator: creator.publicKey,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .approveStrategy(await strategyVersion(strategyPda))
+        .accounts({
+          strategy: strategyPda,
+          adminConfig,
+          admin: admin.publicKey,
+        })
+        .rpc();
+    });
+
+    it("Creator pauses and resumes a strategy", async () => {
+      await program.methods
+        .pauseStrategy()
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      let strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.deepEqual(strategy.status, { paused: {} });
+
+      await program.methods
+        .resumeStrategy()
+        .accounts({ strategy: strategyPda, adminConfig, authority: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.deepEqual(strategy.status, { approved: {} });
+    });
+
+    it("Paused strategy is not executable", async () => {
+      await program.methods
+        .pauseStrategy()
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
         .signers([creator])
         .rpc();
 
+      const strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.deepEqual(strategy.status, { paused: {} });
+    });
+
+    it("Creator cannot resume an emergency pause", async () => {
+      await program.methods
+        .emergencyPauseStrategy()
+        .accounts({ strategy: strategyPda, adminConfig, admin: admin.publicKey })
+        .rpc();
+
       try {
         await program.methods
-          .updateMetrics(new anchor.BN(1000), true)
-          .accounts({
-            strategy: strategy2Pda,
-            executor: otherUser.publicKey,
-          })
-          .signers([otherUser])
+          .resumeStrategy()
+          .accounts({ strategy: strategyPda, adminConfig, authority: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("EmergencyPauseActive");
+      }
+    });
+
+    it("Governance lifts an emergency pause", async () => {
+      await program.methods
+        .resumeStrategy()
+        .accounts({ strategy: strategyPda, adminConfig, authority: admin.publicKey })
+        .rpc();
+
+      const strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.deepEqual(strategy.status, { approved: {} });
+      assert.isFalse(strategy.emergencyPaused);
+    });
+
+    it("Only governance emergency pauses and resumes in governance-only mode", async () => {
+      // Stands in for the DAO governance PDA (EmergencyPauseStrategy / ResumeStrategy actions)
+      const governance = Keypair.generate();
+      const previous = await program.account.adminConfig.fetch(adminConfig);
+      await program.methods
+        .setApprovalMode(governance.publicKey, true)
+        .accounts({ adminConfig, authority: admin.publicKey })
+        .rpc();
+
+      try {
+        await program.methods
+          .emergencyPauseStrategy()
+          .accounts({ strategy: strategyPda, adminConfig, admin: admin.publicKey })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedApprover");
+      }
+
+      await program.methods
+        .emergencyPauseStrategy()
+        .accounts({ strategy: strategyPda, adminConfig, admin: governance.publicKey })
+        .signers([governance])
+        .rpc();
+
+      try {
+        await program.methods
+          .resumeStrategy()
+          .accounts({ strategy: strategyPda, adminConfig, authority: admin.publicKey })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("EmergencyPauseActive");
+      }
+
+      await program.methods
+        .resumeStrategy()
+        .accounts({ strategy: strategyPda, adminConfig, authority: governance.publicKey })
+        .signers([governance])
+        .rpc();
+
+      const strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.deepEqual(strategy.status, { approved: {} });
+
+      await program.methods
+        .setApprovalMode(previous.governance, previous.governanceOnly)
+        .accounts({ adminConfig, authority: governance.publicKey })
+        .signers([governance])
+        .rpc();
+    });
+
+    it("Fails to close a strategy that is not retired", async () => {
+      try {
+        await program.methods
+          .closeStrategy()
+          .accounts({ strategy: strategyPda, creator: creator.publicKey })
+          .remainingAccounts(pairIndexAccounts)
+          .signers([creator])
           .rpc();
         assert.fail("Should have failed");
       } catch (err) {
-        expect(err.toString()).to.include("StrategyNotApproved");
+        expect(err.toString()).to.include("StrategyNotClosable");
       }
     });
+
+    it("Retires and closes a strategy", async () => {
+      await program.methods
+        .retireStrategy()
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      const strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.deepEqual(strategy.status, { retired: {} });
+
+      await program.methods
+        .closeStrategy()
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+      const info = await provider.connection.getAccountInfo(strategyPda);
+      assert.isNull(info);
+    });
+  });
+
+  describe("Strategy Discovery Indexes", () => {
+    let strategyPda: PublicKey;
+    let creatorIndex: PublicKey;
+    const strategyId = new anchor.BN(50);
+
+    before(async () => {
+      [strategyPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("strategy"),
+          creator.publicKey.toBuffer(),
+          strategyId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [creatorIndex] = PublicKey.findProgramAddressSync(
+        [Buffer.from("creator_index"), creator.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .createStrategy(
+          strategyId,
+          [{ raydium: {} }, { orca: {} }],
+          [{ tokenA: SOL_MINT, tokenB: USDC_MINT }],
+          50,
+          100
+        )
+        .accounts({
+          strategy: strategyPda,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+    });
+
+    const pairEntry = async () => {
+      const index = await program.account.tokenPairIndex.fetch(solUsdcPairIndex);
+      return index.entries.find((entry) => entry.strategy.equals(strategyPda));
+    };
+
+    it("Lists new strategies by creator and token pair", async () => {
+      const index = await program.account.creatorIndex.fetch(creatorIndex);
+      assert.isTrue(index.strategies.some((key) => key.equals(strategyPda)));
+
+      const entry = await pairEntry();
+      assert.isDefined(entry);
+      assert.isFalse(entry.executable);
+      // Raydium (bit 0) | Orca (bit 1)
+      assert.equal(entry.dexMask, 0b11);
+    });
+
+    it("Fails to create a strategy without its pair index", async () => {
+      const missingId = new anchor.BN(51);
+      const [missingPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("strategy"),
+          creator.publicKey.toBuffer(),
+          missingId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createStrategy(missingId, [{ raydium: {} }], [{ tokenA: SOL_MINT, tokenB: USDC_MINT }], 50, 100)
+          .accounts({
+            strategy: missingPda,
+            creator: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("MissingPairIndex");
+      }
+    });
+
+    it("Marks the entry executable on approval", async () => {
+      await program.methods
+        .approveStrategy(await strategyVersion(strategyPda))
+        .accounts({ strategy: strategyPda, adminConfig, admin: admin.publicKey })
+        .remainingAccounts(pairIndexAccounts)
+        .rpc();
+
+      const entry = await pairEntry();
+      assert.isTrue(entry.executable);
+    });
+
+    it("Anyone can re-sync a stale entry", async () => {
+      // Pause without passing the index, leaving the entry stale
+      await program.methods
+        .pauseStrategy()
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc();
+      assert.isTrue((await pairEntry()).executable);
+
+      await program.methods
+        .syncStrategyIndex()
+        .accounts({ strategy: strategyPda })
+        .remainingAccounts(pairIndexAccounts)
+        .rpc();
+      assert.isFalse((await pairEntry()).executable);
+    });
+
+    it("Removes the strategy from indexes on close", async () => {
+      await program.methods
+        .retireStrategy()
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .closeStrategy()
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+      const index = await program.account.creatorIndex.fetch(creatorIndex);
+      assert.isFalse(index.strategies.some((key) => key.equals(strategyPda)));
+      assert.isUndefined(await pairEntry());
+    });
+
+    it("Keeps creating and approving strategies once a pair index is full", async () => {
+      const MAX_PAIR_INDEX_ENTRIES = 64;
+      const CREATOR_INDEX_INITIAL_CAPACITY = 32;
+
+      // Fresh pair so the index starts empty
+      const [mintA, mintB] = [Keypair.generate().publicKey, Keypair.generate().publicKey].sort((a, b) =>
+        Buffer.compare(a.toBuffer(), b.toBuffer())
+      );
+      const [pairIndex] = PublicKey.findProgramAddressSync(
+        [Buffer.from("pair_index"), mintA.toBuffer(), mintB.toBuffer()],
+        program.programId
+      );
+      await program.methods
+        .initTokenPairIndex(mintA, mintB)
+        .accounts({ payer: admin.publicKey })
+        .rpc();
+      const indexAccounts = [{ pubkey: pairIndex, isWritable: true, isSigner: false }];
+
+      const create = async (owner: Keypair, id: number) => {
+        const [pda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("strategy"), owner.publicKey.toBuffer(), new anchor.BN(id).toArrayLike(Buffer, "le", 8)],
+          program.programId
+        );
+        await program.methods
+          .createStrategy(new anchor.BN(id), [{ raydium: {} }], [{ tokenA: mintA, tokenB: mintB }], 50, 100)
+          .accounts({ strategy: pda, creator: owner.publicKey, systemProgram: SystemProgram.programId })
+          .remainingAccounts(indexAccounts)
+          .signers([owner])
+          .rpc();
+        return pda;
+      };
+
+      // Fill the index with pending strategies from a single creator
+      const spammer = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(spammer.publicKey, 20 * anchor.web3.LAMPORTS_PER_SOL)
+      );
+      for (let i = 0; i < MAX_PAIR_INDEX_ENTRIES; i++) {
+        await create(spammer, 1000 + i);
+      }
+      let index = await program.account.tokenPairIndex.fetch(pairIndex);
+      assert.equal(index.entries.length, MAX_PAIR_INDEX_ENTRIES);
+
+      // The creator index grew past its initial capacity instead of blocking creation
+      const [spammerIndex] = PublicKey.findProgramAddressSync(
+        [Buffer.from("creator_index"), spammer.publicKey.toBuffer()],
+        program.programId
+      );
+      const spammerStrategies = (await program.account.creatorIndex.fetch(spammerIndex)).strategies;
+      assert.isAbove(spammerStrategies.length, CREATOR_INDEX_INITIAL_CAPACITY);
+      assert.equal(spammerStrategies.length, MAX_PAIR_INDEX_ENTRIES);
+
+      // Creation still succeeds, the pending strategy just is not listed
+      const honest = await create(creator, 52);
+      index = await program.account.tokenPairIndex.fetch(pairIndex);
+      assert.equal(index.entries.length, MAX_PAIR_INDEX_ENTRIES);
+      assert.isFalse(index.entries.some((entry) => entry.strategy.equals(honest)));
+
+      // Approval lists it in place of a pending entry
+      await program.methods
+        .approveStrategy(await strategyVersion(honest))
+        .accounts({ strategy: honest, adminConfig, admin: admin.publicKey })
+        .remainingAccounts(indexAccounts)
+        .rpc();
+
+      index = await program.account.tokenPairIndex.fetch(pairIndex);
+      assert.equal(index.entries.length, MAX_PAIR_INDEX_ENTRIES);
+      const entry = index.entries.find((entry) => entry.strategy.equals(honest));
+      assert.isDefined(entry);
+      assert.isTrue(entry.executable);
+    });
+  });
+
+  describe("Automatic Strategy IDs", () => {
+    const autoCreator = Keypair.generate();
+    let creatorIndex: PublicKey;
+    let strategyCounter: PublicKey;
+
+    const strategyPdaFor = (id: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("strategy"), autoCreator.publicKey.toBuffer(), id.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+
+    const createAuto = async (id: anchor.BN) =>
+      program.methods
+        .createStrategyAuto([{ raydium: {} }], [{ tokenA: SOL_MINT, tokenB: USDC_MINT }], 50, 100)
+        .accounts({
+          creatorIndex,
+          strategy: strategyPdaFor(id),
+          creator: autoCreator.publicKey,
+        })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([autoCreator])
+        .rpc();
+
+    before(async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(autoCreator.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL)
+      );
+
+      [creatorIndex] = PublicKey.findProgramAddressSync(
+        [Buffer.from("creator_index"), autoCreator.publicKey.toBuffer()],
+        program.programId
+      );
+      [strategyCounter] = PublicKey.findProgramAddressSync(
+        [Buffer.from("counter")],
+        program.programId
+      );
+    });
+
+    it("Assigns IDs from the creator's counter", async () => {
+      const counterBefore = await program.account.strategyCounter.fetch(strategyCounter);
+
+      await createAuto(new anchor.BN(0));
+
+      const strategy = await program.account.strategyAccount.fetch(strategyPdaFor(new anchor.BN(0)));
+      assert.equal(strategy.strategyId.toNumber(), 0);
+
+      const index = await program.account.creatorIndex.fetch(creatorIndex);
+      assert.equal(index.nextStrategyId.toNumber(), 1);
+
+      const counterAfter = await program.account.strategyCounter.fetch(strategyCounter);
+      assert.equal(
+        counterAfter.totalStrategies.toNumber(),
+        counterBefore.totalStrategies.toNumber() + 1
+      );
+    });
+
+    it("Skips past explicitly chosen IDs", async () => {
+      const explicitId = new anchor.BN(5);
+      await program.methods
+        .createStrategy(explicitId, [{ raydium: {} }], [{ tokenA: SOL_MINT, tokenB: USDC_MINT }], 50, 100)
+        .accounts({
+          strategy: strategyPdaFor(explicitId),
+          creator: autoCreator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([autoCreator])
+        .rpc();
+
+      let index = await program.account.creatorIndex.fetch(creatorIndex);
+      assert.equal(index.nextStrategyId.toNumber(), 6);
+
+      await createAuto(new anchor.BN(6));
+
+      index = await program.account.creatorIndex.fetch(creatorIndex);
+      assert.equal(index.nextStrategyId.toNumber(), 7);
+      assert.equal(index.strategies.length, 3);
+    });
+  });
+
+  describe("Creation Bond", () => {
+    const BOND = new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL);
+    const bondTreasury = Keypair.generate();
+
+    const strategyPdaFor = (id: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("strategy"), creator.publicKey.toBuffer(), id.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+
+    const bondEscrowFor = (strategyPda: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("bond"), strategyPda.toBuffer()],
+        program.programId
+      )[0];
+
+    const createBonded = async (id: anchor.BN) => {
+      await program.methods
+        .createStrategy(id, [{ raydium: {} }], [{ tokenA: SOL_MINT, tokenB: USDC_MINT }], 50, 100)
+        .accounts({
+          strategy: strategyPdaFor(id),
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+      return strategyPdaFor(id);
+    };
+
+    const settle = (strategyPda: PublicKey) =>
+      program.methods
+        .settleBond()
+        .accounts({
+          strategy: strategyPda,
+          bondEscrow: bondEscrowFor(strategyPda),
+          adminConfig,
+          creator: creator.publicKey,
+          treasury: bondTreasury.publicKey,
+        })
+        .rpc();
+
+    before(async () => {
+      await program.methods
+        .setCreationBond(BOND, bondTreasury.publicKey)
+        .accounts({ adminConfig, authority: admin.publicKey })
+        .rpc();
+    });
+
+    after(async () => {
+      // Restore bond-free creation for other suites
+      await program.methods
+        .setCreationBond(new anchor.BN(0), bondTreasury.publicKey)
+        .accounts({ adminConfig, authority: admin.publicKey })
+        .rpc();
+    });
+
+    it("Fails to set a bond without authority", async () => {
+      try {
+        await program.methods
+          .setCreationBond(BOND, otherUser.publicKey)
+          .accounts({ adminConfig, authority: otherUser.publicKey })
+          .signers([otherUser])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedAdmin");
+      }
+    });
+
+    it("Locks the bond and refunds it after approval", async () => {
+      const strategyPda = await createBonded(new anchor.BN(60));
+      const escrow = await program.account.bondEscrow.fetch(bondEscrowFor(strategyPda));
+      assert.equal(escrow.amount.toString(), BOND.toString());
+      assert.isFalse(escrow.settled);
+
+      try {
+        await settle(strategyPda);
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("BondLocked");
+      }
+
+      await program.methods
+        .approveStrategy(await strategyVersion(strategyPda))
+        .accounts({ strategy: strategyPda, adminConfig, admin: admin.publicKey })
+        .rpc();
+
+      const creatorBefore = await provider.connection.getBalance(creator.publicKey);
+      await settle(strategyPda);
+      const creatorAfter = await provider.connection.getBalance(creator.publicKey);
+
+      assert.equal(creatorAfter - creatorBefore, BOND.toNumber());
+      assert.isTrue((await program.account.bondEscrow.fetch(bondEscrowFor(strategyPda))).settled);
+    });
+
+    it("Forfeits the bond to the treasury on rejection", async () => {
+      const strategyPda = await createBonded(new anchor.BN(61));
+
+      await program.methods
+        .rejectStrategy()
+        .accounts({ strategy: strategyPda, adminConfig, admin: admin.publicKey })
+        .rpc();
+
+      try {
+        await program.methods
+          .closeStrategy()
+          .accounts({ strategy: strategyPda, creator: creator.publicKey })
+          .remainingAccounts(pairIndexAccounts)
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("BondNotSettled");
+      }
+
+      await settle(strategyPda);
+      const treasuryBalance = await provider.connection.getBalance(bondTreasury.publicKey);
+      assert.equal(treasuryBalance, BOND.toNumber());
+    });
+
+    it("Refunds the bond when a pending strategy is withdrawn", async () => {
+      const strategyPda = await createBonded(new anchor.BN(62));
+
+      await program.methods
+        .retireStrategy()
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .closeStrategy()
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+      assert.isNull(await provider.connection.getAccountInfo(bondEscrowFor(strategyPda)));
+    });
+  });
+
+  describe("Strategy Routes", () => {
+    let strategyPda: PublicKey;
+    let routePda: PublicKey;
+    let routeIndexAccounts: { pubkey: PublicKey; isWritable: boolean; isSigner: boolean }[];
+    const strategyId = new anchor.BN(70);
+    const RAYDIUM_POOL = Keypair.generate().publicKey;
+    const ORCA_POOL = Keypair.generate().publicKey;
+    const BONK_MINT = new PublicKey("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263");
+
+    before(async () => {
+      [strategyPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("strategy"),
+          creator.publicKey.toBuffer(),
+          strategyId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [routePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("route"), strategyPda.toBuffer()],
+        program.programId
+      );
+
+      // Routes may only swap listed pairs, so list the BONK legs too
+      routeIndexAccounts = [...pairIndexAccounts];
+      for (const pair of [[USDC_MINT, BONK_MINT], [BONK_MINT, SOL_MINT]]) {
+        const [mintA, mintB] = pair.sort((a, b) => Buffer.compare(a.toBuffer(), b.toBuffer()));
+        const [pairIndex] = PublicKey.findProgramAddressSync(
+          [Buffer.from("pair_index"), mintA.toBuffer(), mintB.toBuffer()],
+          program.programId
+        );
+        await program.methods
+          .initTokenPairIndex(mintA, mintB)
+          .accounts({ payer: admin.publicKey })
+          .rpc();
+        routeIndexAccounts.push({ pubkey: pairIndex, isWritable: true, isSigner: false });
+      }
+
+      await program.methods
+        .createStrategy(
+          strategyId,
+          [{ raydium: {} }, { orca: {} }],
+          [
+            { tokenA: SOL_MINT, tokenB: USDC_MINT },
+            { tokenA: USDC_MINT, tokenB: BONK_MINT },
+            { tokenA: BONK_MINT, tokenB: SOL_MINT },
+          ],
+          50,
+          100
+        )
+        .accounts({
+          strategy: strategyPda,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(routeIndexAccounts)
+        .signers([creator])
+        .rpc();
+    });
+
+    it("Refuses an initial route through a DEX the strategy does not list", async () => {
+      try {
+        await program.methods
+          .initRoute([
+            { dex: { raydium: {} }, pool: RAYDIUM_POOL, inputMint: SOL_MINT, outputMint: USDC_MINT },
+            { dex: { meteora: {} }, pool: Keypair.generate().publicKey, inputMint: USDC_MINT, outputMint: SOL_MINT },
+          ])
+          .accounts({ strategy: strategyPda, strategyRoute: routePda, creator: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("RouteDexNotAllowed");
+      }
+    });
+
+    it("Sets a two-hop route that cycles back to the borrowed mint", async () => {
+      await program.methods
+        .initRoute([
+          { dex: { raydium: {} }, pool: RAYDIUM_POOL, inputMint: SOL_MINT, outputMint: USDC_MINT },
+          { dex: { orca: {} }, pool: ORCA_POOL, inputMint: USDC_MINT, outputMint: SOL_MINT },
+        ])
+        .accounts({ strategy: strategyPda, strategyRoute: routePda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      const route = await program.account.strategyRoute.fetch(routePda);
+      assert.equal(route.hops.length, 2);
+      assert.equal(route.hops[0].pool.toString(), RAYDIUM_POOL.toString());
+    });
+
+    it("Grows the route account for a triangular route", async () => {
+      const sizeBefore = (await provider.connection.getAccountInfo(routePda)).data.length;
+
+      await program.methods
+        .updateRoute([
+          { dex: { raydium: {} }, pool: RAYDIUM_POOL, inputMint: SOL_MINT, outputMint: USDC_MINT },
+          { dex: { orca: {} }, pool: ORCA_POOL, inputMint: USDC_MINT, outputMint: BONK_MINT },
+          { dex: { raydium: {} }, pool: Keypair.generate().publicKey, inputMint: BONK_MINT, outputMint: SOL_MINT },
+        ])
+        .accounts({ strategy: strategyPda, strategyRoute: routePda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      const sizeAfter = (await provider.connection.getAccountInfo(routePda)).data.length;
+      assert.isAbove(sizeAfter, sizeBefore);
+
+      const route = await program.account.strategyRoute.fetch(routePda);
+      assert.equal(route.hops.length, 3);
+    });
+
+    it("Fails with a route that does not return to the borrowed mint", async () => {
+      try {
+        await program.methods
+          .updateRoute([
+            { dex: { raydium: {} }, pool: RAYDIUM_POOL, inputMint: SOL_MINT, outputMint: USDC_MINT },
+            { dex: { orca: {} }, pool: ORCA_POOL, inputMint: USDC_MINT, outputMint: BONK_MINT },
+          ])
+          .accounts({ strategy: strategyPda, strategyRoute: routePda, creator: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("RouteNotClosed");
+      }
+    });
+
+    it("Fails with broken hop continuity", async () => {
+      try {
+        await program.methods
+          .updateRoute([
+            { dex: { raydium: {} }, pool: RAYDIUM_POOL, inputMint: SOL_MINT, outputMint: USDC_MINT },
+            { dex: { orca: {} }, pool: ORCA_POOL, inputMint: BONK_MINT, outputMint: SOL_MINT },
+          ])
+          .accounts({ strategy: strategyPda, strategyRoute: routePda, creator: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("RouteNotContinuous");
+      }
+    });
+
+    it("Fails with a hop on a DEX the strategy does not list", async () => {
+      try {
+        await program.methods
+          .updateRoute([
+            { dex: { raydium: {} }, pool: RAYDIUM_POOL, inputMint: SOL_MINT, outputMint: USDC_MINT },
+            { dex: { meteora: {} }, pool: Keypair.generate().publicKey, inputMint: USDC_MINT, outputMint: SOL_MINT },
+          ])
+          .accounts({ strategy: strategyPda, strategyRoute: routePda, creator: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("RouteDexNotAllowed");
+      }
+    });
+
+    it("Fails with a hop through a token pair the strategy does not list", async () => {
+      const unlistedMint = Keypair.generate().publicKey;
+      try {
+        await program.methods
+          .updateRoute([
+            { dex: { raydium: {} }, pool: RAYDIUM_POOL, inputMint: SOL_MINT, outputMint: unlistedMint },
+            { dex: { orca: {} }, pool: ORCA_POOL, inputMint: unlistedMint, outputMint: SOL_MINT },
+          ])
+          .accounts({ strategy: strategyPda, strategyRoute: routePda, creator: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("RoutePairNotAllowed");
+      }
+    });
+
+    it("Fails with a route that does not start from WSOL", async () => {
+      try {
+        await program.methods
+          .updateRoute([
+            { dex: { orca: {} }, pool: ORCA_POOL, inputMint: USDC_MINT, outputMint: BONK_MINT },
+            { dex: { raydium: {} }, pool: Keypair.generate().publicKey, inputMint: BONK_MINT, outputMint: USDC_MINT },
+          ])
+          .accounts({ strategy: strategyPda, strategyRoute: routePda, creator: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("RouteNotFromWsol");
+      }
+    });
+
+    it("Sends an approved strategy back to Pending on route change", async () => {
+      await program.methods
+        .approveStrategy(await strategyVersion(strategyPda))
+        .accounts({ strategy: strategyPda, adminConfig, admin: admin.publicKey })
+        .remainingAccounts(routeIndexAccounts)
+        .rpc();
+
+      const twoHopRoute = [
+        { dex: { raydium: {} }, pool: RAYDIUM_POOL, inputMint: SOL_MINT, outputMint: USDC_MINT },
+        { dex: { orca: {} }, pool: ORCA_POOL, inputMint: USDC_MINT, outputMint: SOL_MINT },
+      ];
+
+      // Demotion must reach every pair index, or they keep listing the strategy as executable
+      try {
+        await program.methods
+          .updateRoute(twoHopRoute)
+          .accounts({ strategy: strategyPda, strategyRoute: routePda, creator: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("MissingPairIndex");
+      }
+
+      await program.methods
+        .updateRoute(twoHopRoute)
+        .accounts({ strategy: strategyPda, strategyRoute: routePda, creator: creator.publicKey })
+        .remainingAccounts(routeIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+      const strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.deepEqual(strategy.status, { pending: {} });
+
+      const index = await program.account.tokenPairIndex.fetch(solUsdcPairIndex);
+      const entry = index.entries.find((entry) => entry.strategy.equals(strategyPda));
+      assert.isDefined(entry);
+      assert.isFalse(entry.executable);
+    });
+
+    it("Refuses a strategy update that drops a DEX the route uses", async () => {
+      try {
+        await program.methods
+          .updateStrategy(
+            [{ raydium: {} }],
+            [
+              { tokenA: SOL_MINT, tokenB: USDC_MINT },
+              { tokenA: USDC_MINT, tokenB: BONK_MINT },
+              { tokenA: BONK_MINT, tokenB: SOL_MINT },
+            ],
+            50,
+            100
+          )
+          .accounts({ strategy: strategyPda, creator: creator.publicKey })
+          .remainingAccounts(routeIndexAccounts)
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("RouteDexNotAllowed");
+      }
+    });
+
+    it("Closes the route together with the strategy", async () => {
+      await program.methods
+        .retireStrategy()
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .closeStrategy()
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
+        .remainingAccounts(routeIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+      assert.isNull(await provider.connection.getAccountInfo(strategyPda));
+      assert.isNull(await provider.connection.getAccountInfo(routePda));
+    });
+  });
+
+  describe("Strategy Borrow Caps", () => {
+    let strategyPda: PublicKey;
+    const strategyId = new anchor.BN(80);
+
+    before(async () => {
+      [strategyPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("strategy"),
+          creator.publicKey.toBuffer(),
+          strategyId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createStrategy(
+          strategyId,
+          [{ raydium: {} }, { orca: {} }],
+          [{ tokenA: SOL_MINT, tokenB: USDC_MINT }],
+          50,
+          100
+        )
+        .accounts({
+          strategy: strategyPda,
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+      await program.methods
+        .approveStrategy(await strategyVersion(strategyPda))
+        .accounts({
+          strategy: strategyPda,
+          adminConfig,
+          admin: admin.publicKey,
+        })
+        .remainingAccounts(pairIndexAccounts)
+        .rpc();
+    });
+
+    const setMaxBorrow = (maxBorrow: anchor.BN) =>
+      program.methods
+        .setMaxBorrow(maxBorrow)
+        .accounts({ strategy: strategyPda, creator: creator.publicKey })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+    it("Starts new strategies at the default borrow cap", async () => {
+      const strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.equal(strategy.maxBorrow.toString(), (100 * anchor.web3.LAMPORTS_PER_SOL).toString());
+    });
+
+    it("Lowers the cap without re-approval", async () => {
+      await setMaxBorrow(new anchor.BN(10 * anchor.web3.LAMPORTS_PER_SOL));
+
+      const strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.equal(strategy.maxBorrow.toString(), (10 * anchor.web3.LAMPORTS_PER_SOL).toString());
+      assert.deepEqual(strategy.status, { approved: {} });
+    });
+
+    it("Fails to raise the cap of an approved strategy without its pair indexes", async () => {
+      try {
+        await program.methods
+          .setMaxBorrow(new anchor.BN(50 * anchor.web3.LAMPORTS_PER_SOL))
+          .accounts({ strategy: strategyPda, creator: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("MissingPairIndex");
+      }
+    });
+
+    it("Sends the strategy back to Pending when the cap is raised", async () => {
+      const before = await program.account.strategyAccount.fetch(strategyPda);
+
+      await setMaxBorrow(new anchor.BN(50 * anchor.web3.LAMPORTS_PER_SOL));
+
+      const strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.equal(strategy.maxBorrow.toString(), (50 * anchor.web3.LAMPORTS_PER_SOL).toString());
+      assert.deepEqual(strategy.status, { pending: {} });
+      assert.equal(strategy.version, before.version + 1);
+
+      const index = await program.account.tokenPairIndex.fetch(solUsdcPairIndex);
+      const entry = index.entries.find((entry) => entry.strategy.equals(strategyPda));
+      assert.isDefined(entry);
+      assert.isFalse(entry.executable);
+    });
+
+    it("Refuses a pending approval after the cap was raised", async () => {
+      // Strategy is Pending from the previous test; governance reviewed this version
+      const reviewedVersion = await strategyVersion(strategyPda);
+
+      await setMaxBorrow(new anchor.BN(80 * anchor.web3.LAMPORTS_PER_SOL));
+
+      const strategy = await program.account.strategyAccount.fetch(strategyPda);
+      assert.deepEqual(strategy.status, { pending: {} });
+      assert.equal(strategy.version, reviewedVersion + 1);
+
+      try {
+        await program.methods
+          .approveStrategy(reviewedVersion)
+          .accounts({ strategy: strategyPda, adminConfig, admin: admin.publicKey })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("StrategyVersionMismatch");
+      }
+    });
+
+    it("Fails with a zero cap", async () => {
+      try {
+        await setMaxBorrow(new anchor.BN(0));
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidMaxBorrow");
+      }
+    });
+
+    it("Fails to set the cap without being the creator", async () => {
+      try {
+        await program.methods
+          .setMaxBorrow(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL))
+          .accounts({ strategy: strategyPda, creator: otherUser.publicKey })
+          .signers([otherUser])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedCreator");
+      }
+    });
+  });
+
+  describe("Circuit Breaker", () => {
+    const PAUSE_CREATE = 1 << 0;
+    const PAUSE_UPDATE = 1 << 1;
+    const PAUSE_APPROVAL = 1 << 2;
+    const guardian = Keypair.generate();
+    const strategyId = new anchor.BN(90);
+    let strategyPda: PublicKey;
+
+    const strategyPdaFor = (id: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("strategy"), creator.publicKey.toBuffer(), id.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+
+    const createStrategy = (id: anchor.BN) =>
+      program.methods
+        .createStrategy(
+          id,
+          [{ raydium: {} }, { orca: {} }],
+          [{ tokenA: SOL_MINT, tokenB: USDC_MINT }],
+          50,
+          100
+        )
+        .accounts({
+          strategy: strategyPdaFor(id),
+          creator: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(pairIndexAccounts)
+        .signers([creator])
+        .rpc();
+
+    const setPause = (groups: number, signer?: Keypair) =>
+      program.methods
+        .setPause(groups)
+        .accounts({ adminConfig, authority: signer ? signer.publicKey : admin.publicKey })
+        .signers(signer ? [signer] : [])
+        .rpc();
+
+    before(async () => {
+      strategyPda = strategyPdaFor(strategyId);
+      await createStrategy(strategyId);
+    });
+
+    afterEach(async () => {
+      await setPause(0);
+    });
+
+    it("Only governance can set the guardian in governance-only mode", async () => {
+      try {
+        await program.methods
+          .setGuardian(guardian.publicKey, new anchor.BN(86_400))
+          .accounts({ adminConfig, authority: otherUser.publicKey })
+          .signers([otherUser])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedAdmin");
+      }
+
+      // Stands in for the DAO governance PDA (RegistryAction::SetGuardian signs the same setter)
+      const governance = Keypair.generate();
+      const previous = await program.account.adminConfig.fetch(adminConfig);
+      await program.methods
+        .setApprovalMode(governance.publicKey, true)
+        .accounts({ adminConfig, authority: admin.publicKey })
+        .rpc();
+
+      try {
+        await program.methods
+          .setGuardian(guardian.publicKey, new anchor.BN(86_400))
+          .accounts({ adminConfig, authority: admin.publicKey })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedAdmin");
+      }
+
+      await program.methods
+        .setGuardian(guardian.publicKey, new anchor.BN(86_400))
+        .accounts({ adminConfig, authority: governance.publicKey })
+        .signers([governance])
+        .rpc();
+
+      const config = await program.account.adminConfig.fetch(adminConfig);
+      assert.equal(config.pause.guardian.toString(), guardian.publicKey.toString());
+
+      // Hand control back to the admin for the remaining tests
+      await program.methods
+        .setApprovalMode(previous.governance, previous.governanceOnly)
+        .accounts({ adminConfig, authority: governance.publicKey })
+        .signers([governance])
+        .rpc();
+    });
+
+    it("Guardian pause blocks strategy creation for 24 hours", async () => {
+      await setPause(PAUSE_CREATE, guardian);
+
+      const config = await program.account.adminConfig.fetch(adminConfig);
+      assert.equal(config.pause.guardianPaused, PAUSE_CREATE);
+      const now = Math.floor(Date.now() / 1000);
+      expect(config.pause.guardianExpiresAt.toNumber()).to.be.closeTo(now + 86_400, 120);
+
+      try {
+        await createStrategy(new anchor.BN(91));
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("ProgramPaused");
+      }
+    });
+
+    it("Admin pause blocks strategy updates and clears the guardian pause", async () => {
+      await setPause(PAUSE_CREATE, guardian);
+      await setPause(PAUSE_UPDATE);
+
+      const config = await program.account.adminConfig.fetch(adminConfig);
+      assert.equal(config.pause.adminPaused, PAUSE_UPDATE);
+      assert.equal(config.pause.guardianPaused, 0);
+
+      try {
+        await program.methods
+          .updateStrategy(
+            [{ raydium: {} }],
+            [{ tokenA: SOL_MINT, tokenB: USDC_MINT }],
+            75,
+            50
+          )
+          .accounts({ strategy: strategyPda, creator: creator.publicKey })
+          .remainingAccounts(pairIndexAccounts)
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("ProgramPaused");
+      }
+    });
+
+    it("Admin pause blocks approvals", async () => {
+      await setPause(PAUSE_APPROVAL);
+
+      try {
+        await program.methods
+          .approveStrategy(await strategyVersion(strategyPda))
+          .accounts({ strategy: strategyPda, adminConfig, admin: admin.publicKey })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("ProgramPaused");
+      }
+    });
+
+    it("Rejects pauses from other signers and unknown groups", async () => {
+      try {
+        await setPause(PAUSE_CREATE, otherUser);
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedPauser");
+      }
+
+      try {
+        await setPause(1 << 3);
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("InvalidPauseGroups");
+      }
+    });
+  });
+
+  describe("Admin Management", () => {
+    it("Transfers admin via propose and accept", async () => {
+      await program.methods
+        .transferAdmin(otherUser.publicKey)
+        .accounts({ adminConfig, admin: admin.publicKey })
+        .rpc();
+
+      let config = await program.account.adminConfig.fetch(adminConfig);
+      assert.equal(config.pendingAdmin.toString(), otherUser.publicKey.toString());
+
+      await program.methods
+        .acceptAdmin()
+        .accounts({ adminConfig, newAdmin: otherUser.publicKey })
+        .signers([otherUser])
+        .rpc();
+
+      config = await program.account.adminConfig.fetch(adminConfig);
+      assert.equal(config.admin.toString(), otherUser.publicKey.toString());
+      assert.equal(config.pendingAdmin.toString(), PublicKey.default.toString());
+
+      // Hand admin back so other suites keep working
+      await program.methods
+        .transferAdmin(admin.publicKey)
+        .accounts({ adminConfig, admin: otherUser.publicKey })
+        .signers([otherUser])
+        .rpc();
+      await program.methods
+        .acceptAdmin()
+        .accounts({ adminConfig, newAdmin: admin.publicKey })
+        .rpc();
+    });
+
+    it("Fails to accept admin without a nomination", async () => {
+      try {
+        await program.methods
+          .acceptAdmin()
+          .accounts({ adminConfig, newAdmin: creator.publicKey })
+          .signers([creator])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedAdmin");
+      }
+    });
+
+    it("Fails to enable governance-only mode without a governance authority", async () => {
+      try {
+        await program.methods
+          .setApprovalMode(PublicKey.default, true)
+          .accounts({ adminConfig, authority: admin.publicKey })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("GovernanceNotConfigured");
+      }
+    });
+
+    it("Governance keeps control of the config in governance-only mode", async () => {
+      // Stands in for the DAO governance PDA, which signs the same setters via execute_proposal
+      const governance = Keypair.generate();
+      const REGISTRY_PAUSE_CREATE = 1 << 0;
+
+      await program.methods
+        .setApprovalMode(governance.publicKey, true)
+        .accounts({ adminConfig, authority: admin.publicKey })
+        .rpc();
+
+      // The admin is locked out...
+      try {
+        await program.methods
+          .setPause(REGISTRY_PAUSE_CREATE)
+          .accounts({ adminConfig, authority: admin.publicKey })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (err) {
+        expect(err.toString()).to.include("UnauthorizedPauser");
+      }
+
+      // ...while governance can still pause, unpause and change the mode
+      await program.methods
+        .setPause(REGISTRY_PAUSE_CREATE)
+        .accounts({ adminConfig, authority: governance.publicKey })
+        .signers([governance])
+        .rpc();
+      let config = await program.account.adminConfig.fetch(adminConfig);
+      assert.equal(config.pause.adminPaused, REGISTRY_PAUSE_CREATE);
+
+      await program.methods
+        .setPause(0)
+        .accounts({ adminConfig, authority: governance.publicKey })
+        .signers([governance])
+        .rpc();
+      await program.methods
+        .setApprovalMode(governance.publicKey, false)
+        .accounts({ adminConfig, authority: governance.publicKey })
+        .signers([governance])
+        .rpc();
+
+      config = await program.account.adminConfig.fetch(adminConfig);
+      assert.equal(config.pause.adminPaused, 0);
+      assert.isFalse(config.governanceOnly);
+    });
+
+    it("Registers the DAO governance PDA as an approver", async () => {
+      const [governancePda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("governance")],
+        new PublicKey("RECwcpcHwBeDAV7tBvUuhJzsih16BaveZRC74kbBkSS")
+      );
+
+      await program.methods
+        .setApprovalMode(governancePda, false)
+        .accounts({ adminConfig, authority: admin.publicKey })
+        .rpc();
+
+      const config = await program.account.adminConfig.fetch(adminConfig);
+      assert.equal(config.governance.toString(), governancePda.toString());
+      assert.isFalse(config.governanceOnly);
+    });
   });
 });